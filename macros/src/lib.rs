@@ -0,0 +1,118 @@
+//! Procedural macros of [bresenham_zip](https://crates.io/crates/bresenham_zip) running the
+//! rasterization at compile time, so the shapes known beforehand are baked into the binary
+//! instead of computed on the device.
+
+use bresenham_zip::triangle::Triangle2;
+use bresenham_zip::Axis;
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+use std::iter::Peekable;
+
+/// Rasterizes the triangle with the given literal vertices at compile time, expanding to an
+/// array of the [Span](bresenham_zip::span::Span)s of
+/// [Triangle2::rasterize_along](bresenham_zip::triangle::Triangle2::rasterize_along), so it can
+/// initialize a `static` or a `const`. The vertices go after the axis travelled,
+/// in any order:
+///
+/// `<axis>: <vertex>, <vertex>, <vertex>`
+///
+/// ```
+/// # use bresenham_zip::{Axis, span::Span, triangle::Triangle2};
+/// use bresenham_zip_macros::static_raster;
+///
+/// static ICON: &[Span<i16>] = &static_raster!(Y: (0, 0), (10, 4), (3, -9));
+///
+/// let spans = Triangle2::new((0, 0), (10, 4), (3, -9)).rasterize_along(Axis::Y).unwrap().spans();
+/// assert!(spans.eq(ICON.iter().cloned()));
+/// ```
+///
+/// The coordinates must be integer literals, known when the crate is compiled:
+/// ```compile_fail
+/// let x = 10;
+/// let spans = bresenham_zip_macros::static_raster!(Y: (0, 0), (x, 4), (3, -9));
+/// ```
+#[proc_macro]
+pub fn static_raster(input: TokenStream) -> TokenStream {
+	match parse(input) {
+		Ok((axis, [a, b, c])) => {
+			let spans = Triangle2::new(a, b, c).rasterize_along(axis)
+				.map(|zip| zip.spans().map(|span| {
+					let (start, end) = span.range.into_inner();
+					format!("::bresenham_zip::span::Span {{ axis_value: {}, range: {}..={} }},",
+						span.axis_value, start, end)
+				}).collect::<String>())
+				.unwrap_or_default();
+			format!("[{}]", spans)
+		}
+		Err(message) => format!("compile_error!(\"static_raster!: {}\")", message),
+	}.parse().unwrap()
+}
+
+/// Returns the axis and the vertices of the triangle written in the macro call
+fn parse(input: TokenStream) -> Result<(Axis, [(i64, i64); 3]), String> {
+	let mut tokens = input.into_iter().peekable();
+	let axis = match tokens.next() {
+		Some(TokenTree::Ident(ident)) => match ident.to_string().as_str() {
+			"X" | "x" => Axis::X,
+			"Y" | "y" => Axis::Y,
+			_ => return Err(format!("unknown axis `{}`, use X or Y", ident)),
+		},
+		_ => return Err("the call must start with the axis, like `Y: (0, 0), (4, 0), (0, 4)`"
+			.into()),
+	};
+	expect_punct(&mut tokens, ':')?;
+	let mut vertices = [(0, 0); 3];
+	for (i, vertex) in vertices.iter_mut().enumerate() {
+		if i > 0 {
+			expect_punct(&mut tokens, ',')?;
+		}
+		*vertex = match tokens.next() {
+			Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+				let mut coordinates = group.stream().into_iter().peekable();
+				let x = integer(&mut coordinates)?;
+				expect_punct(&mut coordinates, ',')?;
+				let y = integer(&mut coordinates)?;
+				if coordinates.next().is_some() {
+					return Err("the vertices must have two coordinates".into());
+				}
+				(x, y)
+			}
+			_ => return Err("expected three vertices like `(0, 0)`".into()),
+		};
+	}
+	// a trailing comma is allowed
+	match tokens.next() {
+		Some(TokenTree::Punct(punct)) if punct.as_char() == ',' && tokens.peek().is_none() => {
+			Ok((axis, vertices))
+		}
+		Some(_) => Err("expected only three vertices".into()),
+		None => Ok((axis, vertices)),
+	}
+}
+
+/// Consumes the next token, which must be the given punctuation
+fn expect_punct<I: Iterator<Item = TokenTree>>(tokens: &mut I, expected: char)
+	-> Result<(), String> {
+	match tokens.next() {
+		Some(TokenTree::Punct(punct)) if punct.as_char() == expected
+			&& (expected != ':' || punct.spacing() == Spacing::Alone) => Ok(()),
+		_ => Err(format!("expected `{}`", expected)),
+	}
+}
+
+/// Consumes the integer literal, with an optional minus sign, of the next tokens
+fn integer<I: Iterator<Item = TokenTree>>(tokens: &mut Peekable<I>) -> Result<i64, String> {
+	let negative = matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '-');
+	if negative {
+		tokens.next();
+	}
+	match tokens.next() {
+		Some(TokenTree::Literal(literal)) => {
+			let digits = literal.to_string();
+			let digits = digits.trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '_');
+			let value: i64 = digits.parse()
+				.map_err(|_| format!("`{}` isn't an integer", literal))?;
+			Ok(if negative { -value } else { value })
+		}
+		_ => Err("the coordinates must be integer literals".into()),
+	}
+}
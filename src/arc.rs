@@ -0,0 +1,389 @@
+//! Package with the logic to rasterize arcs and pie slices of circles
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
+use crate::circle::CircleZip;
+use crate::fill::FillIterator;
+use crate::span::SpanIterator;
+use crate::util::{floor_div, narrow, wide, Point};
+use crate::{Axis, Point2, SignedNum};
+
+/// Vector from the center of the circle, widened to compute its products
+type Vector = (i128, i128);
+
+/// Sector of a circle, the pie slice swept counterclockwise from one direction to another one,
+/// both included, with the Y axis pointing up; so clockwise on screens with the Y axis pointing
+/// down. The directions are vectors from the center instead of angles, so no trigonometry is
+/// needed: `(1, 0)` is the angle 0, `(0, 1)` the right angle and `(-1, 1)` the angle of 135°.
+/// Two directions pointing to the same side sweep the whole circle.
+///
+/// The outline of the circle uses the same criterion of the [CircleZip], so the arc lies on the
+/// edge of the filled sector.
+///
+/// ```
+/// # use bresenham_zip::{Axis, arc::Sector};
+/// let quarter = Sector::new((0, 0), 4, (1, 0), (0, 1));
+/// assert!(quarter.arc().all(|(x, y)| x >= 0 && y >= 0));
+/// let mut zip = quarter.rasterize_along(Axis::Y).unwrap();
+/// assert_eq!(zip.next(), Some(((0, 0), (4, 0))));
+/// assert_eq!(zip.last(), Some(((0, 4), (2, 4))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sector<T> {
+	center: Point2<T>,
+	radius: T,
+	start: Point2<T>,
+	end: Point2<T>,
+}
+
+impl<T: SignedNum> Sector<T> {
+
+	/// Creates the sector of the circle between two directions
+	///
+	/// * `center` - Center of the circle
+	/// * `radius` - Distance from the center to the edge, in pixels
+	/// * `start` - Non-zero direction where the sector starts
+	/// * `end` - Non-zero direction where the sector ends
+	///
+	pub fn new(center: Point2<T>, radius: T, start: Point2<T>, end: Point2<T>) -> Self {
+		Self { center, radius, start, end }
+	}
+
+	/// Returns an iterator over the points of the outline of the circle inside the sector, see
+	/// [ArcPoints]
+	pub fn arc(&self) -> ArcPoints<T> {
+		let radius = wide(self.radius);
+		let octant = match radius.cmp(&0) {
+			Ordering::Greater => 0,
+			// the center is only yielded by the last octant
+			Ordering::Equal => 7,
+			Ordering::Less => 8,
+		};
+		ArcPoints {
+			center: self.center,
+			wedge: Wedge::new(self.start, self.end),
+			limit: radius * radius + radius,
+			octant,
+			step: 0,
+			height: radius,
+		}
+	}
+
+	/// Returns the zip of the filled sector along the axis, see [SectorZip]
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	pub fn rasterize_along(&self, axis: Axis) -> Option<SectorZip<T>> {
+		Some(SectorZip {
+			circle: CircleZip::new(self.center, self.radius, axis).ok()?,
+			center: self.center,
+			wedge: Wedge::new(self.start, self.end),
+			axis: if axis == Axis::X { 0 } else { 1 },
+			pending: None,
+		})
+	}
+
+}
+
+/// How far the sector sweeps, deciding how the two half-planes of its sides are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sweep {
+	/// The whole circle
+	Full,
+	/// Up to half of the circle, inside both half-planes
+	Convex,
+	/// More than half of the circle, inside any of the half-planes
+	Reflex,
+}
+
+/// Directions of the sides of a [Sector]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Wedge {
+	start: Vector,
+	end: Vector,
+	sweep: Sweep,
+}
+
+impl Wedge {
+
+	fn new<T: SignedNum>(start: Point2<T>, end: Point2<T>) -> Self {
+		let (start, end) = ((wide(start.0), wide(start.1)), (wide(end.0), wide(end.1)));
+		let sweep = match cross(start, end).cmp(&0) {
+			Ordering::Equal if start.0 * end.0 + start.1 * end.1 > 0 => Sweep::Full,
+			Ordering::Less => Sweep::Reflex,
+			_ => Sweep::Convex,
+		};
+		Self { start, end, sweep }
+	}
+
+	/// Checks if the vector points inside the sector
+	fn contains(&self, vector: Vector) -> bool {
+		let after_start = cross(self.start, vector) >= 0;
+		let before_end = cross(vector, self.end) >= 0;
+		match self.sweep {
+			Sweep::Full => true,
+			Sweep::Convex => after_start && before_end,
+			Sweep::Reflex => after_start || before_end,
+		}
+	}
+
+	/// Returns the ranges of the row of the circle inside the sector, up to two of them sorted
+	///
+	/// * `row` - Value of the row in the travelled axis, relative to the center
+	/// * `half` - Half of the length of the row in the circle
+	/// * `axis` - Travelled axis
+	///
+	fn row(&self, row: i128, half: i128, axis: u8) -> [Option<Vector>; 2] {
+		let after_start = side(self.start, row, half, axis, false);
+		let before_end = side(self.end, row, half, axis, true);
+		match (self.sweep, after_start, before_end) {
+			(Sweep::Full, _, _) => [Some((-half, half)), None],
+			(Sweep::Convex, Some(a), Some(b)) => {
+				let range = (a.0.max(b.0), a.1.min(b.1));
+				[(range.0 <= range.1).then_some(range), None]
+			}
+			(Sweep::Convex, _, _) => [None, None],
+			(Sweep::Reflex, Some(a), Some(b)) => {
+				let (a, b) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+				if b.0 <= a.1 + 1 {
+					[Some((a.0, a.1.max(b.1))), None]
+				} else {
+					[Some(a), Some(b)]
+				}
+			}
+			(Sweep::Reflex, a, b) => [a.or(b), None],
+		}
+	}
+
+}
+
+/// Returns the range of the row between `-half` and `half` where the turn from the direction to
+/// the point is counterclockwise or zero, or clockwise or zero if `reversed`
+fn side(direction: Vector, row: i128, half: i128, axis: u8, reversed: bool) -> Option<Vector> {
+	// the turn is `slope * value + constant` for each value of the row
+	let (mut slope, mut constant) = if axis == 1 {
+		(-direction.1, direction.0 * row)
+	} else {
+		(direction.0, -direction.1 * row)
+	};
+	if reversed {
+		(slope, constant) = (-slope, -constant);
+	}
+	let (start, end) = match slope.cmp(&0) {
+		Ordering::Greater => ((-floor_div(constant, slope)).max(-half), half),
+		Ordering::Less => (-half, floor_div(constant, -slope).min(half)),
+		Ordering::Equal if constant >= 0 => (-half, half),
+		Ordering::Equal => return None,
+	};
+	(start <= end).then_some((start, end))
+}
+
+/// Returns the cross product of the vectors, positive if the turn from `a` to `b` is
+/// counterclockwise
+fn cross(a: Vector, b: Vector) -> i128 {
+	a.0 * b.1 - a.1 * b.0
+}
+
+/// Iterator over the points of the outline of a [Sector], counterclockwise from the angle 0. Each
+/// point is yielded once, and consecutive points of the circle are neighbours, diagonals included.
+///
+/// It walks the eight octants of the circle, stepping along the axis where the outline moves the
+/// most and choosing the farthest point of the other one still inside the filled circle.
+#[derive(Clone, PartialEq)]
+pub struct ArcPoints<T> {
+	center: Point2<T>,
+	wedge: Wedge,
+	limit: i128,
+	octant: u8,
+	step: i128,
+	height: i128,
+}
+
+impl<T: SignedNum> Iterator for ArcPoints<T> {
+	type Item = Point2<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.octant < 8 {
+			let even = self.octant % 2 == 0;
+			if !even && self.step < 0 {
+				(self.octant, self.step) = (self.octant + 1, 0);
+				continue;
+			}
+			let (step, limit) = (self.step, self.limit);
+			let inside = |height: i128| height * height + step * step <= limit;
+			while inside(self.height + 1) {
+				self.height += 1;
+			}
+			while self.height > 0 && !inside(self.height) {
+				self.height -= 1;
+			}
+			let height = self.height;
+			if even && height < step {
+				// the octant ends at the diagonal, the next one walks back from it
+				(self.octant, self.step) = (self.octant + 1, step - 1);
+				continue;
+			}
+			self.step += if even { 1 } else { -1 };
+			// the ends of the octants are shared, the axes by the odd and the diagonals by the even
+			if (even && step == 0) || (!even && step == height && step > 0) {
+				continue;
+			}
+			let vector = match self.octant {
+				0 => (height, step),
+				1 => (step, height),
+				2 => (-step, height),
+				3 => (-height, step),
+				4 => (-height, -step),
+				5 => (-step, -height),
+				6 => (step, -height),
+				_ => (height, -step),
+			};
+			if self.wedge.contains(vector) {
+				return Some((
+					narrow(wide(self.center.0) + vector.0),
+					narrow(wide(self.center.1) + vector.1),
+				));
+			}
+		}
+		None
+	}
+}
+
+impl<T: SignedNum> Debug for ArcPoints<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "ArcPoints [ {:?} ]. Octant: {}", self.center, self.octant)
+	}
+}
+
+/// Zip of a filled [Sector], yielding the pair of points at both ends of each piece of its rows in
+/// the travelled axis, like a [CircleZip]. The rows of sectors sweeping more than half of the
+/// circle can be split in two pieces, yielded one after the other.
+#[derive(Clone, PartialEq)]
+pub struct SectorZip<T> {
+	circle: CircleZip<T>,
+	center: Point2<T>,
+	wedge: Wedge,
+	axis: u8,
+	pending: Option<(Point2<T>, Point2<T>)>,
+}
+
+impl<T: SignedNum> SectorZip<T> {
+
+	/// Turns the zip into an iterator over every point between each pair of points,
+	/// both included.
+	pub fn pixels(self) -> FillIterator<Self, Point2<T>> {
+		FillIterator::new(self)
+	}
+
+	/// Turns the zip into an iterator over the [Span](crate::span::Span)s defined by each pair
+	/// of points
+	pub fn spans(self) -> SpanIterator<Self> {
+		let axis = self.axis;
+		SpanIterator::new(self, axis)
+	}
+
+}
+
+impl<T: SignedNum> Iterator for SectorZip<T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(pair) = self.pending.take() {
+			return Some(pair);
+		}
+		let other = 1 - self.axis;
+		loop {
+			let (start, end) = self.circle.next()?;
+			let row = wide(start.nth(self.axis)) - wide(self.center.nth(self.axis));
+			let half = wide(end.nth(other)) - wide(self.center.nth(other));
+			let [first, second] = self.wedge.row(row, half, self.axis).map(|range| {
+				range.map(|(from, to)| {
+					let (mut a, mut b) = (start, start);
+					a.set_nth(other, narrow(wide(self.center.nth(other)) + from));
+					b.set_nth(other, narrow(wide(self.center.nth(other)) + to));
+					(a, b)
+				})
+			});
+			if first.is_some() {
+				self.pending = second;
+				return first;
+			}
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let pending = self.pending.is_some() as usize;
+		(pending, Some(pending + 2 * self.circle.len()))
+	}
+}
+
+impl<T: SignedNum> Debug for SectorZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "SectorZip [ {:?} ]", self.circle)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::circle::CircleZip;
+	use crate::Axis;
+	use super::Sector;
+
+	#[test]
+	fn outline() {
+		for radius in 0..30i32 {
+			let mut filled = [[false; 63]; 63];
+			for (x, y) in CircleZip::new((0, 0), radius, Axis::Y).unwrap().pixels() {
+				filled[(y + 31) as usize][(x + 31) as usize] = true;
+			}
+			let mut outline = [[0; 63]; 63];
+			let arc = Sector::new((0, 0), radius, (1, 0), (2, 0)).arc();
+			// the last point closes the circle with the first one
+			let mut previous = arc.clone().last();
+			for (x, y) in arc {
+				let (column, row) = ((x + 31) as usize, (y + 31) as usize);
+				assert!(filled[row][column]);
+				assert!(radius == 0 || !(filled[row - 1][column] && filled[row + 1][column]
+					&& filled[row][column - 1] && filled[row][column + 1]));
+				let (last_x, last_y) = previous.unwrap();
+				assert_eq!((x - last_x).abs().max((y - last_y).abs()), (radius > 0) as i32);
+				previous = Some((x, y));
+				outline[row][column] += 1;
+			}
+			assert!(outline.iter().flatten().all(|count| *count <= 1));
+		}
+	}
+
+	#[test]
+	fn quarter() {
+		let sector = Sector::new((5, -5), 20, (0, 3), (-1, 0));
+		assert!(sector.arc().all(|(x, y)| x <= 5 && y >= -5));
+		assert_eq!(sector.arc().next(), Some((5, 15)));
+		assert_eq!(sector.arc().last(), Some((-15, -5)));
+		for axis in [Axis::X, Axis::Y] {
+			let pixels = sector.rasterize_along(axis).unwrap().pixels();
+			let circle = CircleZip::new((5, -5), 20, axis).unwrap().pixels();
+			assert!(pixels.eq(circle.filter(|(x, y)| *x <= 5 && *y >= -5)));
+		}
+	}
+
+	#[test]
+	fn reflex() {
+		// three quarters, leaving out the points between the diagonals with positive X
+		let sector = Sector::new((0, 0), 10, (1, 1), (1, -1));
+		let outside = |(x, y): (i32, i32)| -x < y && y < x;
+		assert!(!sector.arc().any(outside));
+		for axis in [Axis::X, Axis::Y] {
+			let circle = CircleZip::new((0, 0), 10, axis).unwrap().pixels();
+			let pixels = sector.rasterize_along(axis).unwrap().pixels();
+			assert!(pixels.eq(circle.filter(|point| !outside(*point))));
+		}
+		// the columns with positive X are split in two pieces
+		let columns = sector.rasterize_along(Axis::X).unwrap();
+		assert_eq!(columns.clone().next(), Some(((-10, -3), (-10, 3))));
+		assert_eq!(columns.filter(|(a, _)| a.0 == 1).count(), 2);
+		assert!(sector.rasterize_along(Axis::Z).is_none());
+	}
+
+}
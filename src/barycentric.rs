@@ -0,0 +1,243 @@
+//! Package with the barycentric coordinates of the points yielded by the zips
+
+use core::fmt::{Debug, Formatter};
+use crate::util::{floor_div, narrow, wide, Point};
+use crate::{Point2, Point3, SignedNum};
+
+/// Barycentric coordinates of a point relative to the three vertices of a triangle. They are kept
+/// as the weight of each vertex along with the sum of the three, so they stay exact with integers:
+/// the coordinate of the vertex `i` is `weights[i] / total`. They're products of coordinates, so
+/// they're kept as `i128` to fit the ones of any triangle.
+///
+/// The weights of a point out of the triangle are negative for the vertices it's farther from,
+/// and the total is zero if the triangle is degenerate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Barycentric {
+	pub weights: [i128; 3],
+	pub total: i128,
+}
+
+impl Barycentric {
+
+	/// Returns the value in the point of an attribute given its values in the three vertices,
+	/// rounded down. The value of the first vertex is returned if the triangle is degenerate.
+	///
+	/// ```
+	/// # use bresenham_zip::barycentric::Barycentric;
+	/// let middle = Barycentric { weights: [1, 1, 2], total: 4 };
+	/// assert_eq!(middle.interpolate([0, 100, 50]), 50);
+	/// ```
+	///
+	/// * `values` - Values of the attribute in each vertex, in the same order
+	///
+	pub fn interpolate<T: SignedNum>(&self, values: [T; 3]) -> T {
+		let total = self.total;
+		if total == 0 {
+			return values[0];
+		}
+		let sum = self.weights.iter().zip(values).map(|(w, v)| w * wide(v)).sum::<i128>();
+		narrow(if total < 0 { floor_div(-sum, -total) } else { floor_div(sum, total) })
+	}
+
+	/// Returns the value in the point of an attribute given its values in the three vertices of a
+	/// projected triangle, rounded down. The attribute is interpolated linearly in the space before
+	/// the projection, dividing it by the `w` of the vertices, so the textures and colors don't
+	/// warp with the depth like they do with [interpolate](Self::interpolate). The value of the
+	/// first vertex is returned if the triangle is degenerate.
+	///
+	/// ```
+	/// # use bresenham_zip::barycentric::Barycentric;
+	/// let middle = Barycentric { weights: [1, 1, 0], total: 2 };
+	/// assert_eq!(middle.interpolate([0, 100, 0]), 50);
+	/// // the second vertex is three times farther, so the middle of the screen is closer to the
+	/// // first one
+	/// assert_eq!(middle.interpolate_perspective([0, 100, 0], [1, 3, 1]), 25);
+	/// ```
+	///
+	/// * `values` - Values of the attribute in each vertex, in the same order
+	/// * `w` - Positive `w` of each vertex after the projection, their depth in the view space
+	///
+	pub fn interpolate_perspective<T: SignedNum>(&self, values: [T; 3], w: [T; 3]) -> T {
+		let w = w.map(wide);
+		// dividing by the w of each vertex is multiplying by the w of the other two
+		let others = [w[1] * w[2], w[0] * w[2], w[0] * w[1]];
+		let weights = self.weights;
+		let total = weights.iter().zip(others).map(|(w, other)| w * other).sum::<i128>();
+		if total == 0 {
+			return values[0];
+		}
+		let sum = weights.iter().zip(others).zip(values)
+			.map(|((w, other), value)| w * other * wide(value))
+			.sum::<i128>();
+		narrow(if total < 0 { floor_div(-sum, -total) } else { floor_div(sum, total) })
+	}
+
+}
+
+/// Returns the barycentric coordinates of the point relative to the vertices, using the
+/// coordinates `x` and `y` of the points
+fn weights<T: SignedNum, P: Point<T>>(point: &P, vertices: &[P; 3], x: u8, y: u8)
+	-> Barycentric {
+	let cross = |o: &P, a: &P, b: &P| {
+		let delta = |p: &P, i: u8| wide(p.nth(i)) - wide(o.nth(i));
+		delta(a, x) * delta(b, y) - delta(a, y) * delta(b, x)
+	};
+	let [a, b, c] = vertices;
+	Barycentric {
+		weights: [cross(b, c, point), cross(c, a, point), cross(a, b, point)],
+		total: cross(a, b, c),
+	}
+}
+
+/// Iterator yielding, along with each pair of points of a zip, their [Barycentric] coordinates
+/// relative to the vertices of the triangle, so any attribute of the vertices can be interpolated.
+///
+/// The points of the lines can be rounded out of the triangle, so the values interpolated there
+/// can exceed the ones of the vertices by a bit. The three-dimensional triangles are projected to
+/// the plane of the two axes where they're the widest, so the points rounded out of the plane of
+/// the triangle still get meaningful weights.
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+/// let zip = triangle.rasterize_along(Axis::Y).unwrap();
+/// for ((left, left_weights), (right, right_weights)) in zip.with_barycentric() {
+///   // the coordinate y of the vertices interpolates the one of the points
+///   assert_eq!(left_weights.interpolate([0, 40, 100]), left.1);
+///   assert_eq!(right_weights.interpolate([0, 40, 100]), right.1);
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct BarycentricIterator<I, P> {
+	zip: I,
+	vertices: [P; 3],
+	axes: (u8, u8),
+}
+
+impl<I, T: SignedNum> BarycentricIterator<I, Point2<T>> {
+
+	#[inline]
+	pub(crate) fn new(zip: I, vertices: [Point2<T>; 3]) -> Self {
+		Self { zip, vertices, axes: (0, 1) }
+	}
+
+}
+
+impl<I, T: SignedNum> BarycentricIterator<I, Point3<T>> {
+
+	pub(crate) fn new_3d(zip: I, vertices: [Point3<T>; 3]) -> Self {
+		let [a, b, c] = vertices.map(|(x, y, z)| (wide(x), wide(y), wide(z)));
+		let (u, v) = ((b.0 - a.0, b.1 - a.1, b.2 - a.2), (c.0 - a.0, c.1 - a.1, c.2 - a.2));
+		let normal = [
+			(u.1 * v.2 - u.2 * v.1).abs(),
+			(u.2 * v.0 - u.0 * v.2).abs(),
+			(u.0 * v.1 - u.1 * v.0).abs(),
+		];
+		// the triangle is the widest in the plane perpendicular to the greatest normal component
+		let axes = if normal[0] >= normal[1] && normal[0] >= normal[2] {
+			(1, 2)
+		} else if normal[1] >= normal[2] { (2, 0) } else { (0, 1) };
+		Self { zip, vertices, axes }
+	}
+
+}
+
+macro_rules! barycentric_iterator {
+	($($point:ident),*) => {
+		$(
+			impl<T: SignedNum, I> Iterator for BarycentricIterator<I, $point<T>>
+				where I: Iterator<Item = ($point<T>, $point<T>)> {
+				type Item = (($point<T>, Barycentric), ($point<T>, Barycentric));
+
+				fn next(&mut self) -> Option<Self::Item> {
+					let (a, b) = self.zip.next()?;
+					let (x, y) = self.axes;
+					Some((
+						(a, weights(&a, &self.vertices, x, y)),
+						(b, weights(&b, &self.vertices, x, y)),
+					))
+				}
+
+				#[inline]
+				fn size_hint(&self) -> (usize, Option<usize>) {
+					self.zip.size_hint()
+				}
+			}
+		)*
+	};
+}
+
+barycentric_iterator!(Point2, Point3);
+
+impl<I: Debug, P: Debug> Debug for BarycentricIterator<I, P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "BarycentricIterator [ {:?}, {:?}, {:?} ] {:?}",
+			self.vertices[0], self.vertices[1], self.vertices[2], self.zip)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::zip::BresenhamZip;
+	use crate::zip_3d::Bresenham3dZip;
+	use super::Barycentric;
+
+	#[test]
+	fn vertices() {
+		let mut zip = BresenhamZip::new((0, 0), (-10, 10), (10, 10), 1).with_barycentric();
+		let ((_, apex), _) = zip.next().unwrap();
+		assert_eq!(apex, Barycentric { weights: [-200, 0, 0], total: -200 });
+		let ((_, left), (_, right)) = zip.last().unwrap();
+		assert_eq!((left.weights, right.weights), ([0, -200, 0], [0, 0, -200]));
+		assert_eq!(left.interpolate([0, 10, 20]), 10);
+	}
+
+	#[test]
+	fn interpolate() {
+		let zip = BresenhamZip::new((0, 0), (-10, 10), (10, 10), 1).with_barycentric();
+		for ((a, weights_a), (b, weights_b)) in zip {
+			// the attribute is the coordinate y itself
+			assert_eq!(weights_a.interpolate([0, 10, 10]), a.1);
+			assert_eq!(weights_b.interpolate([0, 10, 10]), b.1);
+		}
+		assert_eq!(Barycentric { weights: [0, 0, 0], total: 0 }.interpolate([7, 1, 2]), 7);
+	}
+
+	#[test]
+	fn perspective() {
+		let zip = BresenhamZip::new((0, 0), (-10, 10), (10, 10), 1).with_barycentric();
+		for ((_, weights), _) in zip {
+			let affine = weights.interpolate([0, 90, -40]);
+			assert_eq!(weights.interpolate_perspective([0, 90, -40], [5, 5, 5]), affine);
+		}
+		let weights = Barycentric { weights: [-1, -2, -1], total: -4 };
+		assert_eq!(weights.interpolate_perspective([0, 60, 0], [2, 1, 2]), 40);
+		let degenerate = Barycentric { weights: [0, 0, 0], total: 0 };
+		assert_eq!(degenerate.interpolate_perspective([7, 1, 2], [1, 1, 1]), 7);
+	}
+
+	#[test]
+	fn three_dimensions() {
+		let zip = Bresenham3dZip::new((0, 0, 0), (0, 10, 20), (20, 10, 20), 2).with_barycentric();
+		for ((a, weights_a), (_, weights_b)) in zip {
+			assert_eq!(weights_a.total, weights_b.total);
+			assert_ne!(weights_a.total, 0);
+			assert_eq!(weights_a.interpolate([0, 20, 20]), a.2);
+		}
+	}
+
+	#[test]
+	fn large() {
+		let zip = BresenhamZip::<i16>::new((0, 0), (-200, 200), (200, 200), 1).with_barycentric();
+		let ((a, weights), _) = zip.last().unwrap();
+		assert_eq!(weights, Barycentric { weights: [0, -80000, 0], total: -80000 });
+		assert_eq!(weights.interpolate([0, 200, 200]), a.1);
+		let (a, b) = ((-100000, 100000, 5), (100000, 100000, -5));
+		let zip = Bresenham3dZip::<i32>::new((0, 0, 0), a, b, 1).with_barycentric();
+		for ((a, weights), _) in zip.step_by(997) {
+			assert_eq!(weights.interpolate([0, 100000, 100000]), a.1);
+		}
+	}
+
+}
@@ -0,0 +1,242 @@
+//! Package with the boolean operations of two rasterizations, combining their spans scanline by
+//! scanline so simple shapes can be added, intersected or subtracted without filling a bitmap
+
+use core::fmt::{Debug, Formatter};
+use crate::span::Span;
+use crate::util::{narrow, wide};
+use crate::SignedNum;
+
+/// Operation combining the spans of two iterators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Operation {
+	/// Keeps the pixels covered by any of the iterators
+	Union,
+	/// Keeps the pixels covered by both iterators
+	Intersection,
+	/// Keeps the pixels covered by the first iterator but not by the second one
+	Difference,
+}
+
+/// Iterator over the spans resulting from an [Operation] on the spans of two other iterators.
+/// Both of them must yield their spans sorted by their axis value and then by their start, from
+/// lowest to highest, like the ones of a [Polygon](crate::polygon::Polygon); the spans are
+/// yielded in the same order, without overlapping or touching each other.
+///
+/// ```
+/// # use bresenham_zip::{boolean::Boolean, span::Span};
+/// let square = (0..=9).map(|y| Span { axis_value: y, range: 0..=9 });
+/// let hole = (3..=6).map(|y| Span { axis_value: y, range: 3..=6 });
+/// let frame = Boolean::difference(square.clone(), hole.clone());
+/// assert_eq!(frame.map(|span| span.range.count()).sum::<usize>(), 100 - 16);
+/// let mut inner = Boolean::intersection(square, hole);
+/// assert_eq!(inner.next(), Some(Span { axis_value: 3, range: 3..=6 }));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Boolean<A, B, T> {
+	a: Runs<A, T>,
+	b: Runs<B, T>,
+	operation: Operation,
+}
+
+impl<A, B, T> Boolean<A, B, T> {
+
+	/// Creates the iterator combining the spans
+	///
+	/// * `a` - Iterator over the first spans, sorted by their axis value and start
+	/// * `b` - Iterator over the second spans, sorted by their axis value and start
+	/// * `operation` - Operation to apply
+	///
+	#[inline]
+	pub fn new(a: A, b: B, operation: Operation) -> Self {
+		Self { a: Runs::new(a), b: Runs::new(b), operation }
+	}
+
+	/// Creates the iterator over the spans covered by any of the iterators
+	#[inline]
+	pub fn union(a: A, b: B) -> Self {
+		Self::new(a, b, Operation::Union)
+	}
+
+	/// Creates the iterator over the spans covered by both iterators
+	#[inline]
+	pub fn intersection(a: A, b: B) -> Self {
+		Self::new(a, b, Operation::Intersection)
+	}
+
+	/// Creates the iterator over the spans covered by `a` but not by `b`
+	#[inline]
+	pub fn difference(a: A, b: B) -> Self {
+		Self::new(a, b, Operation::Difference)
+	}
+
+}
+
+impl<A, B, T> Boolean<A, B, T>
+	where T: SignedNum, A: Iterator<Item = Span<T>>, B: Iterator<Item = Span<T>> {
+
+	fn union_next(&mut self) -> Option<Run<T>> {
+		let (value, start, mut end) = match (self.a.peek(), self.b.peek()) {
+			(Some(a), Some(b)) if (b.0, b.1) < (a.0, a.1) => self.b.take(),
+			(Some(_), _) => self.a.take(),
+			(None, _) => self.b.take(),
+		}?;
+		// the runs of each iterator are already joined, so they alternate extending the run
+		loop {
+			if let Some(run) = self.a.peek().filter(|run| joins(run, value, end)) {
+				self.a.take();
+				end = end.max(run.2);
+			} else if let Some(run) = self.b.peek().filter(|run| joins(run, value, end)) {
+				self.b.take();
+				end = end.max(run.2);
+			} else {
+				return Some((value, start, end));
+			}
+		}
+	}
+
+	fn intersection_next(&mut self) -> Option<Run<T>> {
+		loop {
+			let (a, b) = (self.a.peek()?, self.b.peek()?);
+			if before(&a, &b) {
+				self.a.take();
+			} else if before(&b, &a) {
+				self.b.take();
+			} else {
+				// the run ending first can't overlap the next one of the other iterator
+				if a.2 <= b.2 { self.a.take() } else { self.b.take() };
+				return Some((a.0, a.1.max(b.1), a.2.min(b.2)));
+			}
+		}
+	}
+
+	fn difference_next(&mut self) -> Option<Run<T>> {
+		loop {
+			let a = self.a.peek()?;
+			let Some(b) = self.b.peek().filter(|b| !before(&a, b)) else {
+				return self.a.take();
+			};
+			if before(&b, &a) {
+				self.b.take();
+				continue;
+			}
+			// the part of a after b is kept to be trimmed by the next runs of b
+			if b.2 < a.2 {
+				self.a.pending = Some((a.0, b.2 + 1, a.2));
+			} else {
+				self.a.take();
+			}
+			if b.1 > a.1 {
+				return Some((a.0, a.1, b.1 - 1));
+			}
+		}
+	}
+
+}
+
+impl<A, B, T> Iterator for Boolean<A, B, T>
+	where T: SignedNum, A: Iterator<Item = Span<T>>, B: Iterator<Item = Span<T>> {
+	type Item = Span<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (axis_value, start, end) = match self.operation {
+			Operation::Union => self.union_next(),
+			Operation::Intersection => self.intersection_next(),
+			Operation::Difference => self.difference_next(),
+		}?;
+		Some(Span { axis_value, range: narrow(start)..=narrow(end) })
+	}
+}
+
+impl<A: Debug, B: Debug, T> Debug for Boolean<A, B, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Boolean [ {:?}, {:?} ]. Operation: {:?}", self.a.spans, self.b.spans,
+			self.operation)
+	}
+}
+
+/// Axis value and bounds of a span, widened so the values next to them can't overflow
+type Run<T> = (T, i128, i128);
+
+/// Spans of an iterator joining the consecutive ones that overlap or touch
+#[derive(Clone, PartialEq)]
+struct Runs<I, T> {
+	spans: I,
+	/// Next run, already joined
+	pending: Option<Run<T>>,
+	/// Span following the pending run
+	next: Option<Span<T>>,
+}
+
+impl<I, T> Runs<I, T> {
+
+	#[inline]
+	fn new(spans: I) -> Self {
+		Self { spans, pending: None, next: None }
+	}
+
+}
+
+impl<T: SignedNum, I: Iterator<Item = Span<T>>> Runs<I, T> {
+
+	/// Returns the next run without consuming it
+	fn peek(&mut self) -> Option<Run<T>> {
+		if self.pending.is_none() {
+			let span = self.next.take().or_else(|| self.spans.next())?;
+			let (start, end) = span.range.into_inner();
+			let (value, start, mut end) = (span.axis_value, wide(start), wide(end));
+			for span in self.spans.by_ref() {
+				let run = (span.axis_value, wide(*span.range.start()), wide(*span.range.end()));
+				if !joins(&run, value, end) {
+					self.next = Some(span);
+					break;
+				}
+				end = end.max(run.2);
+			}
+			self.pending = Some((value, start, end));
+		}
+		self.pending
+	}
+
+	/// Consumes the next run
+	fn take(&mut self) -> Option<Run<T>> {
+		let run = self.peek();
+		self.pending = None;
+		run
+	}
+
+}
+
+/// Checks if the run overlaps or touches the one with the given axis value ending in `end`
+fn joins<T: SignedNum>(run: &Run<T>, value: T, end: i128) -> bool {
+	run.0 == value && run.1 <= end + 1
+}
+
+/// Checks if the run `a` ends before the run `b` starts
+fn before<T: SignedNum>(a: &Run<T>, b: &Run<T>) -> bool {
+	a.0 < b.0 || (a.0 == b.0 && a.2 < b.1)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::span::Span;
+	use super::Boolean;
+
+	#[test]
+	fn operations() {
+		let span = |(axis_value, range)| Span { axis_value, range };
+		let a = [(0, 0..=4), (0, 6..=9), (1, -5..=5), (2, 0..=0), (2, 1..=3)].map(span).into_iter();
+		let b = [(0, 3..=7), (1, -9..=-7), (1, -2..=-1), (1, 4..=4), (1, 8..=9), (3, 0..=9)];
+		let b = b.map(span).into_iter();
+		let union = [(0, 0..=9), (1, -9..=-7), (1, -5..=5), (1, 8..=9), (2, 0..=3), (3, 0..=9)];
+		assert!(Boolean::union(a.clone(), b.clone()).eq(union.map(span)));
+		let intersection = [(0, 3..=4), (0, 6..=7), (1, -2..=-1), (1, 4..=4)];
+		assert!(Boolean::intersection(a.clone(), b.clone()).eq(intersection.map(span)));
+		let difference = [(0, 0..=2), (0, 8..=9), (1, -5..=-3), (1, 0..=3), (1, 5..=5), (2, 0..=3)];
+		assert!(Boolean::difference(a.clone(), b.clone()).eq(difference.map(span)));
+		let difference = Boolean::difference(b, a).map(|span| span.axis_value);
+		assert!(difference.eq([0, 1, 1, 3]));
+	}
+
+}
@@ -0,0 +1,111 @@
+//! Package with the preview of rasterized triangles in the terminal with braille characters.
+//! Requires the `std` feature.
+
+use core::fmt::{Display, Formatter, Write};
+use std::vec;
+use std::vec::Vec;
+use crate::sink::SpanSink;
+use crate::triangle::Triangle2;
+use crate::{Axis, SignedNum};
+
+/// Bit of the dot in each column and row of a braille cell, of two columns and four rows
+const DOTS: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// Canvas of sub-pixels displayed as Unicode braille characters, each one showing 2x4 of them,
+/// for compact previews in the terminal. It's a [SpanSink], so any zip can be rasterized into it,
+/// with `true` setting the sub-pixels and `false` clearing them. The sub-pixels out of the canvas
+/// are ignored.
+///
+/// ```
+/// # use bresenham_zip::{braille::BrailleCanvas, triangle::Triangle2};
+/// let mut canvas = BrailleCanvas::new(8, 8);
+/// canvas.draw(&Triangle2::new((0, 0), (7, 0), (0, 7)));
+/// assert_eq!(canvas.to_string(), "⣿⣿⡿⠋\n⡿⠋⠀⠀");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrailleCanvas {
+	width: usize,
+	height: usize,
+	cells: Vec<u8>,
+}
+
+impl BrailleCanvas {
+
+	/// Creates the empty canvas
+	///
+	/// * `width`, `height` - Size of the canvas in sub-pixels
+	///
+	pub fn new(width: usize, height: usize) -> Self {
+		Self { width, height, cells: vec![0; width.div_ceil(2) * height.div_ceil(4)] }
+	}
+
+	/// Sets the sub-pixels of the triangle rasterized along [Axis::Y]
+	pub fn draw<T: SignedNum>(&mut self, triangle: &Triangle2<T>) {
+		if let Some(zip) = triangle.rasterize_along(Axis::Y) {
+			zip.rasterize_into(self, true);
+		}
+	}
+
+	/// Clears every sub-pixel
+	pub fn clear(&mut self) {
+		self.cells.fill(0);
+	}
+
+}
+
+impl<T: SignedNum> SpanSink<T, bool> for BrailleCanvas {
+	fn fill_span(&mut self, y: T, x0: T, x1: T, value: bool) {
+		let (Some(y), Some(x1)) = (y.to_usize(), x1.to_usize()) else {
+			return;
+		};
+		let (x0, x1) = (x0.to_usize().unwrap_or_default(), x1.min(self.width.saturating_sub(1)));
+		if y >= self.height || x0 > x1 || x0 >= self.width {
+			return;
+		}
+		let columns = self.width.div_ceil(2);
+		for x in x0..=x1 {
+			let (cell, dot) = ((y / 4) * columns + x / 2, DOTS[x % 2][y % 4]);
+			if value {
+				self.cells[cell] |= dot;
+			} else {
+				self.cells[cell] &= !dot;
+			}
+		}
+	}
+}
+
+impl Display for BrailleCanvas {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		let columns = self.width.div_ceil(2).max(1);
+		for (i, row) in self.cells.chunks(columns).enumerate() {
+			if i > 0 {
+				f.write_char('\n')?;
+			}
+			for cell in row {
+				f.write_char(char::from_u32(0x2800 + *cell as u32).unwrap_or(' '))?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::string::ToString;
+	use crate::sink::SpanSink;
+	use super::BrailleCanvas;
+
+	#[test]
+	fn dots() {
+		let mut canvas = BrailleCanvas::new(3, 5);
+		canvas.fill_span(0, 0, 0, true);
+		canvas.fill_span(3, -4, 9, true);
+		canvas.fill_span(4, 2, 2, true);
+		canvas.fill_span(3, 1, 1, false);
+		canvas.fill_span(5, 0, 2, true);
+		assert_eq!(canvas.to_string(), "\u{2841}\u{2840}\n\u{2800}\u{2801}");
+		canvas.clear();
+		assert_eq!(canvas.to_string(), "\u{2800}\u{2800}\n\u{2800}\u{2800}");
+	}
+
+}
@@ -3,8 +3,12 @@
 ///
 /// `<dimension>:<axis> - <starting point> -> <first ending point>, <second ending point>`
 ///
-/// Being: `dimension` one of `2` or `3`, axis: `x`|`y`|`z` and the points tuples of [crate::Point2]
-/// or [crate::Point3] depending of the dimension of the Zip to create.
+/// Being: `dimension` one of `2D` or `3D`, axis: `X`|`Y`|`Z` and the points tuples of
+/// [crate::Point2] or [crate::Point3] depending of the dimension of the Zip to create, or arrays
+/// like `[50, 50]`. The points can be any expression, like variables, indexed vertices or
+/// computed tuples. Both the dimension and the axis can be written in lowercase, and the
+/// dimension can be left out when the starting point is written as a tuple or an array, taking
+/// it from its number of coordinates.
 ///
 /// # Example
 /// ```
@@ -14,49 +18,97 @@
 /// let zip_2d_x = bresenham_zip::build_zip!(2D:X - (50, 50) -> (100, 0), (100, 100))?;
 /// // 3D Zip to iterate through Z
 /// let zip_3d_z = bresenham_zip::build_zip!(3D:Z - (50, 50, 50) -> (0, 0, 100), (25, 50, 100))?;
+/// // Zip built from runtime data
+/// let vertices = [(50, 50), (100, 0), (100, 100)];
+/// let x0 = 40;
+/// let zip = bresenham_zip::build_zip!(2D:X - (x0 + 10, 50) -> vertices[1], vertices[2])?;
+/// // 3D Zip to iterate through Y, with the dimension inferred
+/// let zip_3d_y = bresenham_zip::build_zip!(y - (0, 0, 0) -> (0, 10, 0), (10, 10, 10))?;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// The points must have as many coordinates as the dimension of the zip:
+/// ```compile_fail
+/// let zip = bresenham_zip::build_zip!(2d:x - (50, 50, 50) -> (100, 0), (100, 100));
+/// ```
 #[macro_export]
 macro_rules! build_zip {
-    (2D:X - $start:tt -> $end_a:tt, $end_b:tt) => {
-	    $crate::zip::Builder::new()
-		      .axis($crate::Axis::X)
-					.start_point($start)
-					.first_ending_point($end_a)
-					.second_ending_point($end_b)
-					.build()
-    };
-    (2D:Y - $start:tt -> $end_a:tt, $end_b:tt) => {
-	    $crate::zip::Builder::new()
-		      .axis($crate::Axis::Y)
-					.start_point($start)
-					.first_ending_point($end_a)
-					.second_ending_point($end_b)
-					.build()
-    };
-    (3D:X - $start:tt -> $end_a:tt, $end_b:tt) => {
-	    $crate::zip_3d::Builder3d::new()
-		      .axis($crate::Axis::X)
-					.start_point($start)
-					.first_ending_point($end_a)
-					.second_ending_point($end_b)
-					.build()
-    };
-    (3D:Y - $start:tt -> $end_a:tt, $end_b:tt) => {
-	    $crate::zip_3d::Builder3d::new()
-		      .axis($crate::Axis::Y)
-					.start_point($start)
-					.first_ending_point($end_a)
-					.second_ending_point($end_b)
-					.build()
-    };
-    (3D:Z - $start:tt -> $end_a:tt, $end_b:tt) => {
-	    $crate::zip_3d::Builder3d::new()
-		      .axis($crate::Axis::Z)
-					.start_point($start)
-					.first_ending_point($end_a)
-					.second_ending_point($end_b)
-					.build()
-    };
-}
\ No newline at end of file
+	(2D:$axis:ident - $($rest:tt)+) => {
+		$crate::build_zip!(@axis (zip::Builder, 2) $axis $($rest)+)
+	};
+	(2d:$axis:ident - $($rest:tt)+) => {
+		$crate::build_zip!(@axis (zip::Builder, 2) $axis $($rest)+)
+	};
+	(3D:$axis:ident - $($rest:tt)+) => {
+		$crate::build_zip!(@axis (zip_3d::Builder3d, 3) $axis $($rest)+)
+	};
+	(3d:$axis:ident - $($rest:tt)+) => {
+		$crate::build_zip!(@axis (zip_3d::Builder3d, 3) $axis $($rest)+)
+	};
+	($axis:ident - $($rest:tt)+) => {
+		$crate::build_zip!(@axis (inferred) $axis $($rest)+)
+	};
+	(@axis $zip:tt X $($rest:tt)+) => { $crate::build_zip!(@dimension $zip X $($rest)+) };
+	(@axis $zip:tt x $($rest:tt)+) => { $crate::build_zip!(@dimension $zip X $($rest)+) };
+	(@axis $zip:tt Y $($rest:tt)+) => { $crate::build_zip!(@dimension $zip Y $($rest)+) };
+	(@axis $zip:tt y $($rest:tt)+) => { $crate::build_zip!(@dimension $zip Y $($rest)+) };
+	(@axis $zip:tt Z $($rest:tt)+) => { $crate::build_zip!(@dimension $zip Z $($rest)+) };
+	(@axis $zip:tt z $($rest:tt)+) => { $crate::build_zip!(@dimension $zip Z $($rest)+) };
+	(@axis $zip:tt $axis:ident $($rest:tt)+) => {
+		compile_error!(concat!("build_zip!: unknown axis `", stringify!($axis), "`, use X, Y or Z"))
+	};
+	// the dimension is checked against the number of coordinates of the starting point, when
+	// it's written as a tuple or an array
+	(@dimension (zip::Builder, 2) Z $($rest:tt)+) => {
+		compile_error!("build_zip!: the 2D zips can only travel the X and Y axes")
+	};
+	(@dimension (zip::Builder, 2) $axis:ident ($a:expr, $b:expr, $c:expr) -> $($rest:tt)+) => {
+		compile_error!("build_zip!: the points of the 2D zips must have two coordinates")
+	};
+	(@dimension (zip::Builder, 2) $axis:ident [$a:expr, $b:expr, $c:expr] -> $($rest:tt)+) => {
+		compile_error!("build_zip!: the points of the 2D zips must have two coordinates")
+	};
+	(@dimension (zip_3d::Builder3d, 3) $axis:ident ($a:expr, $b:expr) -> $($rest:tt)+) => {
+		compile_error!("build_zip!: the points of the 3D zips must have three coordinates")
+	};
+	(@dimension (zip_3d::Builder3d, 3) $axis:ident [$a:expr, $b:expr] -> $($rest:tt)+) => {
+		compile_error!("build_zip!: the points of the 3D zips must have three coordinates")
+	};
+	(@dimension (inferred) Z $($rest:tt)+) => {
+		$crate::build_zip!(@dimension (zip_3d::Builder3d, 3) Z $($rest)+)
+	};
+	(@dimension (inferred) $axis:ident ($a:expr, $b:expr) -> $($rest:tt)+) => {
+		$crate::build_zip!(@start (zip::Builder, 2) $axis [($a, $b)] -> $($rest)+)
+	};
+	(@dimension (inferred) $axis:ident [$a:expr, $b:expr] -> $($rest:tt)+) => {
+		$crate::build_zip!(@start (zip::Builder, 2) $axis [[$a, $b]] -> $($rest)+)
+	};
+	(@dimension (inferred) $axis:ident ($a:expr, $b:expr, $c:expr) -> $($rest:tt)+) => {
+		$crate::build_zip!(@start (zip_3d::Builder3d, 3) $axis [($a, $b, $c)] -> $($rest)+)
+	};
+	(@dimension (inferred) $axis:ident [$a:expr, $b:expr, $c:expr] -> $($rest:tt)+) => {
+		$crate::build_zip!(@start (zip_3d::Builder3d, 3) $axis [[$a, $b, $c]] -> $($rest)+)
+	};
+	(@dimension (inferred) $axis:ident $($rest:tt)+) => {
+		compile_error!(concat!("build_zip!: the dimension can't be inferred from the starting ",
+			"point, write it before the axis like `2D:X` or `3D:X`"))
+	};
+	(@dimension $zip:tt $axis:ident $($rest:tt)+) => {
+		$crate::build_zip!(@start $zip $axis [] $($rest)+)
+	};
+	// the starting point can't be an `expr` followed by `->`, so its tokens are collected one by
+	// one until the arrow
+	(@start ($module:ident::$builder:ident, $dimension:tt) $axis:ident [$($start:tt)+]
+		-> $end_a:expr, $end_b:expr) => {
+		$crate::$module::$builder::new()
+			.axis($crate::Axis::$axis)
+			.start_point($($start)+)
+			.first_ending_point($end_a)
+			.second_ending_point($end_b)
+			.build()
+	};
+	(@start $zip:tt $axis:ident [$($start:tt)*] $next:tt $($rest:tt)*) => {
+		$crate::build_zip!(@start $zip $axis [$($start)* $next] $($rest)*)
+	};
+}
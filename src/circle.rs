@@ -0,0 +1,150 @@
+//! Package with the logic to rasterize filled circles
+
+use core::fmt::{Debug, Formatter};
+use crate::fill::FillIterator;
+use crate::span::SpanIterator;
+use crate::util::{narrow, wide, Point};
+use crate::{Axis, Error, Point2, SignedNum};
+
+/// Zip of a filled circle, yielding the pair of points at both ends of each of its rows in the
+/// travelled axis, the same way the zips of the triangles do. The rows are computed with the
+/// integer midpoint criterion, a point `(x, y)` away from the center being inside the circle if
+/// `x² + y² <= r² + r`, the same one used by the outlines of the [arc](crate::arc) module.
+///
+/// ```
+/// # use bresenham_zip::{Axis, circle::CircleZip};
+/// let mut zip = CircleZip::new((10, 10), 3, Axis::Y).unwrap();
+/// assert_eq!(zip.len(), 7);
+/// assert_eq!(zip.next(), Some(((9, 7), (11, 7))));
+/// assert_eq!(zip.nth(2), Some(((7, 10), (13, 10))));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct CircleZip<T> {
+	center: Point2<T>,
+	radius: i128,
+	offset: i128,
+	half: i128,
+	axis: u8,
+}
+
+impl<T: SignedNum> CircleZip<T> {
+
+	/// Creates the zip of the circle going from its lowest row in the axis to its highest one. A
+	/// radius of zero yields only the center, and a negative one nothing.
+	///
+	/// * `center` - Center of the circle
+	/// * `radius` - Distance from the center to the edge, in pixels
+	/// * `axis` - Axis to use in the iteration
+	///
+	/// # Error
+	/// [Error::MissingAxis], if the axis is [Axis::Z].
+	///
+	pub fn new(center: Point2<T>, radius: T, axis: Axis) -> Result<Self, Error<T>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => return Err(Error::MissingAxis),
+		};
+		let radius = wide(radius);
+		Ok(Self { center, radius, offset: -radius, half: 0, axis })
+	}
+
+	/// Turns the zip into an iterator over every point between each pair of points,
+	/// both included.
+	pub fn pixels(self) -> FillIterator<Self, Point2<T>> {
+		FillIterator::new(self)
+	}
+
+	/// Turns the zip into an iterator over the [Span](crate::span::Span)s defined by each pair
+	/// of points
+	pub fn spans(self) -> SpanIterator<Self> {
+		let axis = self.axis;
+		SpanIterator::new(self, axis)
+	}
+
+}
+
+impl<T: SignedNum> Iterator for CircleZip<T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.offset > self.radius {
+			return None;
+		}
+		// the half width grows until the center and shrinks after it, one step at a time
+		let rest = self.radius * self.radius + self.radius - self.offset * self.offset;
+		while (self.half + 1) * (self.half + 1) <= rest {
+			self.half += 1;
+		}
+		while self.half * self.half > rest {
+			self.half -= 1;
+		}
+		let (mut a, mut b) = (self.center, self.center);
+		let row = narrow(wide(self.center.nth(self.axis)) + self.offset);
+		let other = 1 - self.axis;
+		a.set_nth(self.axis, row);
+		b.set_nth(self.axis, row);
+		a.set_nth(other, narrow(wide(self.center.nth(other)) - self.half));
+		b.set_nth(other, narrow(wide(self.center.nth(other)) + self.half));
+		self.offset += 1;
+		Some((a, b))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = (self.radius - self.offset + 1).max(0) as usize;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for CircleZip<T> {}
+
+impl<T: SignedNum> Debug for CircleZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "CircleZip [ {:?}, {} ]. Remaining: {}", self.center, self.radius, self.len())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Axis, Error};
+	use super::CircleZip;
+
+	#[test]
+	fn rows() {
+		let zip = CircleZip::new((0, 0), 5, Axis::Y).unwrap();
+		let halves = zip.map(|(a, b)| {
+			assert_eq!((a.1, a.0), (b.1, -b.0));
+			b.0
+		});
+		assert!(halves.eq([2, 3, 4, 5, 5, 5, 5, 5, 4, 3, 2]));
+		let mut zip = CircleZip::new((-3, 8), 5, Axis::X).unwrap();
+		assert_eq!(zip.nth(5), Some(((-3, 3), (-3, 13))));
+		assert_eq!(zip.last(), Some(((2, 6), (2, 10))));
+	}
+
+	#[test]
+	fn symmetric() {
+		for radius in 0..40 {
+			let zip = CircleZip::new((0, 0), radius, Axis::Y).unwrap();
+			let mut covered = [[false; 81]; 81];
+			for (x, y) in zip.pixels() {
+				covered[(y + 40) as usize][(x + 40) as usize] = true;
+			}
+			for (y, row) in covered.iter().enumerate() {
+				for (x, pixel) in row.iter().enumerate() {
+					assert_eq!(*pixel, covered[x][y]);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn degenerate() {
+		let zip = CircleZip::new((4, 4), 0, Axis::Y).unwrap();
+		assert!(zip.eq([((4, 4), (4, 4))]));
+		assert_eq!(CircleZip::new((4, 4), -1, Axis::X).unwrap().len(), 0);
+		assert_eq!(CircleZip::new((4, 4), 1, Axis::Z).unwrap_err(), Error::MissingAxis);
+	}
+
+}
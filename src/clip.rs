@@ -0,0 +1,411 @@
+//! Package with the clipping of the zips against axis-aligned rectangles and boxes
+
+mod clip_3d;
+
+use core::fmt::{Debug, Formatter};
+use crate::fill::{Fillable, FillIterator};
+use crate::util::{floor_div, narrow, point_at, wide, Point};
+use crate::{Point2, Point3, SignedNum};
+
+pub use clip_3d::{clip_triangle, clip_triangle_to_box, ClippedTriangle, HalfSpace};
+
+/// Axis-aligned rectangle or box defined by its lowest and highest corners, both included. It
+/// matches the bounding boxes returned by the triangles.
+pub type Aabb<P> = (P, P);
+
+/// Iterator clipping each pair of points yielded by a zip to an [Aabb]. The pairs fully out of
+/// it are dropped and the ones partially inside are trimmed to the part inside, so the triangles
+/// can be rendered in windows or tiles.
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+/// for (a, b) in triangle.rasterize_along(Axis::Y).unwrap().clip_rect(((10, 10), (50, 50))) {
+///   assert!((10..=50).contains(&a.0) && (10..=50).contains(&b.0));
+///   assert!((10..=50).contains(&a.1));
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ClipRect<I, P> {
+	zip: I,
+	aabb: Aabb<P>,
+}
+
+impl<I, P> ClipRect<I, P> {
+
+	/// Creates the iterator clipping the pairs of any zip
+	///
+	/// * `zip` - Iterator over the pairs of points to clip
+	/// * `aabb` - Lowest and highest corners of the rectangle or box to clip to
+	///
+	pub fn new(zip: I, aabb: Aabb<P>) -> Self {
+		Self { zip, aabb }
+	}
+
+	/// Turns the iterator into an iterator over every point between each clipped pair of points
+	pub fn pixels(self) -> FillIterator<Self, P> where P: Fillable {
+		FillIterator::new(self)
+	}
+
+}
+
+impl<T: SignedNum, I: Iterator<Item = (Point2<T>, Point2<T>)>> Iterator for ClipRect<I, Point2<T>> {
+	type Item = I::Item;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.zip.by_ref().find_map(|(a, b)| clip_segment(a, b, &self.aabb))
+	}
+}
+
+impl<T, I> DoubleEndedIterator for ClipRect<I, Point2<T>>
+	where T: SignedNum, I: DoubleEndedIterator<Item = (Point2<T>, Point2<T>)> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.zip.by_ref().rev().find_map(|(a, b)| clip_segment(a, b, &self.aabb))
+	}
+}
+
+impl<T: SignedNum, I: Iterator<Item = (Point3<T>, Point3<T>)>> Iterator for ClipRect<I, Point3<T>> {
+	type Item = I::Item;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.zip.by_ref().find_map(|(a, b)| clip_segment(a, b, &self.aabb))
+	}
+}
+
+impl<T, I> DoubleEndedIterator for ClipRect<I, Point3<T>>
+	where T: SignedNum, I: DoubleEndedIterator<Item = (Point3<T>, Point3<T>)> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.zip.by_ref().rev().find_map(|(a, b)| clip_segment(a, b, &self.aabb))
+	}
+}
+
+impl<I: Debug, P: Debug> Debug for ClipRect<I, P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "ClipRect [ {:?} ] to {:?}", self.zip, self.aabb)
+	}
+}
+
+/// Half of the plane at the left of the directed line going from `from` to `to`, the line
+/// included. The left is the counterclockwise side in a system with the Y axis up, so the other
+/// half is kept swapping the points. If both points are the same, the whole plane is kept.
+///
+/// ```
+/// # use bresenham_zip::clip::HalfPlane;
+/// let below = HalfPlane::new((10, 5), (0, 5));
+/// assert!(below.contains((3, 5)) && below.contains((-20, 0)) && !below.contains((3, 6)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct HalfPlane<T> {
+	pub from: Point2<T>,
+	pub to: Point2<T>,
+}
+
+impl<T: SignedNum> HalfPlane<T> {
+
+	/// Creates the half of the plane at the left of the line `from -> to`
+	pub fn new(from: Point2<T>, to: Point2<T>) -> Self {
+		Self { from, to }
+	}
+
+	/// Checks if the point is inside the half of the plane or on its line
+	pub fn contains(&self, point: Point2<T>) -> bool {
+		self.side(point) >= 0
+	}
+
+	/// Returns a value growing with the distance to the line on the side kept, and negative on
+	/// the other one
+	pub(crate) fn side(&self, point: Point2<T>) -> i128 {
+		let (dx, dy) = self.direction();
+		dx * (wide(point.1) - wide(self.from.1)) - dy * (wide(point.0) - wide(self.from.0))
+	}
+
+	/// Returns the vector going from `from` to `to`
+	pub(crate) fn direction(&self) -> (i128, i128) {
+		(wide(self.to.0) - wide(self.from.0), wide(self.to.1) - wide(self.from.1))
+	}
+
+	/// Trims the pair of points sharing the value of the axis to the part inside, computing where
+	/// the line crosses it, or returns `None` if the pair is fully out. The points keep their
+	/// order.
+	pub(crate) fn trim(&self, a: Point2<T>, b: Point2<T>, axis: u8)
+		-> Option<(Point2<T>, Point2<T>)> {
+		let other = 1 - axis;
+		let (dx, dy) = self.direction();
+		// the side grows `slope` with each unit of the other axis along the pair
+		let slope = if other == 0 { -dy } else { dx };
+		let (value_a, value_b) = (wide(a.nth(other)), wide(b.nth(other)));
+		let base = self.side(a) - slope * value_a;
+		let (mut low, mut high) = (value_a.min(value_b), value_a.max(value_b));
+		match slope.signum() {
+			0 if base < 0 => return None,
+			1 => low = low.max(-(base.div_euclid(slope))),
+			-1 => high = high.min(base.div_euclid(-slope)),
+			_ => (),
+		}
+		if low > high {
+			return None;
+		}
+		let (mut a, mut b) = (a, b);
+		let (first, last) = if value_a <= value_b { (low, high) } else { (high, low) };
+		a.set_nth(other, narrow(first));
+		b.set_nth(other, narrow(last));
+		Some((a, b))
+	}
+
+}
+
+/// Clips the segment `a -> b` to the rectangle with the Cohen–Sutherland algorithm, so the
+/// edges of a triangle can be clipped to the viewport before building its zips. The points
+/// moved to the borders of the rectangle have their other coordinate truncated.
+///
+/// ```
+/// # use bresenham_zip::clip::clip_line;
+/// assert_eq!(clip_line((-10, 5), (30, 5), ((0, 0), (20, 20))), Some(((0, 5), (20, 5))));
+/// assert_eq!(clip_line((-10, 5), (-2, 30), ((0, 0), (20, 20))), None);
+/// ```
+///
+/// * `a`, `b` - Ends of the segment
+/// * `rect` - Lowest and highest corners of the rectangle
+///
+/// Returns the ends of the part of the segment inside the rectangle, in the same order, or
+/// `None` if the segment is fully out of it.
+///
+pub fn clip_line<T: SignedNum>(a: Point2<T>, b: Point2<T>, rect: Aabb<Point2<T>>)
+	-> Option<(Point2<T>, Point2<T>)> {
+	let (mut a, mut b) = (a, b);
+	loop {
+		let (code_a, code_b) = (outcode(a, &rect), outcode(b, &rect));
+		if code_a | code_b == INSIDE {
+			return Some((a, b));
+		}
+		if code_a & code_b != INSIDE {
+			return None;
+		}
+		// one of the points out of the rectangle is moved to the border it's beyond
+		let code = if code_a != INSIDE { code_a } else { code_b };
+		let (axis, value) = if code & LEFT != INSIDE {
+			(0, rect.0.0)
+		} else if code & RIGHT != INSIDE {
+			(0, rect.1.0)
+		} else if code & BOTTOM != INSIDE {
+			(1, rect.0.1)
+		} else {
+			(1, rect.1.1)
+		};
+		if code == code_a {
+			a = point_at(a, b, axis, value);
+		} else {
+			b = point_at(a, b, axis, value);
+		}
+	}
+}
+
+/// Clips the polygon to the rectangle with the Sutherland–Hodgman algorithm, so the parts of the
+/// polygon out of the viewport are removed before rasterizing it with
+/// [Polygon](crate::polygon::Polygon). The polygon must be convex for the result to be exact,
+/// as concave polygons may get degenerate edges along the borders of the rectangle.
+/// Requires the `alloc` feature.
+///
+/// ```
+/// # use bresenham_zip::clip::clip_polygon;
+/// let clipped = clip_polygon(&[(0, 0), (20, 0), (0, 20)], ((5, 5), (30, 30)));
+/// assert_eq!(clipped, vec![(5, 5), (15, 5), (5, 15)]);
+/// ```
+///
+/// * `vertices` - Vertices of the polygon, the last one is joined with the first one
+/// * `rect` - Lowest and highest corners of the rectangle
+///
+/// Returns the vertices of the clipped polygon, which is empty if the polygon is fully out of
+/// the rectangle. The points added at the borders of the rectangle have their other coordinate
+/// truncated.
+///
+#[cfg(feature = "alloc")]
+pub fn clip_polygon<T: SignedNum>(vertices: &[Point2<T>], rect: Aabb<Point2<T>>)
+	-> alloc::vec::Vec<Point2<T>> {
+	let (min, max) = rect;
+	// each border is defined by its axis, its value and if the inside is above it
+	let borders = [(0, min.0, true), (0, max.0, false), (1, min.1, true), (1, max.1, false)];
+	let mut output = alloc::vec::Vec::from(vertices);
+	for (axis, value, above) in borders {
+		let input = core::mem::take(&mut output);
+		let inside = |point: &Point2<T>| {
+			if above { point.nth(axis) >= value } else { point.nth(axis) <= value }
+		};
+		for (i, current) in input.iter().enumerate() {
+			let previous = &input[(i + input.len() - 1) % input.len()];
+			if inside(current) != inside(previous) {
+				output.push(point_at(*previous, *current, axis, value));
+			}
+			if inside(current) {
+				output.push(*current);
+			}
+		}
+	}
+	output
+}
+
+const INSIDE: u8 = 0;
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const BOTTOM: u8 = 4;
+const TOP: u8 = 8;
+
+/// Returns the Cohen–Sutherland region code of the point, with a bit for each border of the
+/// rectangle it's beyond
+fn outcode<T: SignedNum>(point: Point2<T>, rect: &Aabb<Point2<T>>) -> u8 {
+	let (min, max) = rect;
+	let mut code = INSIDE;
+	if point.0 < min.0 {
+		code |= LEFT;
+	} else if point.0 > max.0 {
+		code |= RIGHT;
+	}
+	if point.1 < min.1 {
+		code |= BOTTOM;
+	} else if point.1 > max.1 {
+		code |= TOP;
+	}
+	code
+}
+
+/// Fraction `numerator / denominator` of a segment, with a positive denominator, widened so the
+/// products can't overflow
+type Fraction = (i128, i128);
+
+/// Returns the part of the segment `a -> b` inside the box, rounding its ends to the closest
+/// points, or `None` if it's fully out of the box
+pub(crate) fn clip_segment<T, P>(a: P, b: P, aabb: &Aabb<P>) -> Option<(P, P)>
+	where T: SignedNum, P: Point<T> + Copy {
+	let (min, max) = aabb;
+	let (mut enter, mut exit) = ((0, 1), (1, 1));
+	for i in 0..P::DIMENSIONS {
+		let (from, delta) = (wide(a.nth(i)), wide(b.nth(i)) - wide(a.nth(i)));
+		let (min, max) = (wide(min.nth(i)), wide(max.nth(i)));
+		if delta == 0 {
+			if from < min || from > max {
+				return None;
+			}
+			continue;
+		}
+		// portions of the segment where it enters and leaves the slab of this coordinate
+		let (low, high) = if delta > 0 {
+			((min - from, delta), (max - from, delta))
+		} else {
+			((from - max, -delta), (from - min, -delta))
+		};
+		if greater(low, enter) {
+			enter = low;
+		}
+		if greater(exit, high) {
+			exit = high;
+		}
+	}
+	if greater(enter, exit) {
+		return None;
+	}
+	Some((point_along(a, b, enter), point_along(a, b, exit)))
+}
+
+/// Checks if the fraction `a` is greater than the fraction `b`
+fn greater(a: Fraction, b: Fraction) -> bool {
+	a.0 * b.1 > b.0 * a.1
+}
+
+/// Returns the point placed at the given portion of the segment `a -> b`, rounded
+fn point_along<T: SignedNum, P: Point<T> + Copy>(a: P, b: P, portion: Fraction) -> P {
+	let (numerator, denominator) = portion;
+	let mut point = a;
+	for i in 0..P::DIMENSIONS {
+		let delta = (wide(b.nth(i)) - wide(a.nth(i))) * numerator;
+		let offset = floor_div(2 * delta + denominator, 2 * denominator);
+		point.set_nth(i, narrow(wide(a.nth(i)) + offset));
+	}
+	point
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::triangle::Triangle2;
+	use crate::zip::BresenhamZip;
+	use crate::zip_3d::Bresenham3dZip;
+	use crate::Axis;
+	use super::{clip_line, clip_segment};
+
+	#[test]
+	fn segments() {
+		let aabb = ((0, 0), (10, 10));
+		assert_eq!(clip_segment((-5, 3), (20, 3), &aabb), Some(((0, 3), (10, 3))));
+		assert_eq!(clip_segment((8, 3), (2, 3), &aabb), Some(((8, 3), (2, 3))));
+		assert_eq!(clip_segment((11, 3), (20, 3), &aabb), None);
+		assert_eq!(clip_segment((2, -1), (8, -1), &aabb), None);
+		assert_eq!(clip_segment((-4, -4), (14, 14), &aabb), Some(((0, 0), (10, 10))));
+		let aabb = ((0, 0, 0), (10, 10, 10));
+		assert_eq!(clip_segment((-10, 5, 0), (10, 5, 10), &aabb), Some(((0, 5, 5), (10, 5, 10))));
+	}
+
+	#[test]
+	fn cohen_sutherland() {
+		let rect = ((0, 0), (10, 10));
+		assert_eq!(clip_line((2, 3), (8, 9), rect), Some(((2, 3), (8, 9))));
+		assert_eq!(clip_line((-5, -5), (15, 15), rect), Some(((0, 0), (10, 10))));
+		assert_eq!(clip_line((15, 15), (-5, -5), rect), Some(((10, 10), (0, 0))));
+		assert_eq!(clip_line((-4, 6), (6, -4), rect), Some(((0, 2), (2, 0))));
+		assert_eq!(clip_line((5, -10), (5, 20), rect), Some(((5, 0), (5, 10))));
+		assert_eq!(clip_line((-1, 8), (3, 12), rect), Some(((0, 9), (1, 10))));
+		assert_eq!(clip_line((-3, 8), (1, 12), rect), None);
+		assert_eq!(clip_line((11, 0), (20, 10), rect), None);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn sutherland_hodgman() {
+		use alloc::vec;
+		use super::clip_polygon;
+
+		let rect = ((0, 0), (10, 10));
+		let square = [(-5, -5), (15, -5), (15, 15), (-5, 15)];
+		assert_eq!(clip_polygon(&square, rect), vec![(0, 10), (0, 0), (10, 0), (10, 10)]);
+		let inner = [(2, 2), (8, 2), (5, 8)];
+		assert_eq!(clip_polygon(&inner, rect), vec![(2, 2), (8, 2), (5, 8)]);
+		assert!(clip_polygon(&[(20, 20), (30, 20), (25, 30)], rect).is_empty());
+		assert_eq!(clip_polygon(&[(5, -5), (15, 5), (5, 15), (-5, 5)], rect).len(), 8);
+	}
+
+	#[test]
+	fn zips() {
+		let zip = BresenhamZip::new((50, 50), (0, 0), (100, 0), 1).clip_rect(((40, 5), (60, 45)));
+		let mut rows = 0;
+		for (a, b) in zip {
+			assert!(a.0 >= 40 && b.0 <= 60 && (5..=45).contains(&a.1));
+			rows += 1;
+		}
+		assert_eq!(rows, 41);
+		let zip = Bresenham3dZip::new((0, 0, 0), (-10, 10, 10), (10, 10, 10), 2);
+		assert_eq!(zip.clip_rect(((0, 0, 0), (5, 5, 5))).next_back(), Some(((0, 5, 5), (5, 5, 5))));
+		let triangle = Triangle2::new((0, 0), (10, 0), (0, 10));
+		let zip = triangle.rasterize_along(Axis::Y).unwrap().clip_rect(((20, 20), (30, 30)));
+		assert_eq!(zip.count(), 0);
+	}
+
+	#[test]
+	fn wide_pairs() {
+		let zip = BresenhamZip::<i16>::try_new_checked((0, 0), (-200, 200), (200, 200), Axis::Y);
+		let mut zip = zip.unwrap().clip_rect(((0, 0), (10, 300)));
+		assert_eq!(zip.next_back(), Some(((0, 200), (10, 200))));
+		assert_eq!(zip.count(), 200);
+		let zip = BresenhamZip::new((0, 0), (-100000, 100000), (100000, 100000), 1);
+		let mut zip = zip.clip_rect(((-5, 0), (5, 200000)));
+		assert_eq!(zip.nth(50000), Some(((-5, 50000), (5, 50000))));
+		assert_eq!(zip.count(), 50000);
+	}
+
+	#[test]
+	fn pixels() {
+		let triangle = Triangle2::new((0, 0), (10, 0), (0, 10));
+		let zip = triangle.rasterize_along(Axis::Y).unwrap().clip_rect(((2, 2), (4, 4)));
+		assert_eq!(zip.pixels().count(), 9);
+	}
+
+}
@@ -0,0 +1,196 @@
+//! Contains the clipping of three-dimensional triangles against planes and boxes
+
+use core::fmt::{Debug, Formatter};
+use crate::clip::Aabb;
+use crate::triangle_3d::Triangle3;
+use crate::util::{point_at, Point};
+use crate::{Axis, Point3, SignedNum};
+
+/// Maximum number of vertices of a triangle clipped by the six planes of a box
+const MAX_VERTICES: usize = 9;
+
+/// Half of the space at one side of an axis-aligned plane, the plane included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HalfSpace<T> {
+	/// Points with a value in the axis greater than or equal to the given one, like `z >= near`
+	Above(Axis, T),
+	/// Points with a value in the axis lower than or equal to the given one, like `z <= far`
+	Below(Axis, T),
+}
+
+impl<T: SignedNum> HalfSpace<T> {
+
+	/// Returns the index of the axis, the value of the plane and if the inside is above it
+	fn plane(&self) -> (u8, T, bool) {
+		let (axis, value, above) = match *self {
+			HalfSpace::Above(axis, value) => (axis, value, true),
+			HalfSpace::Below(axis, value) => (axis, value, false),
+		};
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => 2,
+		};
+		(axis, value, above)
+	}
+
+}
+
+/// Clips the triangle to a half of the space, like the one in front of the near plane of a
+/// camera. The part inside is a triangle or a quadrilateral, so one or two triangles are yielded.
+///
+/// ```
+/// # use bresenham_zip::{Axis, clip::{clip_triangle, HalfSpace}, triangle_3d::Triangle3};
+/// let triangle = Triangle3::new((0, 0, 0), (100, 0, 100), (0, 100, 100));
+/// for part in clip_triangle(&triangle, HalfSpace::Above(Axis::Z, 10)) {
+///   for (a, b) in part.rasterize_along(Axis::Z) {
+///     assert!(a.2 >= 10 && b.2 >= 10);
+///   }
+/// }
+/// ```
+///
+/// * `triangle` - Triangle to clip
+/// * `half_space` - Half of the space to keep
+///
+pub fn clip_triangle<T: SignedNum>(triangle: &Triangle3<T>, half_space: HalfSpace<T>)
+	-> ClippedTriangle<T> {
+	let mut clipped = ClippedTriangle::new(triangle);
+	clipped.clip(half_space);
+	clipped
+}
+
+/// Clips the triangle to an axis-aligned box, yielding the triangles conforming the part
+/// inside it
+///
+/// * `triangle` - Triangle to clip
+/// * `aabb` - Lowest and highest corners of the box
+///
+pub fn clip_triangle_to_box<T: SignedNum>(triangle: &Triangle3<T>, aabb: Aabb<Point3<T>>)
+	-> ClippedTriangle<T> {
+	let (min, max) = aabb;
+	let mut clipped = ClippedTriangle::new(triangle);
+	let slabs = [(Axis::X, min.0, max.0), (Axis::Y, min.1, max.1), (Axis::Z, min.2, max.2)];
+	for (axis, min, max) in slabs {
+		clipped.clip(HalfSpace::Above(axis, min));
+		clipped.clip(HalfSpace::Below(axis, max));
+	}
+	clipped
+}
+
+/// Iterator over the triangles conforming the part of a clipped [Triangle3], obtained with
+/// [clip_triangle] or [clip_triangle_to_box]. They can be rasterized with
+/// [Triangle3::rasterize_along], which splits them into flat triangles ready to zip.
+///
+/// The points added at the planes have their other coordinates truncated. If the triangle is
+/// fully out, nothing is yielded.
+#[derive(Clone, PartialEq)]
+pub struct ClippedTriangle<T> {
+	vertices: [Point3<T>; MAX_VERTICES],
+	len: usize,
+	next: usize,
+}
+
+impl<T: SignedNum> ClippedTriangle<T> {
+
+	fn new(triangle: &Triangle3<T>) -> Self {
+		let mut vertices = [triangle.a; MAX_VERTICES];
+		vertices[..3].copy_from_slice(&triangle.vertices());
+		Self { vertices, len: 3, next: 1 }
+	}
+
+	/// Returns the vertices of the clipped polygon, in the same winding order as the triangle
+	pub fn vertices(&self) -> &[Point3<T>] {
+		&self.vertices[..self.len]
+	}
+
+	/// Clips the polygon to the half space with the Sutherland–Hodgman algorithm
+	fn clip(&mut self, half_space: HalfSpace<T>) {
+		let (axis, value, above) = half_space.plane();
+		let inside = |point: &Point3<T>| {
+			if above { point.nth(axis) >= value } else { point.nth(axis) <= value }
+		};
+		let (input, len) = (self.vertices, self.len);
+		self.len = 0;
+		for (i, current) in input[..len].iter().enumerate() {
+			let previous = &input[(i + len - 1) % len];
+			// clipping a convex polygon adds one vertex at most, so the capacity isn't exceeded
+			if inside(current) != inside(previous) {
+				self.vertices[self.len] = point_at(*previous, *current, axis, value);
+				self.len += 1;
+			}
+			if inside(current) {
+				self.vertices[self.len] = *current;
+				self.len += 1;
+			}
+		}
+	}
+
+}
+
+impl<T: SignedNum> Iterator for ClippedTriangle<T> {
+	type Item = Triangle3<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.next + 1 >= self.len {
+			return None;
+		}
+		let vertices = &self.vertices;
+		let triangle = Triangle3::new(vertices[0], vertices[self.next], vertices[self.next + 1]);
+		self.next += 1;
+		Some(triangle)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.len.saturating_sub(self.next + 1);
+		(remaining, Some(remaining))
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for ClippedTriangle<T> {}
+
+impl<T: SignedNum> Debug for ClippedTriangle<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "ClippedTriangle {:?}", self.vertices())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::triangle_3d::Triangle3;
+	use crate::Axis;
+	use super::{clip_triangle, clip_triangle_to_box, HalfSpace};
+
+	#[test]
+	fn plane() {
+		let triangle = Triangle3::new((0, 0, 0), (10, 0, 10), (0, 10, 10));
+		let mut clipped = clip_triangle(&triangle, HalfSpace::Above(Axis::Z, 4));
+		assert_eq!(clipped.vertices(), [(0, 4, 4), (4, 0, 4), (10, 0, 10), (0, 10, 10)]);
+		assert_eq!(clipped.len(), 2);
+		assert_eq!(clipped.next(), Some(Triangle3::new((0, 4, 4), (4, 0, 4), (10, 0, 10))));
+		assert_eq!(clipped.next(), Some(Triangle3::new((0, 4, 4), (10, 0, 10), (0, 10, 10))));
+		assert_eq!(clipped.next(), None);
+
+		let clipped = clip_triangle(&triangle, HalfSpace::Below(Axis::Z, 4));
+		assert_eq!(clipped.vertices(), [(0, 4, 4), (0, 0, 0), (4, 0, 4)]);
+		assert_eq!(clip_triangle(&triangle, HalfSpace::Above(Axis::X, 11)).count(), 0);
+		assert_eq!(clip_triangle(&triangle, HalfSpace::Above(Axis::X, 0)).count(), 1);
+	}
+
+	#[test]
+	fn aabb() {
+		let triangle = Triangle3::new((-10, 0, 5), (20, 0, 5), (5, 30, 5));
+		let clipped = clip_triangle_to_box(&triangle, ((0, 0, 0), (10, 10, 10)));
+		for point in clipped.vertices() {
+			assert!((0..=10).contains(&point.0) && (0..=10).contains(&point.1));
+		}
+		for part in clipped {
+			for (a, b) in part.rasterize_along(Axis::Y) {
+				assert!((0..=10).contains(&a.0) && (0..=10).contains(&b.0));
+			}
+		}
+		let outside = clip_triangle_to_box(&triangle, ((0, 0, 6), (10, 10, 10)));
+		assert_eq!(outside.count(), 0);
+	}
+
+}
@@ -0,0 +1,206 @@
+//! Package with the iterators to get the points of the zips as the point types of the users
+
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use crate::{Point2, Point2Like, Point3, Point3Like};
+
+/// Point types that can be built from the tuples yielded by the zips. It's implemented by every
+/// [Point2Like] and [Point3Like] type with a default value, which is then filled coordinate by
+/// coordinate.
+pub trait FromTuple<Q> {
+	/// Builds the point with the coordinates of the tuple
+	fn from_tuple(tuple: Q) -> Self;
+}
+
+impl<T, P: Point2Like<T> + Default> FromTuple<Point2<T>> for P {
+	fn from_tuple((x, y): Point2<T>) -> Self {
+		let mut point = P::default();
+		point.set_x(x);
+		point.set_y(y);
+		point
+	}
+}
+
+impl<T, P: Point3Like<T> + Default> FromTuple<Point3<T>> for P {
+	fn from_tuple((x, y, z): Point3<T>) -> Self {
+		let mut point = P::default();
+		point.set_x(x);
+		point.set_y(y);
+		point.set_z(z);
+		point
+	}
+}
+
+/// Iterator converting each pair of points yielded by a zip into a pair of the point type `P`,
+/// like a vertex type of the user implementing [Point2Like] or [Point3Like]
+///
+/// ```
+/// # use bresenham_zip::triangle::Triangle2;
+/// # use bresenham_zip::{Axis, convert::PairsAs};
+/// let zip = Triangle2::new((0, 0), (4, 0), (0, 4)).rasterize_along(Axis::Y).unwrap();
+/// for (left, right) in PairsAs::<_, [i32; 2]>::new(zip) {
+///   assert_eq!(left[1], right[1]);
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct PairsAs<I, P> {
+	zip: I,
+	point: PhantomData<P>,
+}
+
+impl<I, P> PairsAs<I, P> {
+
+	/// Creates the iterator converting the pairs of the zip
+	///
+	/// * `zip` - Iterator over the pairs of tuples
+	///
+	#[inline]
+	pub fn new(zip: I) -> Self {
+		Self { zip, point: PhantomData }
+	}
+
+}
+
+impl<Q, I: Iterator<Item = (Q, Q)>, P: FromTuple<Q>> Iterator for PairsAs<I, P> {
+	type Item = (P, P);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.zip.next().map(|(a, b)| (P::from_tuple(a), P::from_tuple(b)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.zip.size_hint()
+	}
+}
+
+impl<Q, I: ExactSizeIterator<Item = (Q, Q)>, P: FromTuple<Q>> ExactSizeIterator
+	for PairsAs<I, P> {}
+
+impl<I: Debug, P> Debug for PairsAs<I, P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "PairsAs [ {:?} ]", self.zip)
+	}
+}
+
+/// Iterator converting each point yielded by another one, like the [pixels](crate::fill) of a
+/// zip or a [line](crate::line), into the point type `P`, like the arrays
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let pixels = Triangle2::new([0, 0], [4, 0], [0, 4]).rasterize_along(Axis::Y).unwrap().pixels();
+/// let mut pixels = pixels.points_as::<[i32; 2]>();
+/// assert_eq!(pixels.next(), Some([0, 0]));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct PointsAs<I, P> {
+	points: I,
+	point: PhantomData<P>,
+}
+
+impl<I, P> PointsAs<I, P> {
+
+	/// Creates the iterator converting the points
+	///
+	/// * `points` - Iterator over the tuples
+	///
+	#[inline]
+	pub fn new(points: I) -> Self {
+		Self { points, point: PhantomData }
+	}
+
+}
+
+impl<Q, I: Iterator<Item = Q>, P: FromTuple<Q>> Iterator for PointsAs<I, P> {
+	type Item = P;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.points.next().map(P::from_tuple)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.points.size_hint()
+	}
+}
+
+impl<Q, I: ExactSizeIterator<Item = Q>, P: FromTuple<Q>> ExactSizeIterator for PointsAs<I, P> {}
+
+impl<I: Debug, P> Debug for PointsAs<I, P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "PointsAs [ {:?} ]", self.points)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::triangle_3d::Triangle3;
+	use crate::zip::Builder;
+	use crate::zip_3d::Builder3d;
+	use crate::{build_zip, Axis, Point3Like};
+
+	#[derive(Debug, Default, PartialEq)]
+	struct Vertex {
+		position: [i64; 3],
+	}
+
+	impl Point3Like<i64> for Vertex {
+		fn x(&self) -> i64 {
+			self.position[0]
+		}
+
+		fn y(&self) -> i64 {
+			self.position[1]
+		}
+
+		fn z(&self) -> i64 {
+			self.position[2]
+		}
+
+		fn set_x(&mut self, x: i64) {
+			self.position[0] = x;
+		}
+
+		fn set_y(&mut self, y: i64) {
+			self.position[1] = y;
+		}
+
+		fn set_z(&mut self, z: i64) {
+			self.position[2] = z;
+		}
+	}
+
+	#[test]
+	fn vertices() {
+		let vertex = |x, y, z| Vertex { position: [x, y, z] };
+		let zip = Builder3d::new()
+			.axis(Axis::Z)
+			.start_point(vertex(0, 0, 0))
+			.first_ending_point(vertex(0, 0, 10))
+			.second_ending_point([10, 10, 10])
+			.build()
+			.unwrap();
+		let (tuples, arrays) = (zip.clone(), zip.clone().pairs_as::<[i64; 3]>());
+		let mut pairs = zip.pairs_as::<Vertex>();
+		assert_eq!(pairs.len(), 11);
+		assert_eq!(pairs.next(), Some((vertex(0, 0, 0), vertex(0, 0, 0))));
+		assert_eq!(pairs.last(), Some((vertex(0, 0, 10), vertex(10, 10, 10))));
+		for ((a, b), (c, d)) in tuples.zip(arrays) {
+			assert_eq!((a, b), ((c[0], c[1], c[2]), (d[0], d[1], d[2])));
+		}
+		let zip = Builder::new().axis(Axis::X).start_point([0, 0])
+			.first_ending_point((5, -5)).second_ending_point([5, 5]).build().unwrap();
+		assert_eq!(zip.pairs_as::<[i32; 2]>().last(), Some(([5, -5], [5, 5])));
+	}
+
+	#[test]
+	fn arrays() {
+		let zip = build_zip!(2D:Y - [5, 0] -> [0, 5], [10, 5]).unwrap();
+		assert_eq!(zip.pairs_as::<[i32; 2]>().last(), Some(([0, 5], [10, 5])));
+		let triangle = Triangle3::new([0, 0, 0], [4, 0, 4], [0, 4, 4]);
+		let pixels = triangle.rasterize_along(Axis::Z).pixels().points_as::<[i8; 3]>();
+		assert!(pixels.clone().all(|[x, y, z]| x >= 0 && y >= 0 && x + y == z));
+		assert_eq!(pixels.count(), 1 + 2 + 3 + 4 + 5);
+	}
+
+}
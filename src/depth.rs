@@ -0,0 +1,174 @@
+//! Package with the depth interpolation of the two-dimensional zips
+
+use core::fmt::{Debug, Formatter};
+use crate::fill::FillIterator;
+use crate::util::{floor_div, narrow, wide};
+use crate::{Point2, Point3, SignedNum};
+
+/// Plane of the depth of a triangle, where the depth of a point is
+/// `(origin + x * point.0 + y * point.1) / total` rounded down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Plane {
+	origin: i128,
+	x: i128,
+	y: i128,
+	total: i128,
+}
+
+impl Plane {
+
+	fn new<T: SignedNum>(vertices: [Point2<T>; 3], depths: [T; 3]) -> Self {
+		let vertices = vertices.map(|(x, y)| (wide(x), wide(y)));
+		let cross = |o: (i128, i128), a: (i128, i128), p: (i128, i128)| {
+			(a.0 - o.0) * (p.1 - o.1) - (a.1 - o.1) * (p.0 - o.0)
+		};
+		let [a, b, c] = vertices;
+		let total = cross(a, b, c);
+		if total == 0 {
+			return Self { origin: wide(depths[0]), x: 0, y: 0, total: 1 };
+		}
+		// the numerator is the sum of the depths weighted by the barycentric coordinates
+		let numerator = |p| {
+			wide(depths[0]) * cross(b, c, p)
+				+ wide(depths[1]) * cross(c, a, p)
+				+ wide(depths[2]) * cross(a, b, p)
+		};
+		let (origin, sign) = (numerator((0, 0)), total.signum());
+		Self {
+			origin: sign * origin,
+			x: sign * (numerator((1, 0)) - origin),
+			y: sign * (numerator((0, 1)) - origin),
+			total: total.abs(),
+		}
+	}
+
+	/// Returns the point with its depth as third coordinate
+	#[inline]
+	fn point<T: SignedNum>(&self, (x, y): Point2<T>) -> Point3<T> {
+		let numerator = self.origin + self.x * wide(x) + self.y * wide(y);
+		(x, y, narrow(floor_div(numerator, self.total)))
+	}
+
+}
+
+/// Zip yielding the pairs of points of a two-dimensional triangle along with their depth as third
+/// coordinate, ready to be tested against a depth buffer. The depth of the vertices is
+/// interpolated linearly over the triangle, so the screen-space triangles don't need to go
+/// through the three-dimensional zips, whose pairs share the value of a three-dimensional axis.
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+/// let zip = triangle.rasterize_with_depth([0, 100, 200], Axis::Y).unwrap();
+/// for ((_, _, depth), _) in zip {
+///   assert!((0..=200).contains(&depth));
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct DepthZip<I> {
+	zip: I,
+	plane: Plane,
+}
+
+impl<I> DepthZip<I> {
+
+	pub(crate) fn new<T: SignedNum>(zip: I, vertices: [Point2<T>; 3], depths: [T; 3]) -> Self {
+		Self { zip, plane: Plane::new(vertices, depths) }
+	}
+
+	/// Turns the zip into an iterator over every point of the triangle with its depth, see
+	/// [DepthFill]
+	pub fn pixels<T: SignedNum>(self) -> DepthFill<I, T>
+		where I: Iterator<Item = (Point2<T>, Point2<T>)> {
+		DepthFill {
+			pixels: FillIterator::new(self.zip),
+			plane: self.plane,
+		}
+	}
+
+}
+
+impl<T: SignedNum, I: Iterator<Item = (Point2<T>, Point2<T>)>> Iterator for DepthZip<I> {
+	type Item = (Point3<T>, Point3<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (a, b) = self.zip.next()?;
+		Some((self.plane.point(a), self.plane.point(b)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.zip.size_hint()
+	}
+}
+
+impl<I: Debug> Debug for DepthZip<I> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "DepthZip [ {:?} ]", self.zip)
+	}
+}
+
+/// Iterator over every point of a triangle rasterized by a [DepthZip], with the depth
+/// interpolated in each of them as third coordinate
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (4, 0), (0, 4));
+/// let mut pixels = triangle.rasterize_with_depth([10, 14, 10], Axis::Y).unwrap().pixels();
+/// assert!(pixels.all(|(x, _, depth)| depth == 10 + x));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct DepthFill<I, T: SignedNum> {
+	pixels: FillIterator<I, Point2<T>>,
+	plane: Plane,
+}
+
+impl<T: SignedNum, I: Iterator<Item = (Point2<T>, Point2<T>)>> Iterator for DepthFill<I, T> {
+	type Item = Point3<T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.pixels.next().map(|point| self.plane.point(point))
+	}
+}
+
+impl<I: Debug, T: SignedNum> Debug for DepthFill<I, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "DepthFill [ {:?} ]", self.pixels)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::triangle::Triangle2;
+	use crate::zip::BresenhamZip;
+	use crate::Axis;
+
+	#[test]
+	fn edges() {
+		let mut zip = BresenhamZip::new((0, 0), (-10, 10), (10, 10), 1).with_depth([0, 20, 40]);
+		assert_eq!(zip.next(), Some(((0, 0, 0), (0, 0, 0))));
+		assert_eq!(zip.next(), Some(((-1, 1, 2), (1, 1, 4))));
+		assert_eq!(zip.last(), Some(((-10, 10, 20), (10, 10, 40))));
+	}
+
+	#[test]
+	fn pixels() {
+		let triangle = Triangle2::<i32>::new((30, 100), (0, 0), (100, 40));
+		let zip = triangle.rasterize_with_depth([40, 50, 310], Axis::Y).unwrap();
+		let spans = zip.clone().map(|(a, b)| (a.0 - b.0).unsigned_abs() as usize + 1);
+		assert_eq!(zip.clone().pixels().count(), spans.sum());
+		// the depth grows 3 units each unit of x and decreases 1 each unit of y
+		for (x, y, depth) in zip.pixels() {
+			assert_eq!(depth, 50 + 3 * x - y);
+		}
+	}
+
+	#[test]
+	fn degenerate() {
+		let triangle = Triangle2::new((0, 0), (5, 5), (10, 10));
+		let mut zip = triangle.rasterize_with_depth([7, 1, 2], Axis::Y).unwrap();
+		assert_eq!(zip.next(), Some(((0, 0, 7), (0, 0, 7))));
+	}
+
+}
@@ -0,0 +1,122 @@
+//! Package with the ordered dithering of the shaded triangles, for monochrome displays like OLED
+//! or e-paper screens. A pixel is lit when its intensity passes the threshold of the 8×8 Bayer
+//! matrix in its position, anchored to the origin so adjacent triangles dither seamlessly.
+//!
+//! Triangles with a single intensity are dithered with the spans of a
+//! [Pattern::bayer](crate::stipple::Pattern::bayer), and the ones with an intensity interpolated
+//! from their vertices with [GouraudZip::dither](crate::gouraud::GouraudZip::dither).
+
+use core::fmt::{Debug, Formatter};
+use crate::gouraud::GouraudSpan;
+use crate::util::{float, narrow, wide, Point};
+use crate::{FloatNum, Point2, SignedNum};
+
+/// Thresholds of the 8×8 Bayer matrix, from 0 to 63, indexed by the row and the column modulo 8
+pub const BAYER: [[u8; 8]; 8] = [
+	[0, 32, 8, 40, 2, 34, 10, 42],
+	[48, 16, 56, 24, 50, 18, 58, 26],
+	[12, 44, 4, 36, 14, 46, 6, 38],
+	[60, 28, 52, 20, 62, 30, 54, 22],
+	[3, 35, 11, 43, 1, 33, 9, 41],
+	[51, 19, 59, 27, 49, 17, 57, 25],
+	[15, 47, 7, 39, 13, 45, 5, 37],
+	[63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+/// Iterator over the pixels of a triangle shaded with a single intensity channel whose intensity
+/// passes the threshold of the [BAYER] matrix. The intensities go from zero, never lit, to one,
+/// always lit, and the row of the matrix is the Y coordinate in both axes.
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (31, 0), (0, 31));
+/// let shading = triangle.rasterize_gouraud([[0.0], [1.0], [1.0]], Axis::Y).unwrap();
+/// for (x, y) in shading.dither() {
+///   // light (x, y), more often the farther from the origin
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Dither<I, T, F> {
+	spans: I,
+	axis: u8,
+	/// Span being walked with the value of the next pixel and its intensity
+	current: Option<(GouraudSpan<T, F, 1>, i128, F)>,
+}
+
+impl<I, T, F> Dither<I, T, F> {
+
+	#[inline]
+	pub(crate) fn new(spans: I, axis: u8) -> Self {
+		Self { spans, axis, current: None }
+	}
+
+}
+
+impl<I, T, F> Iterator for Dither<I, T, F>
+	where I: Iterator<Item = GouraudSpan<T, F, 1>>, T: SignedNum, F: FloatNum {
+	type Item = Point2<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let other = 1 - self.axis;
+		loop {
+			if let Some((span, value, intensity)) = self.current.as_mut() {
+				if *value > wide(span.right.nth(other)) {
+					self.current = None;
+					continue;
+				}
+				let mut point = span.left;
+				point.set_nth(other, narrow(*value));
+				let lit = *intensity > threshold::<F>(wide(point.1), wide(point.0));
+				*value += 1;
+				*intensity += span.increment[0];
+				if lit {
+					return Some(point);
+				}
+				continue;
+			}
+			let span = self.spans.next()?;
+			let (value, intensity) = (wide(span.left.nth(other)), span.left_color[0]);
+			self.current = Some((span, value, intensity));
+		}
+	}
+}
+
+impl<I: Debug, T, F> Debug for Dither<I, T, F> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Dither [ {:?} ]", self.spans)
+	}
+}
+
+/// Returns the threshold of the [BAYER] matrix in the position as an intensity, in the middle of
+/// its step so the intensities equal to a threshold are consistently rounded
+fn threshold<F: FloatNum>(row: i128, column: i128) -> F {
+	let step = BAYER[row.rem_euclid(8) as usize][column.rem_euclid(8) as usize];
+	float::<F>(2 * i128::from(step) + 1) / float(128)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::stipple::Pattern;
+	use crate::triangle::Triangle2;
+	use crate::Axis;
+
+	#[test]
+	fn levels() {
+		let square = Triangle2::new((0, 0), (15, 0), (0, 15));
+		for (level, intensity) in [(0, 0.0), (16, 0.25), (32, 0.5), (64, 1.0)] {
+			let shading = square.rasterize_gouraud([[intensity]; 3], Axis::Y).unwrap();
+			let lit = shading.dither().filter(|(x, y)| *x < 8 && *y < 8).count();
+			assert_eq!(lit, level);
+			let Pattern::Bits(rows) = Pattern::bayer((intensity * 255.0) as u8) else {
+				unreachable!()
+			};
+			assert_eq!(rows.iter().map(|row| row.count_ones()).sum::<u32>(), level as u32);
+		}
+		// both axes dither the same pixels
+		let shading = |axis| square.rasterize_gouraud([[0.0], [1.0], [0.5]], axis).unwrap();
+		let mut by_rows: [[bool; 16]; 16] = Default::default();
+		shading(Axis::Y).dither().for_each(|(x, y)| by_rows[y as usize][x as usize] = true);
+		assert!(shading(Axis::X).dither().all(|(x, y)| by_rows[y as usize][x as usize]));
+	}
+
+}
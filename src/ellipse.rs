@@ -0,0 +1,167 @@
+//! Package with the logic to rasterize filled axis-aligned ellipses
+
+use core::fmt::{Debug, Formatter};
+use crate::fill::FillIterator;
+use crate::span::SpanIterator;
+use crate::util::{narrow, wide, Point};
+use crate::{Axis, Error, Point2, SignedNum};
+
+/// Zip of a filled ellipse with its axes parallel to X and Y, yielding the pair of points at both
+/// ends of each of its rows in the travelled axis, the same way the zips of the triangles do. The
+/// rows are computed with integers only, a point `(x, y)` away from the center being inside the
+/// ellipse of radii `(a, b)` if `m * (b² * x² + a² * y²) <= a² * b² * (m + 1)`, with `m` the
+/// largest radius. With both radii equal, it's the same criterion of the
+/// [CircleZip](crate::circle::CircleZip).
+///
+/// ```
+/// # use bresenham_zip::{Axis, ellipse::EllipseZip};
+/// let mut zip = EllipseZip::new((0, 0), (6, 2), Axis::Y).unwrap();
+/// assert_eq!(zip.len(), 5);
+/// assert_eq!(zip.next(), Some(((-2, -2), (2, -2))));
+/// assert_eq!(zip.nth(1), Some(((-6, 0), (6, 0))));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct EllipseZip<T> {
+	center: Point2<T>,
+	radii: (i128, i128),
+	offset: i128,
+	half: i128,
+	axis: u8,
+}
+
+impl<T: SignedNum> EllipseZip<T> {
+
+	/// Creates the zip of the ellipse going from its lowest row in the axis to its highest one.
+	/// A radius of zero collapses the ellipse into a line, and a negative one yields nothing.
+	///
+	/// * `center` - Center of the ellipse
+	/// * `radii` - Distances from the center to the edge along X and Y, in pixels
+	/// * `axis` - Axis to use in the iteration
+	///
+	/// # Error
+	/// [Error::MissingAxis], if the axis is [Axis::Z].
+	///
+	pub fn new(center: Point2<T>, radii: Point2<T>, axis: Axis) -> Result<Self, Error<T>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => return Err(Error::MissingAxis),
+		};
+		let radii = match (wide(radii.0), wide(radii.1)) {
+			(x, y) if x < 0 || y < 0 => (-1, -1),
+			radii => radii,
+		};
+		let offset = -if axis == 0 { radii.0 } else { radii.1 };
+		Ok(Self { center, radii, offset, half: 0, axis })
+	}
+
+	/// Turns the zip into an iterator over every point between each pair of points,
+	/// both included.
+	pub fn pixels(self) -> FillIterator<Self, Point2<T>> {
+		FillIterator::new(self)
+	}
+
+	/// Turns the zip into an iterator over the [Span](crate::span::Span)s defined by each pair
+	/// of points
+	pub fn spans(self) -> SpanIterator<Self> {
+		let axis = self.axis;
+		SpanIterator::new(self, axis)
+	}
+
+	/// Returns the radii across and along the travelled axis
+	fn oriented(&self) -> (i128, i128) {
+		if self.axis == 0 { (self.radii.1, self.radii.0) } else { self.radii }
+	}
+
+}
+
+impl<T: SignedNum> Iterator for EllipseZip<T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (across, along) = self.oriented();
+		if self.offset > along {
+			return None;
+		}
+		// the half width grows until the center and shrinks after it, one step at a time
+		let largest = across.max(along);
+		let inside = |half: i128| {
+			largest * (along * along * half * half + across * across * self.offset * self.offset)
+				<= across * across * along * along * (largest + 1)
+		};
+		while self.half < across && inside(self.half + 1) {
+			self.half += 1;
+		}
+		while self.half > 0 && !inside(self.half) {
+			self.half -= 1;
+		}
+		let (mut a, mut b) = (self.center, self.center);
+		let row = narrow(wide(self.center.nth(self.axis)) + self.offset);
+		let other = 1 - self.axis;
+		a.set_nth(self.axis, row);
+		b.set_nth(self.axis, row);
+		a.set_nth(other, narrow(wide(self.center.nth(other)) - self.half));
+		b.set_nth(other, narrow(wide(self.center.nth(other)) + self.half));
+		self.offset += 1;
+		Some((a, b))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = (self.oriented().1 - self.offset + 1).max(0) as usize;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for EllipseZip<T> {}
+
+impl<T: SignedNum> Debug for EllipseZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "EllipseZip [ {:?}, {:?} ]. Remaining: {}", self.center, self.radii, self.len())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::circle::CircleZip;
+	use crate::{Axis, Error};
+	use super::EllipseZip;
+
+	#[test]
+	fn circle() {
+		for radius in 0..30 {
+			for axis in [Axis::X, Axis::Y] {
+				let ellipse = EllipseZip::new((7, -3), (radius, radius), axis).unwrap();
+				assert!(ellipse.eq(CircleZip::new((7, -3), radius, axis).unwrap()));
+			}
+		}
+	}
+
+	#[test]
+	fn axes() {
+		let rows = EllipseZip::new((0, 0), (6, 2), Axis::Y).unwrap();
+		let columns = EllipseZip::new((0, 0), (2, 6), Axis::X).unwrap();
+		assert!(rows.eq(columns.map(|(a, b)| ((a.1, a.0), (b.1, b.0)))));
+		let mut pixels = [[0; 13]; 5];
+		for (x, y) in EllipseZip::new((0, 0), (6, 2), Axis::X).unwrap().pixels() {
+			pixels[(y + 2) as usize][(x + 6) as usize] += 1;
+		}
+		for (x, y) in EllipseZip::new((0, 0), (6, 2), Axis::Y).unwrap().pixels() {
+			pixels[(y + 2) as usize][(x + 6) as usize] -= 1;
+		}
+		assert!(pixels.iter().flatten().all(|count| *count == 0));
+	}
+
+	#[test]
+	fn degenerate() {
+		let line = EllipseZip::new((4, 4), (0, 3), Axis::Y).unwrap();
+		assert!(line.clone().all(|(a, b)| a == b && a.0 == 4));
+		assert_eq!(line.len(), 7);
+		let line = EllipseZip::new((4, 4), (0, 3), Axis::X).unwrap();
+		assert!(line.eq([((4, 1), (4, 7))]));
+		assert!(EllipseZip::new((4, 4), (0, 0), Axis::Y).unwrap().eq([((4, 4), (4, 4))]));
+		assert_eq!(EllipseZip::new((4, 4), (-1, 5), Axis::X).unwrap().len(), 0);
+		assert_eq!(EllipseZip::new((4, 4), (1, 1), Axis::Z).unwrap_err(), Error::MissingAxis);
+	}
+
+}
@@ -0,0 +1,134 @@
+//! Errors that can be generated building the zips
+
+use core::fmt::{Debug, Display, Formatter};
+
+/// Errors that can be generated building a zip. They don't borrow anything, so they can be
+/// stored and copied freely.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error<T> {
+	/// No valid axis was specified
+	MissingAxis,
+	/// One of the points was not specified, contains which one is missing
+	MissingPoint(PointRole),
+	/// The axis is X and the ending points have different X values
+	InvalidX(T, T),
+	/// The axis is Y and the ending points have different Y values
+	InvalidY(T, T),
+	/// The axis is Z and the ending points have different Z values
+	InvalidZ(T, T),
+	/// The points are too far apart to compute the lines with the type of their coordinates,
+	/// only checked by the `try_new_checked` constructors
+	Overflow,
+	/// An option of the builder was set but the zip built can't apply it, contains which one
+	UnsupportedOption(BuilderOption),
+}
+
+/// Each of the three points required by the builders of the zips
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PointRole {
+	/// Starting point of both lines
+	Start,
+	/// Ending point of the first line
+	FirstEnding,
+	/// Ending point of the second line
+	SecondEnding,
+}
+
+/// Each of the options of the builders that only some zips can apply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BuilderOption {
+	/// The starting point is excluded
+	Apex,
+	/// The flat edge between the ending points is excluded
+	FlatEdge,
+	/// The iteration starts at the flat edge
+	Direction,
+	/// The axis values are restricted to a range
+	ClampAxis,
+}
+
+impl<T: Debug> Error<T> {
+
+	/// Returns the description of the error as an owned string. Requires the `alloc` feature,
+	/// [Display] can be used to write the description without allocating.
+	#[cfg(feature = "alloc")]
+	pub fn message(&self) -> alloc::string::String {
+		use alloc::string::ToString;
+		self.to_string()
+	}
+
+}
+
+impl<T: Debug> Display for Error<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Error::MissingAxis => write!(f, "no valid axis was specified"),
+			Error::MissingPoint(point) => write!(f, "the {} point was not specified", point),
+			Error::InvalidX(a, b) => {
+				write!(f, "the ending points must share the same X, found {:?} and {:?}", a, b)
+			}
+			Error::InvalidY(a, b) => {
+				write!(f, "the ending points must share the same Y, found {:?} and {:?}", a, b)
+			}
+			Error::InvalidZ(a, b) => {
+				write!(f, "the ending points must share the same Z, found {:?} and {:?}", a, b)
+			}
+			Error::Overflow => write!(f, "the points are too far apart for their coordinate type"),
+			Error::UnsupportedOption(option) => {
+				write!(f, "the zip can't be built with the {} option", option)
+			}
+		}
+	}
+}
+
+impl<T: Debug> Debug for Error<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		Display::fmt(self, f)
+	}
+}
+
+impl<T: Debug> core::error::Error for Error<T> {}
+
+impl Display for BuilderOption {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		f.write_str(match self {
+			BuilderOption::Apex => "include_apex",
+			BuilderOption::FlatEdge => "include_flat_edge",
+			BuilderOption::Direction => "direction",
+			BuilderOption::ClampAxis => "clamp_axis",
+		})
+	}
+}
+
+impl Display for PointRole {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		f.write_str(match self {
+			PointRole::Start => "start",
+			PointRole::FirstEnding => "first ending",
+			PointRole::SecondEnding => "second ending",
+		})
+	}
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	use super::{Error, PointRole};
+
+	#[test]
+	fn message() {
+		assert_eq!(Error::<i32>::MissingAxis.message(), "no valid axis was specified");
+		assert_eq!(
+			Error::InvalidY(1, 2).message(),
+			"the ending points must share the same Y, found 1 and 2"
+		);
+		let error = Error::<i32>::MissingPoint(PointRole::FirstEnding);
+		assert_eq!(error.message(), "the first ending point was not specified");
+	}
+
+}
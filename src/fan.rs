@@ -0,0 +1,131 @@
+//! Package with the logic to rasterize two-dimensional triangle fans
+
+use core::fmt::{Debug, Formatter};
+use core::slice::Windows;
+use crate::span::{Span, SpanIterator};
+use crate::triangle::Triangle2;
+use crate::zip::TopLeftZip;
+use crate::{Axis, Point2, SignedNum};
+
+/// Triangle fan defined by an apex shared by all its triangles and a ring of vertices, each pair
+/// of consecutive vertices conforming a wedge with the apex. It's the natural decomposition of the
+/// convex polygons, taking one of their vertices as apex and the rest as ring. To close the fan
+/// around the apex, repeat the first vertex of the ring at its end.
+///
+/// ```
+/// # use bresenham_zip::{Axis, fan::Fan};
+/// let ring = [(0, 0), (10, 0), (10, 10), (0, 10), (0, 0)];
+/// let spans = Fan::new((5, 5), &ring).spans(Axis::Y).unwrap();
+/// assert_eq!(spans.map(|span| span.range.count()).sum::<usize>(), 10 * 10);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Fan<'a, T> {
+	apex: Point2<T>,
+	ring: &'a [Point2<T>],
+}
+
+impl<'a, T: SignedNum> Fan<'a, T> {
+
+	/// Creates a new fan with the given apex and ring
+	///
+	/// * `apex` - Vertex shared by every triangle
+	/// * `ring` - Vertices around the apex, each consecutive pair conforming a triangle with it
+	///
+	pub fn new(apex: Point2<T>, ring: &'a [Point2<T>]) -> Self {
+		Self { apex, ring }
+	}
+
+	/// Returns an iterator over the triangles of the fan
+	pub fn triangles(&self) -> impl Iterator<Item = Triangle2<T>> + 'a {
+		let apex = self.apex;
+		self.ring.windows(2).map(move |pair| Triangle2::new(apex, pair[0], pair[1]))
+	}
+
+	/// Returns an iterator over the spans of all the triangles of the fan in the given axis, one
+	/// wedge after the other. They follow the top-left fill rule, see [TopLeftZip], so the edges
+	/// shared by consecutive wedges are only yielded once.
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	pub fn spans(&self, axis: Axis) -> Option<FanSpans<'a, T>> {
+		if axis == Axis::Z {
+			return None;
+		}
+		Some(FanSpans {
+			apex: self.apex,
+			wedges: self.ring.windows(2),
+			axis,
+			current: None,
+		})
+	}
+
+}
+
+/// Iterator over the spans of every wedge of a [Fan], following the top-left fill rule
+#[derive(Clone)]
+pub struct FanSpans<'a, T> {
+	apex: Point2<T>,
+	wedges: Windows<'a, Point2<T>>,
+	axis: Axis,
+	current: Option<SpanIterator<TopLeftZip<T>>>,
+}
+
+impl<T: SignedNum> Iterator for FanSpans<'_, T> {
+	type Item = Span<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(span) = self.current.as_mut().and_then(|spans| spans.next()) {
+				return Some(span);
+			}
+			let wedge = self.wedges.next()?;
+			let triangle = Triangle2::new(self.apex, wedge[0], wedge[1]);
+			self.current = triangle.rasterize_top_left(self.axis).map(TopLeftZip::spans);
+		}
+	}
+}
+
+impl<T: SignedNum> Debug for FanSpans<'_, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "FanSpans [ {:?} ]. Remaining wedges: {}", self.current, self.wedges.len())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Axis;
+	use super::Fan;
+
+	#[test]
+	fn watertight() {
+		// hexagon around its center, and the same hexagon from one of its vertices
+		let ring = [(4, 0), (12, 0), (16, 8), (12, 16), (4, 16), (0, 8), (4, 0)];
+		for (apex, ring) in [((8, 8), &ring[..]), ((4, 0), &ring[1..6])] {
+			for axis in [Axis::X, Axis::Y] {
+				let mut covered = [[0; 17]; 17];
+				for span in Fan::new(apex, ring).spans(axis).unwrap() {
+					for value in span.range {
+						let (x, y) = if axis == Axis::X {
+							(span.axis_value, value)
+						} else {
+							(value, span.axis_value)
+						};
+						covered[y as usize][x as usize] += 1;
+					}
+				}
+				assert!(covered.iter().flatten().all(|count| *count <= 1));
+				assert_eq!(covered.iter().flatten().sum::<i32>(), 192, "{:?}", axis);
+			}
+		}
+	}
+
+	#[test]
+	fn triangles() {
+		let ring = [(10, 0), (10, 10), (0, 10)];
+		let fan = Fan::new((0, 0), &ring);
+		assert_eq!(fan.triangles().count(), 2);
+		assert!(fan.spans(Axis::Z).is_none());
+		assert_eq!(Fan::new((0, 0), &ring[..1]).spans(Axis::Y).unwrap().next(), None);
+	}
+
+}
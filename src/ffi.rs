@@ -0,0 +1,288 @@
+//! Package with the C interface of the zips over `i32` coordinates, so the rasterizer can be used
+//! from C or C++. The zips are created behind opaque handles that must be released with their
+//! `free` function, and each call pulls the next span through out-parameters. To link it, build
+//! the crate as a static library, like with `cargo rustc --crate-type staticlib --features ffi`.
+//!
+//! The axes are given as `0` for X, `1` for Y and `2` for Z, and the functions creating the zips
+//! return one of the `BZ_` status codes.
+
+use alloc::boxed::Box;
+use crate::span::SpanIterator;
+use crate::zip::BresenhamZip;
+use crate::zip_3d::Bresenham3dZip;
+use crate::{Axis, Error};
+
+/// The zip was created
+pub const BZ_OK: i32 = 0;
+/// A pointer argument was null
+pub const BZ_NULL_POINTER: i32 = 1;
+/// The axis isn't valid for the dimension of the zip
+pub const BZ_MISSING_AXIS: i32 = 2;
+/// The axis is X and the ending points have different X values
+pub const BZ_INVALID_X: i32 = 3;
+/// The axis is Y and the ending points have different Y values
+pub const BZ_INVALID_Y: i32 = 4;
+/// The axis is Z and the ending points have different Z values
+pub const BZ_INVALID_Z: i32 = 5;
+/// The points are too far apart to compute the lines
+pub const BZ_OVERFLOW: i32 = 6;
+
+/// Two-dimensional point of the C interface
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BzPoint2 {
+	pub x: i32,
+	pub y: i32,
+}
+
+/// Three-dimensional point of the C interface
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BzPoint3 {
+	pub x: i32,
+	pub y: i32,
+	pub z: i32,
+}
+
+/// Span of the two-dimensional zips, with the value of the travelled axis and the first and last
+/// values covered in the other axis, both included
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BzSpan {
+	pub axis_value: i32,
+	pub start: i32,
+	pub end: i32,
+}
+
+/// Opaque handle of a two-dimensional zip, created with [bz_zip2d_new]
+pub struct BzZip2d(SpanIterator<BresenhamZip<i32>>);
+
+/// Opaque handle of a three-dimensional zip, created with [bz_zip3d_new]
+pub struct BzZip3d(Bresenham3dZip<i32>);
+
+/// Creates the two-dimensional zip from the starting point to both ending points, which must share
+/// the value of the axis, and writes its handle in `out`. Returns [BZ_OVERFLOW] if the points are
+/// too far apart to compute the lines in `i32`.
+///
+/// * `start` - Starting point of both lines
+/// * `end_a`, `end_b` - Ending points of the lines
+/// * `axis` - Axis travelled by the zip, `0` for X or `1` for Y
+/// * `out` - Where the handle is written, only when [BZ_OK] is returned
+///
+/// # Safety
+/// `out` must be null or valid to write a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn bz_zip2d_new(start: BzPoint2, end_a: BzPoint2, end_b: BzPoint2,
+	axis: u32, out: *mut *mut BzZip2d) -> i32 {
+	if out.is_null() {
+		return BZ_NULL_POINTER;
+	}
+	let Some(axis) = axis_of(axis) else {
+		return BZ_MISSING_AXIS;
+	};
+	let zip = BresenhamZip::try_new_checked((start.x, start.y), (end_a.x, end_a.y),
+		(end_b.x, end_b.y), axis);
+	match zip {
+		Ok(zip) => {
+			*out = Box::into_raw(Box::new(BzZip2d(zip.spans())));
+			BZ_OK
+		}
+		Err(error) => status(error),
+	}
+}
+
+/// Writes the next span of the zip in `out`, returning `false` without writing anything once the
+/// zip is exhausted
+///
+/// # Safety
+/// `zip` must be a handle returned by [bz_zip2d_new] not freed yet, and `out` must be valid to
+/// write a [BzSpan]. Both can be null, returning `false`.
+#[no_mangle]
+pub unsafe extern "C" fn bz_zip2d_next_span(zip: *mut BzZip2d, out: *mut BzSpan) -> bool {
+	if zip.is_null() || out.is_null() {
+		return false;
+	}
+	match (*zip).0.next() {
+		Some(span) => {
+			*out = BzSpan { axis_value: span.axis_value, start: *span.range.start(),
+				end: *span.range.end() };
+			true
+		}
+		None => false,
+	}
+}
+
+/// Returns the number of spans left to pull from the zip, `0` if it's null
+///
+/// # Safety
+/// `zip` must be null or a handle returned by [bz_zip2d_new] not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bz_zip2d_remaining(zip: *const BzZip2d) -> usize {
+	if zip.is_null() { 0 } else { (*zip).0.size_hint().0 }
+}
+
+/// Releases the zip. Passing null does nothing.
+///
+/// # Safety
+/// `zip` must be null or a handle returned by [bz_zip2d_new] not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bz_zip2d_free(zip: *mut BzZip2d) {
+	if !zip.is_null() {
+		drop(Box::from_raw(zip));
+	}
+}
+
+/// Creates the three-dimensional zip from the starting point to both ending points, which must
+/// share the value of the axis, and writes its handle in `out`. Returns [BZ_OVERFLOW] if the
+/// points are too far apart to compute the lines in `i32`.
+///
+/// * `start` - Starting point of both lines
+/// * `end_a`, `end_b` - Ending points of the lines
+/// * `axis` - Axis travelled by the zip, `0` for X, `1` for Y or `2` for Z
+/// * `out` - Where the handle is written, only when [BZ_OK] is returned
+///
+/// # Safety
+/// `out` must be null or valid to write a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn bz_zip3d_new(start: BzPoint3, end_a: BzPoint3, end_b: BzPoint3,
+	axis: u32, out: *mut *mut BzZip3d) -> i32 {
+	if out.is_null() {
+		return BZ_NULL_POINTER;
+	}
+	let Some(axis) = axis_of(axis) else {
+		return BZ_MISSING_AXIS;
+	};
+	let zip = Bresenham3dZip::try_new_checked((start.x, start.y, start.z),
+		(end_a.x, end_a.y, end_a.z), (end_b.x, end_b.y, end_b.z), axis);
+	match zip {
+		Ok(zip) => {
+			*out = Box::into_raw(Box::new(BzZip3d(zip)));
+			BZ_OK
+		}
+		Err(error) => status(error),
+	}
+}
+
+/// Writes the points of both lines in the next value of the axis in `out_a` and `out_b`,
+/// returning `false` without writing anything once the zip is exhausted
+///
+/// # Safety
+/// `zip` must be a handle returned by [bz_zip3d_new] not freed yet, and `out_a` and `out_b` must
+/// be valid to write a [BzPoint3]. Any of them can be null, returning `false`.
+#[no_mangle]
+pub unsafe extern "C" fn bz_zip3d_next_pair(zip: *mut BzZip3d, out_a: *mut BzPoint3,
+	out_b: *mut BzPoint3) -> bool {
+	if zip.is_null() || out_a.is_null() || out_b.is_null() {
+		return false;
+	}
+	match (*zip).0.next() {
+		Some((a, b)) => {
+			*out_a = BzPoint3 { x: a.0, y: a.1, z: a.2 };
+			*out_b = BzPoint3 { x: b.0, y: b.1, z: b.2 };
+			true
+		}
+		None => false,
+	}
+}
+
+/// Returns the number of pairs left to pull from the zip, `0` if it's null
+///
+/// # Safety
+/// `zip` must be null or a handle returned by [bz_zip3d_new] not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bz_zip3d_remaining(zip: *const BzZip3d) -> usize {
+	if zip.is_null() { 0 } else { (*zip).0.len() }
+}
+
+/// Releases the zip. Passing null does nothing.
+///
+/// # Safety
+/// `zip` must be null or a handle returned by [bz_zip3d_new] not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn bz_zip3d_free(zip: *mut BzZip3d) {
+	if !zip.is_null() {
+		drop(Box::from_raw(zip));
+	}
+}
+
+/// Returns the axis with the given index of the C interface
+fn axis_of(index: u32) -> Option<Axis> {
+	match index {
+		0 => Some(Axis::X),
+		1 => Some(Axis::Y),
+		2 => Some(Axis::Z),
+		_ => None,
+	}
+}
+
+/// Returns the status code of the error
+fn status(error: Error<i32>) -> i32 {
+	match error {
+		Error::MissingAxis => BZ_MISSING_AXIS,
+		// the three points are always given and no option is set
+		Error::MissingPoint(_) | Error::UnsupportedOption(_) => BZ_NULL_POINTER,
+		Error::InvalidX(..) => BZ_INVALID_X,
+		Error::InvalidY(..) => BZ_INVALID_Y,
+		Error::InvalidZ(..) => BZ_INVALID_Z,
+		Error::Overflow => BZ_OVERFLOW,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use core::ptr;
+	use crate::build_zip;
+	use super::*;
+
+	#[test]
+	fn spans() {
+		let point = |x, y| BzPoint2 { x, y };
+		let mut zip = ptr::null_mut();
+		unsafe {
+			let status = bz_zip2d_new(point(50, 50), point(0, 100), point(100, 100), 1, &mut zip);
+			assert_eq!(status, BZ_OK);
+			assert_eq!(bz_zip2d_remaining(zip), 51);
+			let mut span = BzSpan::default();
+			let expected = build_zip!(2D:Y - (50, 50) -> (0, 100), (100, 100)).unwrap().spans();
+			for expected in expected {
+				assert!(bz_zip2d_next_span(zip, &mut span));
+				assert_eq!(span.axis_value, expected.axis_value);
+				assert_eq!(span.start..=span.end, expected.range);
+			}
+			assert!(!bz_zip2d_next_span(zip, &mut span));
+			bz_zip2d_free(zip);
+			let status = bz_zip2d_new(point(50, 50), point(0, 100), point(100, 90), 1, &mut zip);
+			assert_eq!(status, BZ_INVALID_Y);
+			let status = bz_zip2d_new(point(50, 50), point(0, 100), point(100, 100), 2, &mut zip);
+			assert_eq!(status, BZ_MISSING_AXIS);
+			assert!(!bz_zip2d_next_span(ptr::null_mut(), &mut span));
+			let (min, max) = (point(i32::MIN, i32::MIN), point(i32::MAX, i32::MAX));
+			assert_eq!(bz_zip2d_new(min, point(i32::MIN, i32::MAX), max, 1, &mut zip), BZ_OVERFLOW);
+		}
+	}
+
+	#[test]
+	fn pairs() {
+		let point = |x, y, z| BzPoint3 { x, y, z };
+		let mut zip = ptr::null_mut();
+		unsafe {
+			let (start, end_a, end_b) = (point(0, 0, 0), point(-4, 2, 8), point(4, 6, 8));
+			assert_eq!(bz_zip3d_new(start, end_a, end_b, 2, &mut zip), BZ_OK);
+			let (mut a, mut b) = (BzPoint3::default(), BzPoint3::default());
+			let expected = build_zip!(3D:Z - (0, 0, 0) -> (-4, 2, 8), (4, 6, 8)).unwrap();
+			for (expected_a, expected_b) in expected {
+				assert!(bz_zip3d_next_pair(zip, &mut a, &mut b));
+				assert_eq!(((a.x, a.y, a.z), (b.x, b.y, b.z)), (expected_a, expected_b));
+			}
+			assert_eq!(bz_zip3d_remaining(zip), 0);
+			bz_zip3d_free(zip);
+			assert_eq!(bz_zip3d_new(start, end_a, end_b, 3, &mut zip), BZ_MISSING_AXIS);
+			assert_eq!(bz_zip3d_new(start, end_a, end_b, 2, ptr::null_mut()), BZ_NULL_POINTER);
+			let (min, max) = (i32::MIN, i32::MAX);
+			let (start, end_b) = (point(min, min, min), point(max, max, max));
+			let status = bz_zip3d_new(start, point(min, 0, max), end_b, 2, &mut zip);
+			assert_eq!(status, BZ_OVERFLOW);
+		}
+	}
+
+}
@@ -0,0 +1,115 @@
+//! Package with the iterator to get every point of a rasterized triangle
+
+use core::fmt::{Debug, Formatter};
+use crate::convert::{FromTuple, PointsAs};
+use crate::line::{Bresenham, Bresenham3d};
+use crate::{Point2, Point3, SignedNum};
+
+/// Points that can be joined with a line to fill the space between the pairs of a zip
+pub trait Fillable: Copy {
+	/// Iterator over every point of the line
+	type Line: Iterator<Item = Self>;
+
+	/// Returns the line going from `start` to `end`, both included
+	fn line(start: Self, end: Self) -> Self::Line;
+}
+
+impl<T: SignedNum> Fillable for Point2<T> {
+	type Line = Bresenham<T>;
+
+	fn line(start: Self, end: Self) -> Self::Line {
+		Bresenham::new(start, end)
+	}
+}
+
+impl<T: SignedNum> Fillable for Point3<T> {
+	type Line = Bresenham3d<T>;
+
+	fn line(start: Self, end: Self) -> Self::Line {
+		Bresenham3d::new(start, end)
+	}
+}
+
+/// Iterator expanding each pair of points yielded by a zip into every point between them.
+/// This way, it yields all the points conforming the rasterized triangle.
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (4, 0), (0, 4));
+/// let pixels = triangle.rasterize_along(Axis::Y).unwrap().pixels();
+/// assert_eq!(pixels.count(), 15);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct FillIterator<I, P: Fillable> {
+	zip: I,
+	line: Option<P::Line>,
+}
+
+impl<I, P: Fillable> FillIterator<I, P> {
+
+	#[inline]
+	pub(crate) fn new(zip: I) -> Self {
+		Self { zip, line: None }
+	}
+
+	/// Turns the iterator into one yielding the points as the point type `Q`, like the arrays,
+	/// see [PointsAs]
+	pub fn points_as<Q: FromTuple<P>>(self) -> PointsAs<Self, Q> {
+		PointsAs::new(self)
+	}
+
+}
+
+impl<I: Iterator<Item = (P, P)>, P: Fillable> Iterator for FillIterator<I, P> {
+	type Item = P;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(point) = self.line.as_mut().and_then(|line| line.next()) {
+				return Some(point);
+			}
+			let (a, b) = self.zip.next()?;
+			self.line = Some(P::line(a, b));
+		}
+	}
+}
+
+impl<I: Debug, P: Fillable> Debug for FillIterator<I, P> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "FillIterator [ {:?} ]", self.zip)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::zip::BresenhamZip;
+	use crate::zip_3d::Bresenham3dZip;
+
+	#[test]
+	fn pixels() {
+		let mut pixels = BresenhamZip::new((2, 0), (0, 2), (4, 2), 1).pixels();
+		assert_eq!(pixels.next(), Some((2, 0)));
+		for x in 1..=3 {
+			assert_eq!(pixels.next(), Some((x, 1)));
+		}
+		for x in 0..=4 {
+			assert_eq!(pixels.next(), Some((x, 2)));
+		}
+		assert_eq!(pixels.next(), None);
+	}
+
+	#[test]
+	fn pixels_3d() {
+		let pixels = Bresenham3dZip::new((0, 0, 0), (0, 0, 10), (10, 10, 10), 2).pixels();
+		let mut z = 0;
+		let mut count = 0;
+		for point in pixels {
+			assert!(point.2 >= z);
+			assert_eq!(point.0, point.1);
+			z = point.2;
+			count += 1;
+		}
+		assert_eq!(count, 66);
+	}
+
+}
@@ -0,0 +1,151 @@
+//! Package with the fixed-point numbers to step the zips with sub-pixel precision
+
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+use crate::FloatNum;
+
+/// Signed fixed-point number with `FRAC` fractional bits stored in an `i32`. It only uses integer
+/// arithmetic, so it can be used with the DDA zips, like [Dda](crate::zip::Dda), to get
+/// sub-pixel precise points in targets without floating-point unit.
+///
+/// ```
+/// # use bresenham_zip::{Axis, fixed::Fixed26_6, zip::Dda};
+/// let start = (Fixed26_6::from_int(5), Fixed26_6::from_raw(32));
+/// let end1 = (Fixed26_6::from_int(0), Fixed26_6::from_raw(672));
+/// let end2 = (Fixed26_6::from_int(10), Fixed26_6::from_raw(672));
+/// for (left, right) in Dda::new(start, end1, end2, Axis::Y).unwrap() {
+///   assert_eq!(left.1, right.1);
+///   assert!(left.0 <= right.0);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FixedPoint<const FRAC: u32 = 6>(i32);
+
+/// Fixed-point number with 26 integer bits and 6 fractional bits, the format used by most font
+/// and vector rasterizers
+pub type Fixed26_6 = FixedPoint<6>;
+
+impl<const FRAC: u32> FixedPoint<FRAC> {
+
+	/// Creates the number with the given raw value, the number multiplied by `2^FRAC`
+	pub const fn from_raw(raw: i32) -> Self {
+		Self(raw)
+	}
+
+	/// Creates the number with the given integer value
+	pub const fn from_int(value: i32) -> Self {
+		Self(value << FRAC)
+	}
+
+	/// Returns the raw value, the number multiplied by `2^FRAC`
+	pub const fn raw(self) -> i32 {
+		self.0
+	}
+
+	/// Returns the largest integer lower than or equal to the number
+	pub const fn floor(self) -> i32 {
+		self.0 >> FRAC
+	}
+
+	/// Returns the nearest integer to the number, rounding half values up
+	pub const fn round(self) -> i32 {
+		(self.0 + (1 << FRAC >> 1)) >> FRAC
+	}
+
+}
+
+impl<const FRAC: u32> From<i32> for FixedPoint<FRAC> {
+	fn from(value: i32) -> Self {
+		Self::from_int(value)
+	}
+}
+
+impl<const FRAC: u32> Add for FixedPoint<FRAC> {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		Self(self.0 + rhs.0)
+	}
+}
+
+impl<const FRAC: u32> AddAssign for FixedPoint<FRAC> {
+	fn add_assign(&mut self, rhs: Self) {
+		self.0 += rhs.0;
+	}
+}
+
+impl<const FRAC: u32> Sub for FixedPoint<FRAC> {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		Self(self.0 - rhs.0)
+	}
+}
+
+impl<const FRAC: u32> Neg for FixedPoint<FRAC> {
+	type Output = Self;
+
+	fn neg(self) -> Self {
+		Self(-self.0)
+	}
+}
+
+impl<const FRAC: u32> Mul for FixedPoint<FRAC> {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self {
+		Self(((self.0 as i64 * rhs.0 as i64) >> FRAC) as i32)
+	}
+}
+
+impl<const FRAC: u32> Div for FixedPoint<FRAC> {
+	type Output = Self;
+
+	fn div(self, rhs: Self) -> Self {
+		Self((((self.0 as i64) << FRAC) / rhs.0 as i64) as i32)
+	}
+}
+
+impl<const FRAC: u32> FloatNum for FixedPoint<FRAC> {
+	const ZERO: Self = Self(0);
+	const ONE: Self = Self(1 << FRAC);
+
+	#[inline]
+	fn to_usize(self) -> usize {
+		self.floor() as usize
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Axis;
+	use crate::zip::Dda;
+	use super::{Fixed26_6, FixedPoint};
+
+	#[test]
+	fn arithmetic() {
+		let half = Fixed26_6::from_raw(32);
+		let three = Fixed26_6::from(3);
+		assert_eq!(three + half, Fixed26_6::from_raw(224));
+		assert_eq!(three - half, Fixed26_6::from_raw(160));
+		assert_eq!(three * half, Fixed26_6::from_raw(96));
+		assert_eq!(three / half, Fixed26_6::from_int(6));
+		assert_eq!(-half, Fixed26_6::from_raw(-32));
+		assert_eq!((three + half).floor(), 3);
+		assert_eq!((three + half).round(), 4);
+		assert_eq!((-half).floor(), -1);
+		assert_eq!(FixedPoint::<8>::from_int(1).raw(), 256);
+	}
+
+	#[test]
+	fn dda() {
+		let start = (Fixed26_6::from_int(5), Fixed26_6::from_raw(32));
+		let end1 = (Fixed26_6::from_int(0), Fixed26_6::from_raw(672));
+		let end2 = (Fixed26_6::from_int(10), Fixed26_6::from_raw(672));
+		let mut zip = Dda::new(start, end1, end2, Axis::Y).unwrap();
+		assert_eq!(zip.len(), 11);
+		assert_eq!(zip.next(), Some((start, start)));
+		let (left, right) = zip.last().unwrap();
+		assert_eq!((left, right), (end1, end2));
+	}
+
+}
@@ -0,0 +1,176 @@
+//! Package with the iteration cap of the zips, a hard bound on the number of steps taken by the
+//! loops driving them
+
+use core::fmt::{Debug, Display, Formatter};
+
+/// Iterator yielding at most `fuel` items of another one, like the pairs of a zip, and `None`
+/// after that even if the iterator has more items left. Each item taken from either end spends
+/// one unit of fuel.
+///
+/// ```
+/// # use bresenham_zip::build_zip;
+/// let zip = build_zip!(2D:Y - (0, 0) -> (-10, 10), (10, 10)).unwrap();
+/// let mut pairs = zip.with_fuel(4);
+/// assert_eq!(pairs.by_ref().count(), 4);
+/// assert!(pairs.is_out_of_fuel());
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Fueled<I> {
+	iter: I,
+	fuel: usize,
+}
+
+impl<I> Fueled<I> {
+
+	/// Creates the iterator capping the items of another one
+	///
+	/// * `iter` - Iterator to cap, like a zip
+	/// * `fuel` - Maximum number of items to yield
+	///
+	#[inline]
+	pub fn new(iter: I, fuel: usize) -> Self {
+		Self { iter, fuel }
+	}
+
+	/// Returns the number of items that can still be yielded
+	#[inline]
+	pub fn fuel(&self) -> usize {
+		self.fuel
+	}
+
+	/// Returns the capped iterator with the items left
+	#[inline]
+	pub fn into_inner(self) -> I {
+		self.iter
+	}
+
+	/// Turns the iterator into one yielding [OutOfFuel] once, instead of stopping silently, when
+	/// the fuel runs out while the capped iterator still has items left
+	#[inline]
+	pub fn fallible(self) -> Fallible<I> {
+		Fallible { fueled: self, failed: false }
+	}
+
+	/// Spends one unit of fuel, returning `false` if there was none left
+	#[inline]
+	fn burn(&mut self) -> bool {
+		let burnt = self.fuel > 0;
+		self.fuel = self.fuel.saturating_sub(1);
+		burnt
+	}
+
+}
+
+impl<I: Iterator> Fueled<I> {
+
+	/// Checks if the fuel ran out before the capped iterator reported being exhausted
+	pub fn is_out_of_fuel(&self) -> bool {
+		self.fuel == 0 && self.iter.size_hint() != (0, Some(0))
+	}
+
+}
+
+impl<I: Iterator> Iterator for Fueled<I> {
+	type Item = I::Item;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.burn() { self.iter.next() } else { None }
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (lower, upper) = self.iter.size_hint();
+		(lower.min(self.fuel), Some(upper.map_or(self.fuel, |upper| upper.min(self.fuel))))
+	}
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Fueled<I> {}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for Fueled<I> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.burn() { self.iter.next_back() } else { None }
+	}
+}
+
+impl<I: Debug> Debug for Fueled<I> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Fueled [ {:?} with {} left ]", self.iter, self.fuel)
+	}
+}
+
+/// Iterator over the items of a [Fueled] one, wrapped in `Ok`, followed by a single
+/// `Err(OutOfFuel)` if the fuel ran out before the capped iterator was exhausted
+///
+/// ```
+/// # use bresenham_zip::{build_zip, fuel::OutOfFuel};
+/// let zip = build_zip!(2D:Y - (0, 0) -> (-10, 10), (10, 10)).unwrap();
+/// let pairs = zip.clone().with_fuel(4).fallible().collect::<Result<Vec<_>, _>>();
+/// assert_eq!(pairs, Err(OutOfFuel));
+/// let pairs = zip.with_fuel(11).fallible().collect::<Result<Vec<_>, _>>();
+/// assert_eq!(pairs.unwrap().len(), 11);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fallible<I> {
+	fueled: Fueled<I>,
+	failed: bool,
+}
+
+impl<I: Iterator> Iterator for Fallible<I> {
+	type Item = Result<I::Item, OutOfFuel>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.failed {
+			return None;
+		}
+		if self.fueled.is_out_of_fuel() {
+			self.failed = true;
+			return Some(Err(OutOfFuel));
+		}
+		self.fueled.next().map(Ok)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (lower, upper) = self.fueled.size_hint();
+		(lower, upper.map(|upper| upper + 1))
+	}
+}
+
+/// Error yielded by [Fallible] when the fuel runs out before the iterator is exhausted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OutOfFuel;
+
+impl Display for OutOfFuel {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		f.write_str("the iteration ran out of fuel before the end")
+	}
+}
+
+impl core::error::Error for OutOfFuel {}
+
+#[cfg(test)]
+mod tests {
+	use crate::build_zip;
+	use super::OutOfFuel;
+
+	#[test]
+	fn capped() {
+		let zip = build_zip!(3D:Z - (0, 0, 0) -> (-4, 2, 8), (4, 6, 8)).unwrap();
+		let mut pairs = zip.clone().with_fuel(5);
+		assert_eq!(pairs.len(), 5);
+		assert_eq!(pairs.next_back(), zip.clone().last());
+		assert!(pairs.by_ref().eq(zip.clone().take(4)));
+		assert!(pairs.is_out_of_fuel());
+		assert_eq!(pairs.into_inner().len(), 4);
+		let mut pairs = zip.clone().with_fuel(9);
+		assert!(pairs.by_ref().eq(zip.clone()));
+		assert!(!pairs.is_out_of_fuel());
+		let mut pairs = zip.with_fuel(2).fallible();
+		assert!(pairs.next().unwrap().is_ok());
+		assert!(pairs.next().unwrap().is_ok());
+		assert_eq!(pairs.next(), Some(Err(OutOfFuel)));
+		assert_eq!(pairs.next(), None);
+	}
+
+}
@@ -0,0 +1,180 @@
+//! Package with the Gouraud shading of the two-dimensional zips
+
+use core::fmt::{Debug, Formatter};
+use crate::barycentric::{Barycentric, BarycentricIterator};
+use crate::dither::Dither;
+use crate::util::{float, wide, Point};
+use crate::{FloatNum, Point2, SignedNum};
+
+/// Span of a triangle shaded with Gouraud, with the color of both of its ends. The `left` point
+/// has the lowest value in the axis out of the zip, so the color of each next pixel of the span
+/// is the one of the previous pixel plus `increment`, the same along the whole triangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GouraudSpan<T, F, const N: usize> {
+	pub left: Point2<T>,
+	pub left_color: [F; N],
+	pub right: Point2<T>,
+	pub right_color: [F; N],
+	pub increment: [F; N],
+}
+
+/// Zip yielding a [GouraudSpan] for each pair of points of a two-dimensional triangle, with the
+/// colors of the vertices interpolated linearly over it. The colors have `N` channels of any
+/// number with fractional part, like `f32` or the [fixed](crate::fixed) numbers, so the fractions
+/// of the increments aren't lost adding them on each pixel.
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+/// let colors = [[255.0, 0.0, 0.0], [0.0, 255.0, 0.0], [0.0, 0.0, 255.0]];
+/// for span in triangle.rasterize_gouraud(colors, Axis::Y).unwrap() {
+///   let mut color = span.left_color;
+///   for x in span.left.0..=span.right.0 {
+///     // fill (x, span.left.1) with color
+///     color.iter_mut().zip(span.increment).for_each(|(channel, step)| *channel += step);
+///   }
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct GouraudZip<I, T, F, const N: usize> {
+	zip: BarycentricIterator<I, Point2<T>>,
+	colors: [[F; N]; 3],
+	increment: [F; N],
+	axis: u8,
+}
+
+impl<I, T: SignedNum, F: FloatNum, const N: usize> GouraudZip<I, T, F, N> {
+
+	pub(crate) fn new(zip: I, vertices: [Point2<T>; 3], colors: [[F; N]; 3], axis: u8) -> Self {
+		let [a, b, c] = vertices.map(|(x, y)| (wide(x), wide(y)));
+		let other = 1 - axis;
+		let total = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+		let mut increment = [F::ZERO; N];
+		if total != 0 {
+			// growth of the weight of each vertex with each unit of the axis out of the zip
+			let growth = if other == 0 {
+				[b.1 - c.1, c.1 - a.1, a.1 - b.1]
+			} else {
+				[c.0 - b.0, a.0 - c.0, b.0 - a.0]
+			};
+			let total = float::<F>(total);
+			for (channel, step) in increment.iter_mut().enumerate() {
+				let mut sum = F::ZERO;
+				for (color, growth) in colors.iter().zip(growth) {
+					sum += color[channel] * float(growth);
+				}
+				*step = sum / total;
+			}
+		}
+		Self {
+			zip: BarycentricIterator::new(zip, vertices),
+			colors,
+			increment,
+			axis,
+		}
+	}
+
+	/// Returns the color of a point given its barycentric coordinates
+	fn color(&self, weights: &Barycentric) -> [F; N] {
+		if weights.total == 0 {
+			return self.colors[0];
+		}
+		let total = float::<F>(weights.total);
+		let mut color = [F::ZERO; N];
+		for (i, channel) in color.iter_mut().enumerate() {
+			for (vertex, weight) in self.colors.iter().zip(weights.weights) {
+				*channel += vertex[i] * float(weight);
+			}
+			*channel = *channel / total;
+		}
+		color
+	}
+
+}
+
+impl<I, T: SignedNum, F: FloatNum> GouraudZip<I, T, F, 1> {
+
+	/// Turns the zip shading a single intensity, from zero to one, into an iterator over the
+	/// pixels lit by the ordered dither of the intensity, see [Dither]
+	pub fn dither(self) -> Dither<Self, T, F> {
+		let axis = self.axis;
+		Dither::new(self, axis)
+	}
+
+}
+
+impl<I, T, F, const N: usize> Iterator for GouraudZip<I, T, F, N>
+	where I: Iterator<Item = (Point2<T>, Point2<T>)>, T: SignedNum, F: FloatNum {
+	type Item = GouraudSpan<T, F, N>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let ((a, weights_a), (b, weights_b)) = self.zip.next()?;
+		let other = 1 - self.axis;
+		let (left, right) = if a.nth(other) <= b.nth(other) {
+			((a, weights_a), (b, weights_b))
+		} else {
+			((b, weights_b), (a, weights_a))
+		};
+		Some(GouraudSpan {
+			left: left.0,
+			left_color: self.color(&left.1),
+			right: right.0,
+			right_color: self.color(&right.1),
+			increment: self.increment,
+		})
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.zip.size_hint()
+	}
+}
+
+impl<I: Debug, T: Debug, F, const N: usize> Debug for GouraudZip<I, T, F, N> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "GouraudZip [ {:?} ]", self.zip)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::fixed::Fixed26_6;
+	use crate::triangle::Triangle2;
+	use crate::Axis;
+
+	#[test]
+	fn increment() {
+		// the color is x + 2y
+		let triangle = Triangle2::new((0, 0), (10, 0), (0, 10));
+		let colors = [[0.0], [10.0], [20.0]];
+		for axis in [Axis::X, Axis::Y] {
+			for span in triangle.rasterize_gouraud(colors, axis).unwrap() {
+				let (left, right) = (span.left, span.right);
+				assert_eq!(span.left_color, [(left.0 + 2 * left.1) as f64]);
+				assert_eq!(span.right_color, [(right.0 + 2 * right.1) as f64]);
+				assert_eq!(span.increment, [if axis == Axis::Y { 1.0 } else { 2.0 }]);
+			}
+		}
+	}
+
+	#[test]
+	fn channels() {
+		let triangle = Triangle2::new((0, 0), (-8, 8), (8, 8));
+		let white = [255, 255, 255].map(Fixed26_6::from_int);
+		let red = [255, 0, 0].map(Fixed26_6::from_int);
+		let mut spans = triangle.rasterize_gouraud([white, red, red], Axis::Y).unwrap();
+		let apex = spans.next().unwrap();
+		assert_eq!((apex.left_color, apex.right_color), (white, white));
+		let base = spans.last().unwrap();
+		assert_eq!((base.left_color, base.right_color), (red, red));
+		assert_eq!(base.increment, [Fixed26_6::from_int(0); 3]);
+	}
+
+	#[test]
+	fn degenerate() {
+		let triangle = Triangle2::new((0, 0), (5, 5), (10, 10));
+		let span = triangle.rasterize_gouraud([[1.0], [2.0], [3.0]], Axis::Y).unwrap().next();
+		assert_eq!(span.map(|span| (span.left_color, span.increment)), Some(([1.0], [0.0])));
+	}
+
+}
@@ -0,0 +1,166 @@
+//! Package with the stamping of footprints into occupancy grids, like the costmaps of robots
+//! marking the cells covered by obstacles or by their own shape
+
+use core::fmt::{Debug, Formatter};
+use num_traits::SaturatingAdd;
+use crate::sink::SpanSink;
+use crate::span::Span;
+use crate::triangle::Triangle2;
+use crate::{Axis, Point2, SignedNum};
+
+/// Way of combining the value stamped in a cell with the one it already has
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Policy {
+	/// Adds the value to the cell, saturating at the highest value of its type, like to count
+	/// the footprints covering it
+	Add,
+	/// Keeps the highest of both values, like the cost of the most dangerous obstacle
+	Max,
+	/// Replaces the cell with the value, like the id of the last footprint stamped
+	Overwrite,
+}
+
+/// Occupancy grid over a slice provided by the caller, storing its cells row by row. The
+/// footprints are stamped with a [Policy], covering each cell of a footprint once, and the parts
+/// out of the grid are ignored.
+///
+/// ```
+/// # use bresenham_zip::{grid::{Grid, Policy}, triangle::Triangle2};
+/// let mut cells = [0u8; 16 * 16];
+/// let mut grid = Grid::new(&mut cells, 16).with_policy(Policy::Add);
+/// grid.stamp(&Triangle2::new((0, 0), (15, 0), (0, 15)), 1);
+/// grid.stamp(&Triangle2::new((15, 15), (15, 0), (0, 15)), 1);
+/// // the diagonal is covered by both triangles
+/// assert_eq!(grid.cell((3, 12)), Some(2));
+/// assert_eq!(grid.cell((3, 3)), Some(1));
+/// ```
+pub struct Grid<'a, V> {
+	cells: &'a mut [V],
+	width: usize,
+	height: usize,
+	policy: Policy,
+}
+
+impl<'a, V: Copy + Ord + SaturatingAdd> Grid<'a, V> {
+
+	/// Creates the grid over the slice, stamping with [Policy::Max]
+	///
+	/// * `cells` - Cells of the grid, row by row. Its length must be a multiple of the width
+	/// * `width` - Number of cells of each row
+	///
+	pub fn new(cells: &'a mut [V], width: usize) -> Self {
+		let height = cells.len().checked_div(width).unwrap_or_default();
+		Self { cells, width, height, policy: Policy::Max }
+	}
+
+	/// Changes the way the stamped values are combined with the cells
+	///
+	/// * `policy` - Policy of the following stamps
+	///
+	pub fn with_policy(mut self, policy: Policy) -> Self {
+		self.policy = policy;
+		self
+	}
+
+	/// Returns the value of the cell, or `None` if it's out of the grid
+	pub fn cell<T: SignedNum>(&self, (x, y): Point2<T>) -> Option<V> {
+		let (x, y) = (x.to_usize()?, y.to_usize()?);
+		(x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+	}
+
+	/// Stamps the value in the cells covered by the triangle
+	///
+	/// * `triangle` - Footprint to stamp, in the coordinates of the cells
+	/// * `value` - Value combined with the cells by the policy
+	///
+	pub fn stamp<T: SignedNum>(&mut self, triangle: &Triangle2<T>, value: V) {
+		if let Some(zip) = triangle.rasterize_along(Axis::Y) {
+			zip.rasterize_into(self, value);
+		}
+	}
+
+	/// Stamps the value in the cells covered by the polygon. Requires the `alloc` feature.
+	///
+	/// * `polygon` - Footprint to stamp, in the coordinates of the cells
+	/// * `rule` - Rule to decide the parts inside of the polygon
+	/// * `value` - Value combined with the cells by the policy
+	///
+	#[cfg(feature = "alloc")]
+	pub fn stamp_polygon<T: SignedNum>(&mut self, polygon: &crate::polygon::Polygon<T>,
+		rule: crate::polygon::FillRule, value: V) {
+		if let Some(spans) = polygon.spans(Axis::Y, rule) {
+			self.stamp_spans(spans, value);
+		}
+	}
+
+	/// Stamps the value in the cells covered by the spans of the rows, like the ones of a
+	/// [Boolean](crate::boolean::Boolean) combination of footprints. The spans must not overlap,
+	/// or their cells are stamped more than once.
+	///
+	/// * `spans` - Spans to stamp, with the rows as axis value
+	/// * `value` - Value combined with the cells by the policy
+	///
+	pub fn stamp_spans<T: SignedNum, I: Iterator<Item = Span<T>>>(&mut self, spans: I, value: V) {
+		for span in spans {
+			let (start, end) = span.range.into_inner();
+			self.fill_span(span.axis_value, start, end, value);
+		}
+	}
+
+}
+
+impl<T: SignedNum, V: Copy + Ord + SaturatingAdd> SpanSink<T, V> for Grid<'_, V> {
+	fn fill_span(&mut self, y: T, x0: T, x1: T, value: V) {
+		let (Some(y), Some(x1)) = (y.to_usize(), x1.to_usize()) else {
+			return;
+		};
+		let x0 = x0.to_usize().unwrap_or_default();
+		if y >= self.height || x0 > x1 || x0 >= self.width {
+			return;
+		}
+		let row = y * self.width;
+		let cells = &mut self.cells[row + x0..=row + x1.min(self.width - 1)];
+		match self.policy {
+			Policy::Add => cells.iter_mut().for_each(|cell| *cell = cell.saturating_add(&value)),
+			Policy::Max => cells.iter_mut().for_each(|cell| *cell = value.max(*cell)),
+			Policy::Overwrite => cells.fill(value),
+		}
+	}
+}
+
+impl<V> Debug for Grid<'_, V> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Grid [ {}x{} ]", self.width, self.height)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::triangle::Triangle2;
+	use super::{Grid, Policy};
+
+	#[test]
+	fn policies() {
+		let big = Triangle2::new((-4, -4), (12, -4), (-4, 12));
+		let small = Triangle2::new((0, 0), (3, 0), (0, 3));
+		let mut cells = [250u8; 8 * 8];
+		let mut grid = Grid::new(&mut cells, 8).with_policy(Policy::Add);
+		grid.stamp(&big, 3);
+		grid.stamp(&small, 3);
+		assert_eq!(grid.cell((0, 0)), Some(255));
+		assert_eq!(grid.cell((4, 0)), Some(253));
+		assert_eq!(grid.cell((7, 7)), Some(250));
+		assert_eq!(grid.cell((8, 0)), None);
+		let mut grid = grid.with_policy(Policy::Max);
+		grid.stamp(&Triangle2::new((4, 7), (7, 7), (7, 0)), 252);
+		assert_eq!(grid.cell((4, 0)), Some(253));
+		assert_eq!(grid.cell((7, 7)), Some(252));
+		let mut grid = grid.with_policy(Policy::Overwrite);
+		grid.stamp(&small, 7);
+		grid.stamp(&Triangle2::new((20, 20), (30, 20), (20, 30)), 7);
+		assert_eq!(cells.iter().filter(|cell| **cell == 7).count(), 10);
+	}
+
+}
@@ -0,0 +1,133 @@
+//! Package with the dump of rasterized triangles into PGM and PPM images, to check the output of
+//! the zips visually. Requires the `std` feature.
+
+use std::fs::File;
+use std::io::{BufWriter, Result, Write};
+use std::path::Path;
+use std::vec;
+use std::vec::Vec;
+use crate::sink::SliceSink;
+use crate::triangle::Triangle2;
+use crate::{Axis, SignedNum};
+
+/// Pixel of an [Image], defining the format of the file written: PGM for the grayscale `u8` and
+/// PPM for the RGB `[u8; 3]`
+pub trait PixelFormat: Copy + Default {
+	/// Magic number starting the header of the file
+	const MAGIC: &'static str;
+
+	/// Returns the bytes of the pixel in the file
+	fn bytes(&self) -> &[u8];
+}
+
+impl PixelFormat for u8 {
+	const MAGIC: &'static str = "P5";
+
+	fn bytes(&self) -> &[u8] {
+		core::slice::from_ref(self)
+	}
+}
+
+impl PixelFormat for [u8; 3] {
+	const MAGIC: &'static str = "P6";
+
+	fn bytes(&self) -> &[u8] {
+		self
+	}
+}
+
+/// Image where the triangles are rasterized along [Axis::Y], row by row with the pixel `(0, 0)`
+/// at the top left corner. The parts of the triangles out of it are ignored.
+///
+/// ```
+/// # use bresenham_zip::{image_dump::Image, triangle::Triangle2};
+/// let mut image = Image::new(4, 4);
+/// image.draw(&Triangle2::new((0, 0), (3, 0), (0, 3)), 255u8);
+/// let mut file = Vec::new();
+/// image.write(&mut file).unwrap();
+/// assert!(file.starts_with(b"P5\n4 4\n255\n\xff\xff\xff\xff\xff\xff\xff\x00"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image<V> {
+	width: usize,
+	height: usize,
+	pixels: Vec<V>,
+}
+
+impl<V: PixelFormat> Image<V> {
+
+	/// Creates the image with every pixel set to its default value, black
+	///
+	/// * `width`, `height` - Size of the image in pixels
+	///
+	pub fn new(width: usize, height: usize) -> Self {
+		Self { width, height, pixels: vec![V::default(); width * height] }
+	}
+
+	/// Fills the pixels of the triangle with the value
+	///
+	/// * `triangle` - Triangle to draw
+	/// * `value` - Value of its pixels, like its color
+	///
+	pub fn draw<T: SignedNum>(&mut self, triangle: &Triangle2<T>, value: V) {
+		if let Some(zip) = triangle.rasterize_along(Axis::Y) {
+			zip.rasterize_into(&mut SliceSink::new(&mut self.pixels, self.width), value);
+		}
+	}
+
+	/// Returns the pixels of the image, row by row
+	pub fn pixels(&self) -> &[V] {
+		&self.pixels
+	}
+
+	/// Writes the image in binary PGM or PPM format, see [PixelFormat]
+	pub fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+		write!(writer, "{}\n{} {}\n255\n", V::MAGIC, self.width, self.height)?;
+		for pixel in &self.pixels {
+			writer.write_all(pixel.bytes())?;
+		}
+		writer.flush()
+	}
+
+	/// Writes the image into the file in the path, replacing it if it exists, see
+	/// [write](Self::write)
+	pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+		self.write(BufWriter::new(File::create(path)?))
+	}
+
+}
+
+/// Draws the triangles in white over a black grayscale image and saves it as a PGM file
+///
+/// * `path` - Path of the file, replaced if it exists
+/// * `width`, `height` - Size of the image in pixels
+/// * `triangles` - Triangles to draw
+///
+pub fn dump<T, P>(path: P, width: usize, height: usize, triangles: &[Triangle2<T>]) -> Result<()>
+	where T: SignedNum, P: AsRef<Path> {
+	let mut image = Image::new(width, height);
+	for triangle in triangles {
+		image.draw(triangle, u8::MAX);
+	}
+	image.save(path)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::vec::Vec;
+	use crate::triangle::Triangle2;
+	use super::Image;
+
+	#[test]
+	fn ppm() {
+		let mut image = Image::new(3, 2);
+		image.draw(&Triangle2::new((1, -5), (1, 1), (9, 1)), [255, 0, 0]);
+		image.draw(&Triangle2::new((0, 0), (0, 0), (0, 0)), [0, 0, 255]);
+		let mut file = Vec::new();
+		image.write(&mut file).unwrap();
+		let (header, pixels) = file.split_at(11);
+		assert_eq!(header, b"P6\n3 2\n255\n");
+		assert_eq!(pixels, [0, 0, 255, 255, 0, 0, 255, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0]);
+	}
+
+}
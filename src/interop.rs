@@ -0,0 +1,179 @@
+//! Implementations of the point traits for the vector types of other math crates
+
+use crate::{Point2Like, Point3Like};
+
+#[cfg(feature = "glam")]
+impl Point2Like<i32> for glam::IVec2 {
+	#[inline]
+	fn x(&self) -> i32 {
+		self.x
+	}
+
+	#[inline]
+	fn y(&self) -> i32 {
+		self.y
+	}
+
+	#[inline]
+	fn set_x(&mut self, x: i32) {
+		self.x = x;
+	}
+
+	#[inline]
+	fn set_y(&mut self, y: i32) {
+		self.y = y;
+	}
+}
+
+#[cfg(feature = "glam")]
+impl Point3Like<i32> for glam::IVec3 {
+	#[inline]
+	fn x(&self) -> i32 {
+		self.x
+	}
+
+	#[inline]
+	fn y(&self) -> i32 {
+		self.y
+	}
+
+	#[inline]
+	fn z(&self) -> i32 {
+		self.z
+	}
+
+	#[inline]
+	fn set_x(&mut self, x: i32) {
+		self.x = x;
+	}
+
+	#[inline]
+	fn set_y(&mut self, y: i32) {
+		self.y = y;
+	}
+
+	#[inline]
+	fn set_z(&mut self, z: i32) {
+		self.z = z;
+	}
+}
+
+#[cfg(feature = "mint")]
+macro_rules! mint_2d {
+	($($t:ident),*) => {
+		$(
+			impl<T: Copy> Point2Like<T> for mint::$t<T> {
+				#[inline]
+				fn x(&self) -> T {
+					self.x
+				}
+
+				#[inline]
+				fn y(&self) -> T {
+					self.y
+				}
+
+				#[inline]
+				fn set_x(&mut self, x: T) {
+					self.x = x;
+				}
+
+				#[inline]
+				fn set_y(&mut self, y: T) {
+					self.y = y;
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "mint")]
+macro_rules! mint_3d {
+	($($t:ident),*) => {
+		$(
+			impl<T: Copy> Point3Like<T> for mint::$t<T> {
+				#[inline]
+				fn x(&self) -> T {
+					self.x
+				}
+
+				#[inline]
+				fn y(&self) -> T {
+					self.y
+				}
+
+				#[inline]
+				fn z(&self) -> T {
+					self.z
+				}
+
+				#[inline]
+				fn set_x(&mut self, x: T) {
+					self.x = x;
+				}
+
+				#[inline]
+				fn set_y(&mut self, y: T) {
+					self.y = y;
+				}
+
+				#[inline]
+				fn set_z(&mut self, z: T) {
+					self.z = z;
+				}
+			}
+		)*
+	};
+}
+
+#[cfg(feature = "mint")]
+mint_2d!(Point2, Vector2);
+#[cfg(feature = "mint")]
+mint_3d!(Point3, Vector3);
+
+#[cfg(test)]
+mod tests {
+
+	#[cfg(feature = "glam")]
+	#[test]
+	fn glam() {
+		use glam::{IVec2, IVec3};
+		use crate::zip::Builder;
+		use crate::zip_3d::Builder3d;
+		use crate::Axis;
+
+		let zip = Builder::new().axis(Axis::Y).start_point(IVec2::new(5, 0))
+			.first_ending_point(IVec2::new(0, 5)).second_ending_point(IVec2::new(10, 5))
+			.build().unwrap();
+		assert_eq!(zip.pairs_as::<IVec2>().last(), Some((IVec2::new(0, 5), IVec2::new(10, 5))));
+		let zip = Builder3d::new().axis(Axis::Z).start_point(IVec3::new(0, 0, 0))
+			.first_ending_point(IVec3::new(0, 4, 4)).second_ending_point(IVec3::new(4, 4, 4))
+			.build().unwrap();
+		let mut pairs = zip.pairs_as::<IVec3>();
+		assert_eq!(pairs.nth(2), Some((IVec3::new(0, 2, 2), IVec3::new(2, 2, 2))));
+	}
+
+
+	#[cfg(feature = "mint")]
+	#[test]
+	fn mint() {
+		use mint::{Point2, Point3, Vector3};
+		use crate::zip::Builder;
+		use crate::zip_3d::Builder3d;
+		use crate::Axis;
+
+		let zip = Builder::new().axis(Axis::X).start_point(Point2 { x: 0, y: 0 })
+			.first_ending_point(Point2 { x: 4, y: -4 }).second_ending_point(Point2 { x: 4, y: 4 })
+			.build().unwrap();
+		// the mint types have no default value, so they are converted from the arrays
+		let (a, b) = zip.pairs_as::<[i32; 2]>().last().unwrap();
+		assert_eq!(Point2::from(a), Point2 { x: 4, y: -4 });
+		assert_eq!(Point2::from(b), Point2 { x: 4, y: 4 });
+		let zip = Builder3d::new().axis(Axis::Y).start_point(Vector3 { x: 0i64, y: 0, z: 0 })
+			.first_ending_point(Point3 { x: 2, y: 2, z: 0 })
+			.second_ending_point(Vector3 { x: -2, y: 2, z: 6 })
+			.build().unwrap();
+		assert_eq!(zip.last(), Some(((2, 2, 0), (-2, 2, 6))));
+	}
+
+}
@@ -18,7 +18,7 @@
 //! # use bresenham_zip::zip::Builder;
 //! # fn main() -> Result<(), Box<dyn Error>> {
 //! for (left, right) in Builder::new().axis(bresenham_zip::Axis::Y).start_point((50, 50))
-//! 			.first_ending_point((0, 100)).second_ending_point((250, 100)).build()? {
+//!       .first_ending_point((0, 100)).second_ending_point((250, 100)).build()? {
 //!   assert_eq!(left.1, right.1);
 //!   assert!((0..=50).contains(&left.0));
 //!   assert!((50..=250).contains(&right.0));
@@ -45,6 +45,18 @@
 //! # }
 //! ```
 //!
+//! ## Any triangle
+//! If the vertices of the triangle don't share any value, use [triangle::Triangle2] or
+//! [triangle_3d::Triangle3]. They split the triangle in two at its middle vertex and chain the
+//! zips of both halves.
+//! ```
+//! # use bresenham_zip::{Axis, triangle::Triangle2};
+//! let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+//! for (left, right) in triangle.rasterize_along(Axis::Y).unwrap() {
+//!   assert_eq!(left.1, right.1);
+//! }
+//! ```
+//!
 //! ## build_zip! macro
 //! This macro allows you to build a whole zip to iterate with a single line. You need to specify
 //! the dimension of the space, the axis to navigate and three points following the next schema:
@@ -52,6 +64,9 @@
 //! {2D|3D}:{X|Y|Z} - {starting_point} -> {first_ending_point}, {second_ending_point}
 //! _Ignore { and } when writting it_
 //!
+//! The dimension and the axis can be written in lowercase too, and the dimension can be left out
+//! when the starting point is a tuple or an array, like in `y - (50, 50) -> (0, 100), (0, 100)`.
+//!
 //! ```
 //! # use std::error::Error;
 //! # use bresenham_zip::build_zip;
@@ -63,20 +78,110 @@
 //! ```
 //!
 //!
-#![feature(error_in_core)]
 #![no_std]
 extern crate core;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub mod zip_3d;
 pub mod zip;
+pub mod triangle;
+pub mod triangle_3d;
+pub mod clip;
+pub mod fill;
+pub mod barycentric;
+pub mod depth;
+pub mod gouraud;
+pub mod zbuffer;
+pub mod line;
+pub mod fixed;
+pub mod span;
+pub mod sink;
+pub mod grid;
+pub mod rle;
+pub mod stipple;
+pub mod dither;
+pub mod boolean;
+pub mod tile;
+pub mod tiler;
+pub mod mesh;
+pub mod fan;
+pub mod circle;
+pub mod ellipse;
+pub mod arc;
+pub mod rounded;
+pub mod tetrahedron;
+pub mod slicer;
+pub mod ray;
+pub mod convert;
+pub mod state;
+pub mod fuel;
+#[cfg(feature = "alloc")]
+pub mod polygon;
+#[cfg(feature = "alloc")]
+pub mod voxelize;
+#[cfg(feature = "alloc")]
+pub mod scanline;
+#[cfg(feature = "async")]
+pub mod stream;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "std")]
+pub mod image_dump;
+#[cfg(feature = "std")]
+pub mod braille;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(feature = "glam", feature = "mint"))]
+mod interop;
 mod error;
 mod build_zip;
+mod rasterize;
 mod util;
+mod zip_core;
+
+pub use error::{BuilderOption, Error, PointRole};
+
+/// Trait to represent any valid number to use with the **BresenhamZip**: the signed integers.
+pub trait SignedNum: num_traits::Signed + num_traits::NumCast + Ord + Copy + core::fmt::Debug
+	+ core::ops::AddAssign + core::ops::SubAssign {}
+impl<T> SignedNum for T
+	where T: num_traits::Signed + num_traits::NumCast + Ord + Copy + core::fmt::Debug
+	+ core::ops::AddAssign + core::ops::SubAssign {}
+
+/// Trait to represent the numbers with fractional part accepted by the DDA zips: `f32`, `f64` and
+/// the [fixed::FixedPoint] numbers
+pub trait FloatNum: Copy + PartialOrd + core::fmt::Debug + core::ops::Neg<Output = Self>
+	+ core::ops::Add<Output = Self> + core::ops::Sub<Output = Self>
+	+ core::ops::Mul<Output = Self> + core::ops::Div<Output = Self> + core::ops::AddAssign {
+	/// Additive identity
+	const ZERO: Self;
+	/// Multiplicative identity
+	const ONE: Self;
+
+	/// Truncates the number into an unsigned integer
+	fn to_usize(self) -> usize;
+}
+
+macro_rules! float_num {
+	($($t:ty),*) => {
+		$(
+			impl FloatNum for $t {
+				const ZERO: Self = 0.0;
+				const ONE: Self = 1.0;
+
+				#[inline]
+				fn to_usize(self) -> usize {
+					self as usize
+				}
+			}
+		)*
+	};
+}
 
-/// Trait to represent any valid number to use with the **BresenhamZip**.
-/// Extension of [line_drawing::SignedNum] to allow the use of [std::fmt::Debug].
-pub trait SignedNum: line_drawing::SignedNum + core::fmt::Debug {}
-impl<T: line_drawing::SignedNum + core::fmt::Debug> SignedNum for T {}
+float_num!(f32, f64);
 
 /// A point in 2D space
 pub type Point2<T> = (T, T);
@@ -84,10 +189,247 @@ pub type Point2<T> = (T, T);
 /// A point in 3D space
 pub type Point3<T> = (T, T, T);
 
+/// Two-dimensional point type accepted by the builders in place of a [Point2], so the vertex
+/// types of the users can be passed directly. It's implemented by the tuples and the arrays.
+///
+/// ```
+/// # use bresenham_zip::{Axis, Point2Like, zip::Builder};
+/// #[derive(Default)]
+/// struct Vertex { x: i32, y: i32 }
+///
+/// impl Point2Like<i32> for Vertex {
+///   fn x(&self) -> i32 { self.x }
+///   fn y(&self) -> i32 { self.y }
+///   fn set_x(&mut self, x: i32) { self.x = x }
+///   fn set_y(&mut self, y: i32) { self.y = y }
+/// }
+///
+/// let zip = Builder::new().axis(Axis::Y).start_point(Vertex { x: 5, y: 0 })
+///   .first_ending_point(Vertex { x: 0, y: 5 }).second_ending_point([10, 5]).build().unwrap();
+/// let (left, right) = zip.pairs_as::<Vertex>().last().unwrap();
+/// assert_eq!((left.x, right.x, right.y), (0, 10, 5));
+/// ```
+pub trait Point2Like<T> {
+	/// Returns the X coordinate of the point
+	fn x(&self) -> T;
+	/// Returns the Y coordinate of the point
+	fn y(&self) -> T;
+	/// Replaces the X coordinate of the point
+	fn set_x(&mut self, x: T);
+	/// Replaces the Y coordinate of the point
+	fn set_y(&mut self, y: T);
+
+	/// Converts the point into a tuple
+	fn to_tuple(&self) -> Point2<T> {
+		(self.x(), self.y())
+	}
+}
+
+/// Three-dimensional point type accepted by the builders in place of a [Point3], like the
+/// [Point2Like] of the two-dimensional ones. It's implemented by the tuples and the arrays.
+pub trait Point3Like<T> {
+	/// Returns the X coordinate of the point
+	fn x(&self) -> T;
+	/// Returns the Y coordinate of the point
+	fn y(&self) -> T;
+	/// Returns the Z coordinate of the point
+	fn z(&self) -> T;
+	/// Replaces the X coordinate of the point
+	fn set_x(&mut self, x: T);
+	/// Replaces the Y coordinate of the point
+	fn set_y(&mut self, y: T);
+	/// Replaces the Z coordinate of the point
+	fn set_z(&mut self, z: T);
+
+	/// Converts the point into a tuple
+	fn to_tuple(&self) -> Point3<T> {
+		(self.x(), self.y(), self.z())
+	}
+}
+
+impl<T: Copy> Point2Like<T> for Point2<T> {
+	#[inline]
+	fn x(&self) -> T {
+		self.0
+	}
+
+	#[inline]
+	fn y(&self) -> T {
+		self.1
+	}
+
+	#[inline]
+	fn set_x(&mut self, x: T) {
+		self.0 = x;
+	}
+
+	#[inline]
+	fn set_y(&mut self, y: T) {
+		self.1 = y;
+	}
+}
+
+impl<T: Copy> Point2Like<T> for [T; 2] {
+	#[inline]
+	fn x(&self) -> T {
+		self[0]
+	}
+
+	#[inline]
+	fn y(&self) -> T {
+		self[1]
+	}
+
+	#[inline]
+	fn set_x(&mut self, x: T) {
+		self[0] = x;
+	}
+
+	#[inline]
+	fn set_y(&mut self, y: T) {
+		self[1] = y;
+	}
+}
+
+impl<T: Copy> Point3Like<T> for Point3<T> {
+	#[inline]
+	fn x(&self) -> T {
+		self.0
+	}
+
+	#[inline]
+	fn y(&self) -> T {
+		self.1
+	}
+
+	#[inline]
+	fn z(&self) -> T {
+		self.2
+	}
+
+	#[inline]
+	fn set_x(&mut self, x: T) {
+		self.0 = x;
+	}
+
+	#[inline]
+	fn set_y(&mut self, y: T) {
+		self.1 = y;
+	}
+
+	#[inline]
+	fn set_z(&mut self, z: T) {
+		self.2 = z;
+	}
+}
+
+impl<T: Copy> Point3Like<T> for [T; 3] {
+	#[inline]
+	fn x(&self) -> T {
+		self[0]
+	}
+
+	#[inline]
+	fn y(&self) -> T {
+		self[1]
+	}
+
+	#[inline]
+	fn z(&self) -> T {
+		self[2]
+	}
+
+	#[inline]
+	fn set_x(&mut self, x: T) {
+		self[0] = x;
+	}
+
+	#[inline]
+	fn set_y(&mut self, y: T) {
+		self[1] = y;
+	}
+
+	#[inline]
+	fn set_z(&mut self, z: T) {
+		self[2] = z;
+	}
+}
+
 /// An enumeration of the axes that can be used building a new zip
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Axis {
 	X,
 	Y,
 	Z,
+}
+
+/// Value used to join two ending points that don't share the same value in the axis of the zip.
+/// See `axis_tolerance` in the builders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Snap {
+	/// Lowest of both values
+	Min,
+	/// Highest of both values
+	Max,
+	/// Value in the middle of both, truncated
+	Midpoint,
+}
+
+/// End of the triangle where the iteration of a zip starts. See `direction` in the builders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+	/// Starts at the starting point and finishes at the flat edge
+	FromApex,
+	/// Starts at the flat edge and finishes at the starting point
+	FromBase,
+}
+
+/// Selection of the axis travelled by a zip. It's implemented by `u8` to choose the axis at
+/// runtime, and by the [AxisX], [AxisY] and [AxisZ] markers to fix it at compile time and avoid
+/// checking the axis on every coordinate access.
+pub trait AxisSelect: Copy + core::fmt::Debug {
+	/// Index of the axis in the point tuples
+	fn index(&self) -> u8;
+}
+
+impl AxisSelect for u8 {
+	#[inline]
+	fn index(&self) -> u8 {
+		*self
+	}
+}
+
+/// Marker to travel the X axis, selected at compile time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AxisX;
+
+/// Marker to travel the Y axis, selected at compile time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AxisY;
+
+/// Marker to travel the Z axis, selected at compile time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AxisZ;
+
+impl AxisSelect for AxisX {
+	#[inline(always)]
+	fn index(&self) -> u8 {
+		0
+	}
+}
+
+impl AxisSelect for AxisY {
+	#[inline(always)]
+	fn index(&self) -> u8 {
+		1
+	}
+}
+
+impl AxisSelect for AxisZ {
+	#[inline(always)]
+	fn index(&self) -> u8 {
+		2
+	}
 }
\ No newline at end of file
@@ -0,0 +1,400 @@
+//! Package with the Bresenham lines used to fill and outline the triangles
+
+use crate::fan::{Fan, FanSpans};
+use crate::util::{isqrt, narrow, wide, Point};
+use crate::{Axis, Point2, Point3, SignedNum};
+
+/// Iterator over the points of the line between two points, both included, following the
+/// Bresenham line algorithm. The offset of the minor coordinate is truncated, so the line walked
+/// in the opposite direction can differ by one unit when the slope leaves a tie.
+///
+/// ```
+/// # use bresenham_zip::line::Bresenham;
+/// let mut line = Bresenham::new((0, 0), (5, 2));
+/// assert_eq!(line.next(), Some((0, 0)));
+/// assert_eq!(line.last(), Some((5, 2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bresenham<T> {
+	point: Point2<T>,
+	sign: Point2<T>,
+	delta: Point2<T>,
+	error: T,
+	remaining: T,
+	steep: bool,
+}
+
+impl<T: SignedNum> Bresenham<T> {
+
+	/// Creates the line going from `start` to `end`
+	///
+	/// * `start` - First point of the line
+	/// * `end` - Last point of the line
+	///
+	pub fn new(start: Point2<T>, end: Point2<T>) -> Self {
+		let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+		let steep = dy.abs() > dx.abs();
+		// the delta is kept as (major, minor)
+		let delta = if steep { (dy.abs(), dx.abs()) } else { (dx.abs(), dy.abs()) };
+		Self {
+			point: start,
+			sign: (dx.signum(), dy.signum()),
+			delta,
+			error: delta.1 - delta.0,
+			remaining: delta.0 + T::one(),
+			steep,
+		}
+	}
+
+}
+
+impl<T: SignedNum> Iterator for Bresenham<T> {
+	type Item = Point2<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining <= T::zero() {
+			return None;
+		}
+		let point = self.point;
+		let (major, minor) = self.delta;
+		let minor_step = self.error >= T::zero();
+		if minor_step {
+			self.error -= major;
+		}
+		self.error += minor;
+		let (step_x, step_y) = if self.steep { (minor_step, true) } else { (true, minor_step) };
+		if step_x {
+			self.point.0 += self.sign.0;
+		}
+		if step_y {
+			self.point.1 += self.sign.1;
+		}
+		self.remaining -= T::one();
+		Some(point)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.remaining.to_usize().unwrap_or_default();
+		(remaining, Some(remaining))
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for Bresenham<T> {}
+
+/// Iterator over the points of the line between two points in 3D, both included, following the
+/// Bresenham line algorithm. The offsets of the minor coordinates are rounded to the closest
+/// value, rounding down the ties.
+///
+/// ```
+/// # use bresenham_zip::line::Bresenham3d;
+/// let mut line = Bresenham3d::new((0, 0, 0), (4, 2, 1));
+/// assert_eq!(line.len(), 5);
+/// assert_eq!(line.last(), Some((4, 2, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bresenham3d<T> {
+	point: Point3<T>,
+	sign: Point3<T>,
+	delta: Point3<T>,
+	error: [T; 3],
+	remaining: T,
+	major: usize,
+}
+
+impl<T: SignedNum> Bresenham3d<T> {
+
+	/// Creates the line going from `start` to `end`
+	///
+	/// * `start` - First point of the line
+	/// * `end` - Last point of the line
+	///
+	pub fn new(start: Point3<T>, end: Point3<T>) -> Self {
+		let delta = (end.0 - start.0, end.1 - start.1, end.2 - start.2);
+		let distance = [delta.0.abs(), delta.1.abs(), delta.2.abs()];
+		let major = if distance[0] >= distance[1] && distance[0] >= distance[2] {
+			0
+		} else if distance[1] >= distance[2] { 1 } else { 2 };
+		let length = distance[major];
+		Self {
+			point: start,
+			sign: (delta.0.signum(), delta.1.signum(), delta.2.signum()),
+			delta: (distance[0], distance[1], distance[2]),
+			error: distance.map(|distance| distance + distance - length),
+			remaining: length + T::one(),
+			major,
+		}
+	}
+
+}
+
+impl<T: SignedNum> Iterator for Bresenham3d<T> {
+	type Item = Point3<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining <= T::zero() {
+			return None;
+		}
+		let point = self.point;
+		let distance = [self.delta.0, self.delta.1, self.delta.2];
+		let length = distance[self.major];
+		let mut step = [false; 3];
+		for i in (0..3).filter(|i| *i != self.major) {
+			step[i] = self.error[i] > T::zero();
+			if step[i] {
+				self.error[i] -= length + length;
+			}
+			self.error[i] += distance[i] + distance[i];
+		}
+		step[self.major] = true;
+		if step[0] {
+			self.point.0 += self.sign.0;
+		}
+		if step[1] {
+			self.point.1 += self.sign.1;
+		}
+		if step[2] {
+			self.point.2 += self.sign.2;
+		}
+		self.remaining -= T::one();
+		Some(point)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.remaining.to_usize().unwrap_or_default();
+		(remaining, Some(remaining))
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for Bresenham3d<T> {}
+
+/// Iterator over the voxels crossed by the line between two points in 3D, both included, taking
+/// the voxels as cubes of size one centered in their coordinates. Consecutive voxels always share
+/// a face, so the line is 6-connected and the walls built with it have no diagonal leaks. When the
+/// line goes exactly through an edge or a corner of the voxels, it moves first along the axis with
+/// the lowest index.
+///
+/// ```
+/// # use bresenham_zip::line::Supercover3d;
+/// let line = Supercover3d::new((0, 0, 0), (2, 1, 0));
+/// assert!(line.eq([(0, 0, 0), (1, 0, 0), (1, 1, 0), (2, 1, 0)]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Supercover3d<T> {
+	point: Point3<T>,
+	sign: Point3<T>,
+	distance: [T; 3],
+	crossed: [T; 3],
+	remaining: usize,
+}
+
+impl<T: SignedNum> Supercover3d<T> {
+
+	/// Creates the line going from `start` to `end`
+	///
+	/// * `start` - First point of the line
+	/// * `end` - Last point of the line
+	///
+	pub fn new(start: Point3<T>, end: Point3<T>) -> Self {
+		let delta = (end.0 - start.0, end.1 - start.1, end.2 - start.2);
+		let distance = [delta.0.abs(), delta.1.abs(), delta.2.abs()];
+		Self {
+			point: start,
+			sign: (delta.0.signum(), delta.1.signum(), delta.2.signum()),
+			distance,
+			crossed: [T::zero(); 3],
+			remaining: distance.iter().map(|distance| distance.to_usize().unwrap_or_default())
+				.sum::<usize>() + 1,
+		}
+	}
+
+	/// Returns the next voxel to yield without advancing the line
+	pub(crate) fn peek(&self) -> Option<Point3<T>> {
+		(self.remaining > 0).then_some(self.point)
+	}
+
+	/// Keeps only the next `n` voxels of the line
+	pub(crate) fn truncate(&mut self, n: usize) {
+		self.remaining = self.remaining.min(n);
+	}
+
+}
+
+impl<T: SignedNum> Iterator for Supercover3d<T> {
+	type Item = Point3<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		let point = self.point;
+		// the next face crossed in each axis is at (2 * crossed + 1) / (2 * distance) of the line
+		let crossing = |i: usize| wide(self.crossed[i] + self.crossed[i]) + 1;
+		let distance = |i: usize| wide(self.distance[i]);
+		let next = (0..3).filter(|i| self.crossed[*i] < self.distance[*i]).reduce(|a, b| {
+			if crossing(b) * distance(a) < crossing(a) * distance(b) { b } else { a }
+		});
+		if let Some(axis) = next {
+			self.crossed[axis] += T::one();
+			let sign = self.sign.nth(axis as u8);
+			self.point.set_nth(axis as u8, self.point.nth(axis as u8) + sign);
+		}
+		Some(point)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for Supercover3d<T> {}
+
+/// Line with a width, rasterized as the rectangle around the segment between its two points.
+/// The rectangle is split into two triangles following the top-left fill rule, see
+/// [TopLeftZip](crate::zip::TopLeftZip), so the lines of a polyline don't overlap at the points
+/// they share. With the Y axis pointing down, the width is taken to the left of the direction of
+/// the line the half rounded down and to the right the rest, so the lines parallel to the axes
+/// are exactly as wide as requested, and the ones of width 1 cover the row of their points.
+///
+/// ```
+/// # use bresenham_zip::{Axis, line::ThickLine};
+/// let line = ThickLine::new((0, 0), (10, 0), 3);
+/// assert_eq!(line.corners(), [(0, -1), (10, -1), (10, 2), (0, 2)]);
+/// let mut pixels = 0;
+/// for span in line.spans(Axis::Y).unwrap() {
+///   assert!((-1..=1).contains(&span.axis_value) && (0..10).contains(span.range.start()));
+///   pixels += span.range.count();
+/// }
+/// assert_eq!(pixels, 3 * 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThickLine<T> {
+	corners: [Point2<T>; 4],
+}
+
+impl<T: SignedNum> ThickLine<T> {
+
+	/// Creates the line going from `start` to `end`. A line with both points equal has no length
+	/// and yields nothing.
+	///
+	/// * `start` - First point of the line
+	/// * `end` - Last point of the line
+	/// * `width` - Width of the line in pixels
+	///
+	pub fn new(start: Point2<T>, end: Point2<T>, width: T) -> Self {
+		let delta = (wide(end.0) - wide(start.0), wide(end.1) - wide(start.1));
+		let squared = delta.0 * delta.0 + delta.1 * delta.1;
+		// perpendicular to the line with the given length, rounded to the closest integer
+		let normal = |length: i128| {
+			let scale = |value: i128| {
+				let doubled = isqrt(4 * value * value * length * length / squared.max(1));
+				value.signum() * (doubled + 1) / 2
+			};
+			(scale(delta.1), scale(-delta.0))
+		};
+		let (left, right) = (normal(wide(width) / 2), normal(wide(width) - wide(width) / 2));
+		let offset = |point: Point2<T>, (x, y): (i128, i128), sign: i128| {
+			(narrow::<T>(wide(point.0) + sign * x), narrow::<T>(wide(point.1) + sign * y))
+		};
+		Self {
+			corners: [
+				offset(start, left, 1),
+				offset(end, left, 1),
+				offset(end, right, -1),
+				offset(start, right, -1),
+			],
+		}
+	}
+
+	/// Returns the corners of the rectangle, from the first point of the line to the last one on
+	/// its left side and back on its right side
+	pub fn corners(&self) -> [Point2<T>; 4] {
+		self.corners
+	}
+
+	/// Returns an iterator over the spans of the line in the given axis, one of its two triangles
+	/// after the other, so the rows crossing the diagonal between them are split in two spans
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	pub fn spans(&self, axis: Axis) -> Option<FanSpans<'_, T>> {
+		Fan::new(self.corners[0], &self.corners[1..]).spans(axis)
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Axis;
+	use super::{Bresenham, Bresenham3d, Supercover3d, ThickLine};
+
+	#[test]
+	fn octants() {
+		let line = [(0, 0), (1, 0), (2, 1), (3, 1), (4, 2)];
+		let ends: [(i32, i32); 8] =
+			[(4, 2), (2, 4), (-2, 4), (-4, 2), (-4, -2), (-2, -4), (2, -4), (4, -2)];
+		for end in ends {
+			let swap = end.0.abs() < end.1.abs();
+			let sign = (end.0.signum(), end.1.signum());
+			let expected = line.map(|(major, minor)| {
+				let (x, y) = if swap { (minor, major) } else { (major, minor) };
+				(x * sign.0, y * sign.1)
+			});
+			assert!(Bresenham::new((0, 0), end).eq(expected));
+		}
+	}
+
+	#[test]
+	fn single_point() {
+		let mut line = Bresenham::new((3, 3), (3, 3));
+		assert_eq!(line.len(), 1);
+		assert_eq!(line.next(), Some((3, 3)));
+		assert_eq!(line.next(), None);
+	}
+
+	#[test]
+	fn three_dimensions() {
+		let line = Bresenham3d::new((0, 0, 0), (-4, 2, 1));
+		let expected = [(0, 0, 0), (-1, 0, 0), (-2, 1, 0), (-3, 1, 1), (-4, 2, 1)];
+		assert!(line.eq(expected));
+		assert_eq!(Bresenham3d::new((1, 2, 3), (1, 2, 3)).count(), 1);
+	}
+
+	#[test]
+	fn supercover() {
+		let line = Supercover3d::new((0, 0, 0), (-4, 2, 1));
+		assert_eq!(line.len(), 4 + 2 + 1 + 1);
+		let mut previous = (1, 0, 0);
+		for voxel in line {
+			let step = (previous.0 - voxel.0, voxel.1 - previous.1, voxel.2 - previous.2);
+			assert!([(1, 0, 0), (0, 1, 0), (0, 0, 1)].contains(&step), "{:?}", voxel);
+			previous = voxel;
+		}
+		assert_eq!(previous, (-4, 2, 1));
+		// through the corners, one axis after the other
+		let diagonal = Supercover3d::new((0, 0, 0), (2, 2, 2));
+		let steps = [(0, 0, 0), (1, 0, 0), (1, 1, 0), (1, 1, 1), (2, 1, 1), (2, 2, 1), (2, 2, 2)];
+		assert!(diagonal.eq(steps));
+		assert!(Supercover3d::new((5, 5, 5), (5, 5, 5)).eq([(5, 5, 5)]));
+	}
+
+	#[test]
+	fn thick() {
+		let line = ThickLine::new((0, 4), (0, -6), 4);
+		assert_eq!(line.corners(), [(-2, 4), (-2, -6), (2, -6), (2, 4)]);
+		let pixels = line.spans(Axis::X).unwrap().map(|span| span.range.count()).sum::<usize>();
+		assert_eq!(pixels, 4 * 10);
+		// the diagonals cover about the area of their rectangle
+		let line = ThickLine::new((0, 0), (30, 40), 10);
+		let pixels = line.spans(Axis::Y).unwrap().map(|span| span.range.count()).sum::<usize>();
+		assert!((490..=510).contains(&pixels), "{}", pixels);
+		let thin = ThickLine::new((3, 3), (12, 3), 1);
+		let spans = thin.spans(Axis::Y).unwrap().map(|span| (span.axis_value, span.range));
+		assert!(spans.eq([(3, 3..=11)]));
+		assert_eq!(ThickLine::new((3, 3), (3, 3), 5).spans(Axis::Y).unwrap().count(), 0);
+	}
+
+}
@@ -0,0 +1,380 @@
+//! Package with the logic to rasterize indexed two-dimensional meshes
+
+use core::fmt::{Debug, Formatter};
+use core::slice::Iter;
+#[cfg(feature = "alloc")]
+use crate::scanline::Scanlines;
+use crate::span::{Span, SpanIterator};
+use crate::triangle::{Triangle2, TriangleZip};
+use crate::zip::TopLeftZip;
+#[cfg(feature = "alloc")]
+use crate::util::Point;
+use crate::{Axis, Point2, SignedNum};
+
+/// Two-dimensional mesh defined by a list of vertices and the triples of indices of the vertices
+/// conforming each one of its triangles.
+///
+/// ```
+/// # use bresenham_zip::{Axis, mesh::Mesh};
+/// let vertices = [(0, 0), (10, 0), (10, 10), (0, 10)];
+/// let indices = [[0, 1, 2], [0, 2, 3]];
+/// for span in Mesh::new(&vertices, &indices).spans(Axis::Y).unwrap() {
+///   assert!((0..=10).contains(&span.axis_value));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Mesh<'a, T> {
+	vertices: &'a [Point2<T>],
+	indices: &'a [[usize; 3]],
+}
+
+impl<'a, T: SignedNum> Mesh<'a, T> {
+
+	/// Creates a new mesh with the given vertices and triangles
+	///
+	/// * `vertices` - Points of the mesh
+	/// * `indices` - Triples of indices of `vertices` conforming each triangle
+	///
+	pub fn new(vertices: &'a [Point2<T>], indices: &'a [[usize; 3]]) -> Self {
+		Self { vertices, indices }
+	}
+
+	/// Returns an iterator over the triangles of the mesh
+	///
+	/// # Panics
+	/// If any of the indices is out of the bounds of the vertices.
+	pub fn triangles(&self) -> impl Iterator<Item = Triangle2<T>> + 'a {
+		let vertices = self.vertices;
+		self.indices
+			.iter()
+			.map(move |[a, b, c]| Triangle2::new(vertices[*a], vertices[*b], vertices[*c]))
+	}
+
+	/// Returns the triangle with the given index, or `None` if there are fewer triangles
+	///
+	/// # Panics
+	/// If any of its indices is out of the bounds of the vertices.
+	pub(crate) fn triangle(&self, index: usize) -> Option<Triangle2<T>> {
+		let [a, b, c] = *self.indices.get(index)?;
+		Some(Triangle2::new(self.vertices[a], self.vertices[b], self.vertices[c]))
+	}
+
+	/// Returns an iterator over the spans of all the triangles of the mesh in the given axis,
+	/// one triangle after the other.
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	/// # Panics
+	/// If any of the indices is out of the bounds of the vertices.
+	pub fn spans(&self, axis: Axis) -> Option<MeshSpans<'a, T>> {
+		self.spans_with(axis, Triangle2::rasterize_along)
+	}
+
+	/// Returns an iterator over the spans of all the triangles of the mesh in the given axis
+	/// following the top-left fill rule, see [TopLeftZip]. This way the rasterization is
+	/// watertight: the pixels of the edges shared by two triangles are only yielded once and
+	/// there are no gaps between them.
+	///
+	/// ```
+	/// # use bresenham_zip::{Axis, mesh::Mesh};
+	/// let vertices = [(0, 0), (10, 0), (10, 10), (0, 10)];
+	/// let indices = [[0, 1, 2], [0, 2, 3]];
+	/// let spans = Mesh::new(&vertices, &indices).watertight_spans(Axis::Y).unwrap();
+	/// assert_eq!(spans.map(|span| span.range.count()).sum::<usize>(), 10 * 10);
+	/// ```
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	/// # Panics
+	/// If any of the indices is out of the bounds of the vertices.
+	pub fn watertight_spans(&self, axis: Axis) -> Option<MeshSpans<'a, T, TopLeftZip<T>>> {
+		self.spans_with(axis, Triangle2::rasterize_top_left)
+	}
+
+	/// Returns an iterator over the spans of all the triangles of the mesh in the given axis,
+	/// like [Mesh::spans], but yielding the pixels of the edges shared by two triangles only
+	/// once, so every pixel is counted once when stamping counts or ids into a grid. See
+	/// [UniqueSpans]. Requires the `alloc` feature.
+	///
+	/// ```
+	/// # use bresenham_zip::{Axis, mesh::Mesh};
+	/// let vertices = [(0, 0), (10, 0), (10, 10), (0, 10)];
+	/// let indices = [[0, 1, 2], [0, 2, 3]];
+	/// let spans = Mesh::new(&vertices, &indices).unique_spans(Axis::Y).unwrap();
+	/// assert_eq!(spans.map(|span| span.range.count()).sum::<usize>(), 11 * 11);
+	/// ```
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	/// # Panics
+	/// If any of the indices is out of the bounds of the vertices.
+	#[cfg(feature = "alloc")]
+	pub fn unique_spans(&self, axis: Axis) -> Option<UniqueSpans<'a, T>> {
+		if axis == Axis::Z {
+			return None;
+		}
+		let mut edges = alloc::collections::BTreeMap::new();
+		for (index, triangle) in self.triangles().enumerate() {
+			for edge in sorted_edges(&triangle) {
+				edges.entry(edge).or_insert(index);
+			}
+		}
+		let neighbours = [None, None, None];
+		Some(UniqueSpans { mesh: *self, axis, next: 0, current: None, neighbours, edges })
+	}
+
+	fn spans_with<Z>(&self, axis: Axis, rasterize: Rasterizer<T, Z>)
+		-> Option<MeshSpans<'a, T, Z>> {
+		if axis == Axis::Z {
+			return None;
+		}
+		Some(MeshSpans {
+			vertices: self.vertices,
+			indices: self.indices.iter(),
+			axis,
+			rasterize,
+			current: None,
+		})
+	}
+
+}
+
+/// Returns the three edges of the triangle with their vertices sorted, so an edge is the same
+/// whatever the direction it's walked by each triangle sharing it
+#[cfg(feature = "alloc")]
+fn sorted_edges<T: SignedNum>(triangle: &Triangle2<T>) -> [(Point2<T>, Point2<T>); 3] {
+	let [a, b, c] = triangle.vertices();
+	[(a, b), (b, c), (c, a)].map(|(from, to)| (from.min(to), from.max(to)))
+}
+
+/// Function rasterizing a triangle into a zip
+type Rasterizer<T, Z> = fn(&Triangle2<T>, Axis) -> Option<Z>;
+
+/// Iterator over the spans of every triangle of a [Mesh], rasterized with the zip `Z`
+#[derive(Clone)]
+pub struct MeshSpans<'a, T, Z = TriangleZip<T>> {
+	vertices: &'a [Point2<T>],
+	indices: Iter<'a, [usize; 3]>,
+	axis: Axis,
+	rasterize: Rasterizer<T, Z>,
+	current: Option<SpanIterator<Z>>,
+}
+
+impl<'a, T, Z> MeshSpans<'a, T, Z>
+	where T: SignedNum, Z: Iterator<Item = (Point2<T>, Point2<T>)> {
+
+	/// Collects the spans of every triangle and groups them by their axis value, yielding the
+	/// scanlines from the lowest to the highest value with their spans sorted, see [Scanlines].
+	/// Requires the `alloc` feature.
+	#[cfg(feature = "alloc")]
+	pub fn scanlines(self) -> Scanlines<alloc::vec::IntoIter<Span<T>>, T> {
+		let mut spans: alloc::vec::Vec<_> = self.collect();
+		spans.sort_by_key(|span| (span.axis_value, *span.range.start()));
+		Scanlines::new(spans.into_iter())
+	}
+
+}
+
+impl<'a, T, Z> Iterator for MeshSpans<'a, T, Z>
+	where T: SignedNum, Z: Iterator<Item = (Point2<T>, Point2<T>)> {
+	type Item = Span<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(span) = self.current.as_mut().and_then(|spans| spans.next()) {
+				return Some(span);
+			}
+			let [a, b, c] = *self.indices.next()?;
+			let triangle = Triangle2::new(self.vertices[a], self.vertices[b], self.vertices[c]);
+			let axis = match self.axis {
+				Axis::X => 0,
+				_ => 1,
+			};
+			let zip = (self.rasterize)(&triangle, self.axis);
+			self.current = zip.map(|zip| SpanIterator::new(zip, axis));
+		}
+	}
+}
+
+/// Iterator over the spans of every triangle of a [Mesh] yielding each pixel of the edges shared
+/// by two triangles only once, obtained with [Mesh::unique_spans]. The triangles are rasterized
+/// like [Triangle2::rasterize_along], so both of them cover the pixels of the edge; each span
+/// is trimmed to skip the pixels already covered by the previous triangles sharing an edge with
+/// its triangle. Those triangles are found in a map from each edge to the first triangle with
+/// it, built when the iterator is created. Requires the `alloc` feature.
+///
+/// The triangles of the mesh must not overlap. The pixels of vertices shared only by triangles
+/// that don't share any edge, like two triangles touching at a corner, are still yielded twice.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct UniqueSpans<'a, T> {
+	mesh: Mesh<'a, T>,
+	axis: Axis,
+	next: usize,
+	current: Option<SpanIterator<TriangleZip<T>>>,
+	/// Zips of the previous triangles sharing each edge of the current one
+	neighbours: [Option<TriangleZip<T>>; 3],
+	/// Index of the first triangle with each edge, with the vertices of the edge sorted
+	edges: alloc::collections::BTreeMap<(Point2<T>, Point2<T>), usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: SignedNum> Iterator for UniqueSpans<'a, T> {
+	type Item = Span<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(span) = self.current.as_mut().and_then(|spans| spans.next()) {
+				if let Some(span) = self.unique(span) {
+					return Some(span);
+				}
+				continue;
+			}
+			let triangle = self.mesh.triangle(self.next)?;
+			for (neighbour, edge) in self.neighbours.iter_mut().zip(sorted_edges(&triangle)) {
+				*neighbour = self.edges.get(&edge)
+					.filter(|index| **index < self.next)
+					.and_then(|index| self.mesh.triangle(*index)?.rasterize_along(self.axis));
+			}
+			self.next += 1;
+			self.current = triangle.rasterize_along(self.axis).map(TriangleZip::spans);
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: SignedNum> UniqueSpans<'a, T> {
+
+	/// Trims the span to the pixels not covered by the neighbours, or returns `None` if all of
+	/// them are covered
+	fn unique(&self, span: Span<T>) -> Option<Span<T>> {
+		let other = match self.axis {
+			Axis::X => 1,
+			_ => 0,
+		};
+		let (mut start, mut end) = span.range.into_inner();
+		for neighbour in self.neighbours.iter().flatten() {
+			let Some((a, b)) = neighbour.span_at(span.axis_value) else {
+				continue;
+			};
+			let (low, high) = (a.nth(other).min(b.nth(other)), a.nth(other).max(b.nth(other)));
+			if high < start || low > end {
+				continue;
+			}
+			// the neighbour is at one side of the span, so it only covers one of its ends
+			if low <= start {
+				if high >= end {
+					return None;
+				}
+				start = high + T::one();
+			} else if high >= end {
+				end = low - T::one();
+			}
+		}
+		Some(Span { axis_value: span.axis_value, range: start..=end })
+	}
+
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: SignedNum> Debug for UniqueSpans<'a, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "UniqueSpans [ {:?} ]. Next triangle: {}", self.current, self.next)
+	}
+}
+
+impl<'a, T: SignedNum, Z: Debug> Debug for MeshSpans<'a, T, Z> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "MeshSpans [ {:?} ]. Remaining triangles: {}", self.current, self.indices.len())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Axis;
+	use super::Mesh;
+
+	const VERTICES: [(i32, i32); 5] = [(0, 0), (10, 0), (10, 10), (0, 10), (20, 5)];
+	const INDICES: [[usize; 3]; 3] = [[0, 1, 2], [0, 2, 3], [1, 4, 2]];
+
+	#[test]
+	fn triangles() {
+		let mesh = Mesh::new(&VERTICES, &INDICES);
+		let mut triangles = mesh.triangles();
+		assert_eq!(triangles.next().unwrap().vertices(), [(0, 0), (10, 0), (10, 10)]);
+		assert_eq!(triangles.next().unwrap().vertices(), [(0, 0), (10, 10), (0, 10)]);
+		assert_eq!(triangles.next().unwrap().vertices(), [(10, 0), (20, 5), (10, 10)]);
+		assert!(triangles.next().is_none());
+	}
+
+	#[test]
+	fn spans() {
+		let mesh = Mesh::new(&VERTICES, &INDICES);
+		assert_eq!(mesh.spans(Axis::Y).unwrap().count(), 11 * 3);
+		assert_eq!(mesh.spans(Axis::X).unwrap().count(), 11 * 2 + 11);
+		for span in mesh.spans(Axis::Y).unwrap() {
+			assert!((0..=10).contains(&span.axis_value));
+			assert!(*span.range.start() >= 0 && *span.range.end() <= 20);
+		}
+	}
+
+	#[test]
+	fn watertight() {
+		// square split in four triangles around its center, and split again in the middle
+		let vertices = [(0, 0), (16, 0), (16, 16), (0, 16), (7, 9), (16, 5)];
+		let indices = [[0, 1, 4], [1, 5, 4], [5, 2, 4], [2, 3, 4], [3, 0, 4]];
+		for axis in [Axis::X, Axis::Y] {
+			let mut covered = [[0; 17]; 17];
+			for span in Mesh::new(&vertices, &indices).watertight_spans(axis).unwrap() {
+				for value in span.range {
+					let (x, y) = if axis == Axis::X {
+						(span.axis_value, value)
+					} else {
+						(value, span.axis_value)
+					};
+					covered[y as usize][x as usize] += 1;
+				}
+			}
+			for (y, row) in covered.iter().enumerate() {
+				for (x, count) in row.iter().enumerate() {
+					assert_eq!(*count, (x < 16 && y < 16) as i32, "({}, {}) in {:?}", x, y, axis);
+				}
+			}
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn unique() {
+		// square split in four triangles around its center, and split again in the middle
+		let vertices = [(0, 0), (16, 0), (16, 16), (0, 16), (7, 9), (16, 5)];
+		let indices = [[0, 1, 4], [1, 5, 4], [5, 2, 4], [2, 3, 4], [3, 0, 4]];
+		for axis in [Axis::X, Axis::Y] {
+			let mut covered = [[0; 17]; 17];
+			for span in Mesh::new(&vertices, &indices).unique_spans(axis).unwrap() {
+				for value in span.range {
+					let (x, y) = if axis == Axis::X {
+						(span.axis_value, value)
+					} else {
+						(value, span.axis_value)
+					};
+					covered[y as usize][x as usize] += 1;
+				}
+			}
+			assert!(covered.iter().flatten().all(|count| *count == 1), "{:?}", axis);
+		}
+	}
+
+	#[test]
+	fn invalid_axis() {
+		assert!(Mesh::new(&VERTICES, &INDICES).spans(Axis::Z).is_none());
+	}
+
+	#[test]
+	#[should_panic]
+	fn invalid_index() {
+		let indices = [[0, 1, 5]];
+		Mesh::new(&VERTICES, &indices).spans(Axis::Y).unwrap().count();
+	}
+
+}
@@ -0,0 +1,140 @@
+//! Package with the parallel iteration of the two-dimensional triangles. Requires the `rayon`
+//! feature.
+
+use core::fmt::{Debug, Formatter};
+use core::ops::Range;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use crate::triangle::TriangleZip;
+use crate::{Point2, SignedNum};
+
+/// Parallel iterator over the pairs of points of a [TriangleZip], in the same order. The pairs
+/// left are split into chunks of consecutive axis values, and each pair is computed on its own
+/// with [span_at](crate::zip::BresenhamZip::span_at), so the threads don't need to walk the
+/// lines up to their chunk.
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// use rayon::prelude::*;
+///
+/// let zip = Triangle2::new((0, 0), (1000, 400), (300, 1000)).rasterize_along(Axis::Y).unwrap();
+/// let pairs: Vec<_> = zip.clone().into_par_iter().collect();
+/// assert!(pairs.into_iter().eq(zip));
+/// ```
+pub struct ParTriangleZip<T> {
+	zip: TriangleZip<T>,
+}
+
+impl<T: SignedNum + Send + Sync> IntoParallelIterator for TriangleZip<T> {
+	type Iter = ParTriangleZip<T>;
+	type Item = (Point2<T>, Point2<T>);
+
+	fn into_par_iter(self) -> Self::Iter {
+		ParTriangleZip { zip: self }
+	}
+}
+
+impl<T: SignedNum + Send + Sync> ParallelIterator for ParTriangleZip<T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.zip.len())
+	}
+}
+
+impl<T: SignedNum + Send + Sync> IndexedParallelIterator for ParTriangleZip<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.zip.len()
+	}
+
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		let pairs = 0..self.zip.len();
+		callback.callback(Chunk { zip: &self.zip, pairs })
+	}
+}
+
+impl<T: SignedNum> Debug for ParTriangleZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "ParTriangleZip [ {:?} ]", self.zip)
+	}
+}
+
+/// Consecutive pairs of a [TriangleZip], given by their position in the zip
+#[derive(Clone)]
+struct Chunk<'a, T> {
+	zip: &'a TriangleZip<T>,
+	pairs: Range<usize>,
+}
+
+impl<'a, T: SignedNum + Send + Sync> Producer for Chunk<'a, T> {
+	type Item = (Point2<T>, Point2<T>);
+	type IntoIter = Self;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self
+	}
+
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let middle = self.pairs.start + index;
+		(
+			Chunk { zip: self.zip, pairs: self.pairs.start..middle },
+			Chunk { zip: self.zip, pairs: middle..self.pairs.end },
+		)
+	}
+}
+
+impl<T: SignedNum> Iterator for Chunk<'_, T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.zip.pair_at(self.pairs.next()?)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.pairs.size_hint()
+	}
+}
+
+impl<T: SignedNum> DoubleEndedIterator for Chunk<'_, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.zip.pair_at(self.pairs.next_back()?)
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for Chunk<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+	extern crate std;
+
+	use std::vec::Vec;
+	use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+	use crate::triangle::Triangle2;
+	use crate::Axis;
+
+	#[test]
+	fn same_pairs() {
+		let triangle = Triangle2::new((-40, 13), (71, -20), (25, 90));
+		for axis in [Axis::X, Axis::Y] {
+			let mut zip = triangle.rasterize_along(axis).unwrap();
+			for _ in 0..3 {
+				let pairs = zip.clone().into_par_iter();
+				assert_eq!(pairs.len(), zip.len());
+				assert_eq!(pairs.collect::<Vec<_>>(), zip.clone().collect::<Vec<_>>());
+				zip.nth(40);
+			}
+		}
+	}
+
+}
@@ -0,0 +1,396 @@
+//! Package with the active edge table rasterizer of arbitrary two-dimensional polygons.
+//! Requires the `alloc` feature.
+
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use crate::boolean::Boolean;
+use crate::scanline::Scanlines;
+use crate::span::Span;
+use crate::util::{floor_div, narrow, wide, Point};
+use crate::{Axis, Point2, SignedNum};
+
+/// Rule to decide which parts of a polygon are inside of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+	/// A point is inside if a line from it to the infinity crosses an odd number of edges
+	EvenOdd,
+	/// A point is inside if the edges don't wind around it the same times in both directions
+	NonZero,
+}
+
+/// Polygon defined by a closed list of vertices. It can be concave and self-intersecting.
+///
+/// ```
+/// # use bresenham_zip::{Axis, polygon::{FillRule, Polygon}};
+/// let star = [(5, 0), (8, 10), (0, 4), (10, 4), (2, 10)];
+/// for span in Polygon::new(&star).spans(Axis::Y, FillRule::NonZero).unwrap() {
+///   assert!((0..10).contains(&span.axis_value));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Polygon<'a, T> {
+	vertices: &'a [Point2<T>],
+}
+
+impl<'a, T: SignedNum> Polygon<'a, T> {
+
+	/// Creates a new polygon with the given vertices. The last vertex is joined with the first
+	/// one.
+	pub fn new(vertices: &'a [Point2<T>]) -> Self {
+		Self { vertices }
+	}
+
+	/// Returns an iterator over the spans inside the polygon in the given axis. A single axis
+	/// value can have multiple spans, they are yielded from lowest to highest.
+	///
+	/// Edges are sampled from their lowest axis value, included, to the highest one, excluded.
+	/// This way vertices shared by two edges are only crossed once.
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	/// * `rule` - [FillRule] to decide the parts inside of the polygon
+	///
+	pub fn spans(&self, axis: Axis, rule: FillRule) -> Option<PolygonSpans<T>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => return None,
+		};
+		let count = self.vertices.len();
+		let mut edges: Vec<Edge<T>> = (0..count)
+			.filter_map(|i| Edge::new(self.vertices[i], self.vertices[(i + 1) % count], axis))
+			.collect();
+		edges.sort_by_key(|edge| edge.lower.nth(axis));
+
+		let current = edges.first().map(|edge| edge.lower.nth(axis)).unwrap_or_else(T::zero);
+		Some(PolygonSpans {
+			edges,
+			next_edge: 0,
+			active: Vec::new(),
+			crossings: Vec::new(),
+			pending: Vec::new(),
+			current,
+			rule,
+			axis,
+		})
+	}
+
+	/// Splits the polygon into triangles clipping its ears, returning the indices of the vertices
+	/// of each triangle. They can be rasterized with a [Mesh](crate::mesh::Mesh) over the same
+	/// vertices, yielding the pixels of the polygon without computing its crossings.
+	///
+	/// The polygon must be simple: its edges can't cross each other. Collinear vertices are
+	/// dropped without generating any triangle, and the triangulation stops if no ear is left,
+	/// which can only happen with polygons that aren't simple.
+	///
+	/// ```
+	/// # use bresenham_zip::{Axis, mesh::Mesh, polygon::Polygon};
+	/// let shape = [(0, 0), (10, 0), (10, 10), (5, 5), (0, 10)];
+	/// let triangles = Polygon::new(&shape).triangulate();
+	/// assert_eq!(triangles.len(), 3);
+	/// for span in Mesh::new(&shape, &triangles).watertight_spans(Axis::Y).unwrap() {
+	///   assert!((0..10).contains(&span.axis_value));
+	/// }
+	/// ```
+	pub fn triangulate(&self) -> Vec<[usize; 3]> {
+		let vertices = self.vertices;
+		let mut triangles = Vec::with_capacity(vertices.len().saturating_sub(2));
+		let mut remaining: Vec<usize> = (0..vertices.len()).collect();
+		// the ears turn to the same side as the whole polygon
+		let area: i128 = (0..vertices.len())
+			.map(|i| {
+				let (a, b) = (vertices[i], vertices[(i + 1) % vertices.len()]);
+				wide(a.0) * wide(b.1) - wide(b.0) * wide(a.1)
+			})
+			.sum();
+		let orientation = area.signum();
+		while remaining.len() > 3 {
+			let count = remaining.len();
+			let corner = |i: usize| {
+				let [a, b, c] = [(i + count - 1) % count, i, (i + 1) % count].map(|j| remaining[j]);
+				(turn(vertices[a], vertices[b], vertices[c]), [a, b, c])
+			};
+			let ear = (0..count).find(|i| {
+				let (side, [a, b, c]) = corner(*i);
+				side == orientation && !remaining.iter().any(|j| {
+					let point = vertices[*j];
+					![a, b, c].iter().any(|k| vertices[*k] == point)
+						&& inside(point, [vertices[a], vertices[b], vertices[c]], orientation)
+				})
+			});
+			match ear.or_else(|| (0..count).find(|i| corner(*i).0 == 0)) {
+				Some(i) => {
+					let (side, triangle) = corner(i);
+					if side != 0 {
+						triangles.push(triangle);
+					}
+					remaining.remove(i);
+				}
+				None => return triangles,
+			}
+		}
+		if let [a, b, c] = remaining[..] {
+			if turn(vertices[a], vertices[b], vertices[c]) != 0 {
+				triangles.push([a, b, c]);
+			}
+		}
+		triangles
+	}
+
+}
+
+/// Returns the sign of the turn from `a -> b` to `b -> c`: positive if counterclockwise in a
+/// system with the Y axis up, negative if clockwise and zero if the points are collinear
+fn turn<T: SignedNum>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> i128 {
+	let ab = (wide(b.0) - wide(a.0), wide(b.1) - wide(a.1));
+	let bc = (wide(c.0) - wide(b.0), wide(c.1) - wide(b.1));
+	(ab.0 * bc.1 - ab.1 * bc.0).signum()
+}
+
+/// Checks if the point is inside the triangle or on its edges, given the turn of its vertices
+fn inside<T: SignedNum>(point: Point2<T>, [a, b, c]: [Point2<T>; 3], orientation: i128) -> bool {
+	[(a, b), (b, c), (c, a)].iter().all(|(from, to)| turn(*from, *to, point) != -orientation)
+}
+
+/// Non-parallel edge of the polygon, sorted by its value in the axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Edge<T> {
+	lower: Point2<T>,
+	upper: Point2<T>,
+	winding: i8,
+}
+
+impl<T: SignedNum> Edge<T> {
+
+	fn new(start: Point2<T>, end: Point2<T>, axis: u8) -> Option<Self> {
+		match start.nth(axis).cmp(&end.nth(axis)) {
+			core::cmp::Ordering::Less => Some(Self { lower: start, upper: end, winding: 1 }),
+			core::cmp::Ordering::Greater => Some(Self { lower: end, upper: start, winding: -1 }),
+			core::cmp::Ordering::Equal => None,
+		}
+	}
+
+	/// Value in the other axis of the edge point with the given axis value, rounded down
+	fn crossing(&self, value: T, axis: u8) -> T {
+		let other = 1 - axis;
+		let (lower, upper) = (self.lower, self.upper);
+		let delta = wide(upper.nth(other)) - wide(lower.nth(other));
+		let travelled = wide(value) - wide(lower.nth(axis));
+		let length = wide(upper.nth(axis)) - wide(lower.nth(axis));
+		narrow(wide(lower.nth(other)) + floor_div(delta * travelled, length))
+	}
+
+}
+
+/// Iterator over the spans of a [Polygon] using an active edge table
+#[derive(Clone, PartialEq)]
+pub struct PolygonSpans<T> {
+	edges: Vec<Edge<T>>,
+	next_edge: usize,
+	active: Vec<Edge<T>>,
+	crossings: Vec<(T, i8)>,
+	pending: Vec<Span<T>>,
+	current: T,
+	rule: FillRule,
+	axis: u8,
+}
+
+impl<T: SignedNum> PolygonSpans<T> {
+
+	/// Turns the iterator into one yielding all the spans of each axis value at once, see
+	/// [Scanlines]
+	pub fn scanlines(self) -> Scanlines<Self, T> {
+		Scanlines::new(self)
+	}
+
+	/// Returns the iterator over the spans covered by any of the polygons, see [Boolean]
+	///
+	/// ```
+	/// # use bresenham_zip::{Axis, polygon::{FillRule, Polygon}};
+	/// let (a, b) = ([(0, 0), (9, 0), (9, 9), (0, 9)], [(5, 0), (14, 0), (14, 9), (5, 9)]);
+	/// let spans = |shape| Polygon::new(shape).spans(Axis::Y, FillRule::EvenOdd).unwrap();
+	/// let mut union = spans(&a).union(spans(&b));
+	/// assert!(union.all(|span| span.range == (0..=14)));
+	/// ```
+	///
+	/// * `other` - Iterator over the spans to add, sorted by their axis value and start
+	///
+	pub fn union<I: Iterator<Item = Span<T>>>(self, other: I) -> Boolean<Self, I, T> {
+		Boolean::union(self, other)
+	}
+
+	/// Returns the iterator over the spans covered by both polygons, see [Boolean]
+	///
+	/// * `other` - Iterator over the spans to intersect, sorted by their axis value and start
+	///
+	pub fn intersection<I: Iterator<Item = Span<T>>>(self, other: I) -> Boolean<Self, I, T> {
+		Boolean::intersection(self, other)
+	}
+
+	/// Returns the iterator over the spans of the polygon not covered by the other one, see
+	/// [Boolean]
+	///
+	/// * `other` - Iterator over the spans to subtract, sorted by their axis value and start
+	///
+	pub fn difference<I: Iterator<Item = Span<T>>>(self, other: I) -> Boolean<Self, I, T> {
+		Boolean::difference(self, other)
+	}
+
+	/// Updates the active edges and fills the pending spans with the ones of the current value
+	fn scan(&mut self) {
+		let (axis, value) = (self.axis, self.current);
+		while let Some(edge) = self.edges.get(self.next_edge) {
+			if edge.lower.nth(axis) > value {
+				break;
+			}
+			self.active.push(*edge);
+			self.next_edge += 1;
+		}
+		self.active.retain(|edge| edge.upper.nth(axis) > value);
+
+		self.crossings.clear();
+		self.crossings.extend(self.active.iter().map(|e| (e.crossing(value, axis), e.winding)));
+		self.crossings.sort_by_key(|(crossing, _)| *crossing);
+
+		let mut winding = 0;
+		let mut start = T::zero();
+		for (crossing, direction) in self.crossings.iter() {
+			let inside = match self.rule {
+				FillRule::EvenOdd => winding % 2 != 0,
+				FillRule::NonZero => winding != 0,
+			};
+			winding += *direction as i32;
+			let now_inside = match self.rule {
+				FillRule::EvenOdd => winding % 2 != 0,
+				FillRule::NonZero => winding != 0,
+			};
+			if !inside && now_inside {
+				start = *crossing;
+			} else if inside && !now_inside {
+				self.pending.push(Span { axis_value: value, range: start..=*crossing });
+			}
+		}
+		self.pending.reverse();
+	}
+
+}
+
+impl<T: SignedNum> Iterator for PolygonSpans<T> {
+	type Item = Span<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(span) = self.pending.pop() {
+				return Some(span);
+			}
+			if self.active.is_empty() && self.next_edge >= self.edges.len() {
+				return None;
+			}
+			self.scan();
+			self.current += T::one();
+		}
+	}
+}
+
+impl<T: SignedNum> Debug for PolygonSpans<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "PolygonSpans [ {:?} ]. Active edges: {}", self.current, self.active.len())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use crate::Axis;
+	use crate::span::Span;
+	use super::{FillRule, Polygon};
+
+	const STAR: [(i32, i32); 5] = [(50, 0), (80, 100), (0, 40), (100, 40), (20, 100)];
+
+	#[test]
+	fn square() {
+		let square = [(0, 0), (10, 0), (10, 10), (0, 10)];
+		let spans = Polygon::new(&square).spans(Axis::Y, FillRule::EvenOdd).unwrap();
+		let spans: Vec<_> = spans.collect();
+		assert_eq!(spans.len(), 10);
+		for (y, span) in spans.into_iter().enumerate() {
+			assert_eq!(span, Span { axis_value: y as i32, range: 0..=10 });
+		}
+	}
+
+	#[test]
+	fn concave() {
+		let shape = [(0, 0), (10, 0), (10, 10), (5, 5), (0, 10)];
+		let spans = Polygon::new(&shape).spans(Axis::Y, FillRule::EvenOdd).unwrap();
+		let spans: Vec<_> = spans.collect();
+		assert_eq!(spans[0], Span { axis_value: 0, range: 0..=10 });
+		assert_eq!(spans[5], Span { axis_value: 5, range: 0..=5 });
+		assert_eq!(spans[6], Span { axis_value: 5, range: 5..=10 });
+		assert_eq!(spans[7], Span { axis_value: 6, range: 0..=4 });
+		assert_eq!(spans[8], Span { axis_value: 6, range: 6..=10 });
+		assert_eq!(spans.len(), 5 + 5 * 2);
+	}
+
+	#[test]
+	fn even_odd() {
+		let spans = Polygon::new(&STAR).spans(Axis::Y, FillRule::EvenOdd).unwrap();
+		let center: Vec<_> = spans.filter(|span| span.axis_value == 60).collect();
+		assert_eq!(center.len(), 2);
+		assert!(center[0].range.end() < center[1].range.start());
+	}
+
+	#[test]
+	fn non_zero() {
+		let spans = Polygon::new(&STAR).spans(Axis::Y, FillRule::NonZero).unwrap();
+		let center: Vec<_> = spans.filter(|span| span.axis_value == 60).collect();
+		assert_eq!(center.len(), 1);
+	}
+
+	#[test]
+	fn x_axis() {
+		let triangle = [(0, 0), (10, 5), (0, 10)];
+		let spans = Polygon::new(&triangle).spans(Axis::X, FillRule::EvenOdd).unwrap();
+		let last = spans.last().unwrap();
+		assert_eq!(last.axis_value, 9);
+		// both edges cross at half a pixel, 4.5 and 5.5
+		assert_eq!(last.range, 4..=5);
+	}
+
+	#[test]
+	fn large() {
+		let triangle = [(0, 0), (100000, 0), (0, 100000)];
+		let mut spans = Polygon::new(&triangle).spans(Axis::Y, FillRule::EvenOdd).unwrap();
+		assert_eq!(spans.nth(50000), Some(Span { axis_value: 50000, range: 0..=50000 }));
+		assert_eq!(spans.count(), 49999);
+		// the crossings are rounded down with any slope
+		let triangle = [(0, 0), (10, 0), (0, 3)];
+		let mut spans = Polygon::new(&triangle).spans(Axis::Y, FillRule::EvenOdd).unwrap();
+		assert_eq!(spans.nth(1), Some(Span { axis_value: 1, range: 0..=6 }));
+	}
+
+	#[test]
+	fn empty() {
+		let line: [(i32, i32); 2] = [(0, 0), (10, 0)];
+		assert_eq!(Polygon::new(&line).spans(Axis::Y, FillRule::NonZero).unwrap().count(), 0);
+		assert!(Polygon::new(&line).spans(Axis::Z, FillRule::NonZero).is_none());
+	}
+
+	#[test]
+	fn triangulate() {
+		let doubled_area = |points: &[(i32, i32)]| (0..points.len()).map(|i| {
+			let (a, b) = (points[i], points[(i + 1) % points.len()]);
+			a.0 * b.1 - b.0 * a.1
+		}).sum::<i32>().abs();
+		// comb with three teeth, clockwise, and with a collinear vertex at its base
+		let mut comb = [(0, 0), (0, 30), (10, 10), (20, 30), (30, 10), (40, 30), (40, 0), (20, 0)];
+		for _ in 0..2 {
+			let triangles = Polygon::new(&comb).triangulate();
+			assert!(triangles.len() <= comb.len() - 2);
+			let area = triangles.iter().map(|indices| doubled_area(&indices.map(|i| comb[i])));
+			assert_eq!(area.sum::<i32>(), doubled_area(&comb));
+			comb.reverse();
+		}
+		assert!(Polygon::new(&STAR[..2]).triangulate().is_empty());
+		assert!(Polygon::new(&[(0, 0), (5, 5), (10, 10)]).triangulate().is_empty());
+	}
+
+}
@@ -0,0 +1,41 @@
+/// Builds a zip with the schema of [build_zip!](crate::build_zip) and calls the fill closure with
+/// every pair of points, so the usual loop filling the triangle fits in a single call. It returns
+/// the result of the building, with the unit when the zip was rasterized.
+///
+/// `<dimension>:<axis> - <starting point> -> <first ending point>, <second ending point> => <fill>`
+///
+/// # Example
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let mut pixels = 0;
+/// bresenham_zip::rasterize!(2D:Y - (50, 50) -> (0, 100), (100, 100) => |left, right| {
+///   assert_eq!(left.1, right.1);
+///   pixels += right.0 - left.0 + 1;
+/// })?;
+/// assert_eq!(pixels, 51 * 51);
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! rasterize {
+	// the zip can't be collected as repeated tokens followed by `=>`, so its tokens are collected
+	// one by one until the arrow
+	(@zip [$($zip:tt)+] => $fill:expr) => {
+		$crate::build_zip!($($zip)+).map(|zip| {
+			// the closure is passed with its signature known, so its arguments are typed
+			fn fill_pairs<P, F: FnMut(P, P)>(zip: impl Iterator<Item = (P, P)>, mut fill: F) {
+				for (a, b) in zip {
+					fill(a, b);
+				}
+			}
+			fill_pairs(zip, $fill)
+		})
+	};
+	(@zip [$($zip:tt)*] $next:tt $($rest:tt)*) => {
+		$crate::rasterize!(@zip [$($zip)* $next] $($rest)*)
+	};
+	($($input:tt)+) => {
+		$crate::rasterize!(@zip [] $($input)+)
+	};
+}
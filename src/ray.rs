@@ -0,0 +1,215 @@
+//! Package with the traversal of the cells of an integer grid crossed by rays with fractional
+//! coordinates, for raycasting against the grids filled with the zips
+
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use crate::util::{floor, float, narrow};
+use crate::{FloatNum, Point2, Point3, SignedNum};
+
+/// Iterator over every cell of a two-dimensional grid crossed by the ray between two points,
+/// following the algorithm of Amanatides and Woo. The cell of each point is the one with the
+/// integer part of its coordinates, so the cell `(x, y)` spans from `x` to `x + 1` and from `y` to
+/// `y + 1`. The ray moves to the neighbour cell sharing the side it crosses first, so consecutive
+/// cells always share a side, and when it goes through a corner it crosses first the axis with the
+/// lowest index.
+///
+/// ```
+/// # use bresenham_zip::ray::GridRay;
+/// let mut ray = GridRay::<_, i32>::new((0.5, 0.5), (2.5, 1.5));
+/// assert_eq!(ray.len(), 4);
+/// assert_eq!(ray.next(), Some((0, 0)));
+/// assert_eq!(ray.next(), Some((1, 0)));
+/// assert_eq!(ray.next(), Some((1, 1)));
+/// assert_eq!(ray.next(), Some((2, 1)));
+/// assert_eq!(ray.next(), None);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct GridRay<F, T> {
+	traversal: Traversal<F, 2>,
+	cell: PhantomData<T>,
+}
+
+impl<F: FloatNum, T: SignedNum> GridRay<F, T> {
+
+	/// Creates the ray going from `start` to `end`. To cast a ray from an origin along a
+	/// direction, use the point at the maximum distance wanted as `end`.
+	///
+	/// * `start` - Origin of the ray, in the first cell yielded
+	/// * `end` - End of the ray, in the last cell yielded
+	///
+	pub fn new(start: Point2<F>, end: Point2<F>) -> Self {
+		Self { traversal: Traversal::new([start.0, start.1], [end.0, end.1]), cell: PhantomData }
+	}
+
+}
+
+impl<F: FloatNum, T: SignedNum> Iterator for GridRay<F, T> {
+	type Item = Point2<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.traversal.next().map(|[x, y]| (narrow(x), narrow(y)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.traversal.remaining, Some(self.traversal.remaining))
+	}
+}
+
+impl<F: FloatNum, T: SignedNum> ExactSizeIterator for GridRay<F, T> {}
+
+impl<F: FloatNum, T: SignedNum> Debug for GridRay<F, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "GridRay [ {:?} ]. Remaining: {}", self.traversal.cell, self.len())
+	}
+}
+
+/// Iterator over every voxel of a three-dimensional grid crossed by the ray between two points,
+/// following the algorithm of Amanatides and Woo, like the [GridRay] of the two-dimensional
+/// grids. Consecutive voxels always share a face.
+///
+/// ```
+/// # use bresenham_zip::ray::GridRay3d;
+/// let mut ray = GridRay3d::<_, i32>::new((0.5, 0.5, 0.5), (-1.5, 0.5, 3.5));
+/// assert_eq!(ray.len(), 6);
+/// assert_eq!(ray.next(), Some((0, 0, 0)));
+/// assert_eq!(ray.last(), Some((-2, 0, 3)));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct GridRay3d<F, T> {
+	traversal: Traversal<F, 3>,
+	cell: PhantomData<T>,
+}
+
+impl<F: FloatNum, T: SignedNum> GridRay3d<F, T> {
+
+	/// Creates the ray going from `start` to `end`. To cast a ray from an origin along a
+	/// direction, use the point at the maximum distance wanted as `end`.
+	///
+	/// * `start` - Origin of the ray, in the first voxel yielded
+	/// * `end` - End of the ray, in the last voxel yielded
+	///
+	pub fn new(start: Point3<F>, end: Point3<F>) -> Self {
+		let traversal = Traversal::new([start.0, start.1, start.2], [end.0, end.1, end.2]);
+		Self { traversal, cell: PhantomData }
+	}
+
+}
+
+impl<F: FloatNum, T: SignedNum> Iterator for GridRay3d<F, T> {
+	type Item = Point3<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.traversal.next().map(|[x, y, z]| (narrow(x), narrow(y), narrow(z)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.traversal.remaining, Some(self.traversal.remaining))
+	}
+}
+
+impl<F: FloatNum, T: SignedNum> ExactSizeIterator for GridRay3d<F, T> {}
+
+impl<F: FloatNum, T: SignedNum> Debug for GridRay3d<F, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "GridRay3d [ {:?} ]. Remaining: {}", self.traversal.cell, self.len())
+	}
+}
+
+/// Traversal of the grid shared by the rays of any dimension
+#[derive(Clone, PartialEq)]
+struct Traversal<F, const N: usize> {
+	cell: [i128; N],
+	last: [i128; N],
+	/// Value of the ray parameter, from 0 at the start to 1 at the end, where the next boundary of
+	/// each axis is crossed
+	next_boundary: [F; N],
+	/// Increase of the ray parameter between two boundaries of each axis
+	delta: [F; N],
+	remaining: usize,
+}
+
+impl<F: FloatNum, const N: usize> Traversal<F, N> {
+
+	fn new(start: [F; N], end: [F; N]) -> Self {
+		let cell = start.map(floor);
+		let last = end.map(floor);
+		let (mut next_boundary, mut delta) = ([F::ZERO; N], [F::ZERO; N]);
+		for i in 0..N {
+			// the axes without movement are never crossed, so they keep the zeros
+			let length = end[i] - start[i];
+			if length > F::ZERO {
+				next_boundary[i] = (float::<F>(cell[i] + 1) - start[i]) / length;
+				delta[i] = F::ONE / length;
+			} else if length < F::ZERO {
+				next_boundary[i] = (start[i] - float::<F>(cell[i])) / -length;
+				delta[i] = F::ONE / -length;
+			}
+		}
+		let steps: u128 = (0..N).map(|i| (last[i] - cell[i]).unsigned_abs()).sum();
+		Self { cell, last, next_boundary, delta, remaining: steps as usize + 1 }
+	}
+
+}
+
+impl<F: FloatNum, const N: usize> Iterator for Traversal<F, N> {
+	type Item = [i128; N];
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		let cell = self.cell;
+		// only the axes not in their last cell yet, so the rounding can't overshoot the end
+		let crossed = (0..N).filter(|i| self.cell[*i] != self.last[*i])
+			.reduce(|a, b| if self.next_boundary[b] < self.next_boundary[a] { b } else { a });
+		if let Some(axis) = crossed {
+			self.cell[axis] += (self.last[axis] - self.cell[axis]).signum();
+			self.next_boundary[axis] += self.delta[axis];
+		}
+		Some(cell)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::fixed::Fixed26_6;
+	use super::{GridRay, GridRay3d};
+
+	#[test]
+	fn walk() {
+		let ray: GridRay<f64, i32> = GridRay::new((-2.25, 3.75), (4.5, -1.2));
+		assert_eq!(ray.len(), 7 + 5 + 1);
+		let mut previous = (-4, 3);
+		for (x, y) in ray {
+			assert!([(1, 0), (0, -1)].contains(&(x - previous.0, y - previous.1)), "{:?}", (x, y));
+			// the ray goes through the cell, between the values at both of its sides
+			let at = |x: f64| 3.75 + (x + 2.25) * (-1.2 - 3.75) / (4.5 + 2.25);
+			let (left, right) = (at((x as f64).max(-2.25)), at((x as f64 + 1.0).min(4.5)));
+			assert!(right <= y as f64 + 1.0 && y as f64 <= left, "{:?}", (x, y));
+			previous = (x, y);
+		}
+		assert_eq!(previous, (4, -2));
+		let still: GridRay<f32, i8> = GridRay::new((0.5, -0.5), (0.75, -0.25));
+		assert!(still.eq([(0, -1)]));
+		let (zero, two) = (Fixed26_6::from(0), Fixed26_6::from(2));
+		let fixed = GridRay::<_, i16>::new((Fixed26_6::from_raw(-96), zero), (two, zero));
+		assert!(fixed.eq([(-2, 0), (-1, 0), (0, 0), (1, 0), (2, 0)]));
+	}
+
+	#[test]
+	fn voxels() {
+		let ray: GridRay3d<f32, i32> = GridRay3d::new((0.1, 0.2, 0.3), (9.9, 9.8, 9.7));
+		assert_eq!(ray.len(), 28);
+		let mut previous = (0, -1, 0);
+		for voxel in ray {
+			let step = (voxel.0 - previous.0, voxel.1 - previous.1, voxel.2 - previous.2);
+			assert!([(1, 0, 0), (0, 1, 0), (0, 0, 1)].contains(&step), "{:?}", voxel);
+			previous = voxel;
+		}
+		assert_eq!(previous, (9, 9, 9));
+	}
+
+}
@@ -0,0 +1,204 @@
+//! Package with the run-length encoding of the spans of the two-dimensional zips
+//!
+//! Each [Span] is encoded as three numbers: the difference of its axis value with the one of the
+//! previous span, the difference of the start of its range with the one of the previous span and
+//! the length of its range minus one. The first span is compared to zero. The differences are
+//! zigzag encoded, so the small negative ones stay small, and every number is written as a
+//! LEB128 varint: seven bits per byte, with the highest bit set in every byte but the last one.
+//!
+//! ```
+//! # use bresenham_zip::{Axis, rle, triangle::Triangle2};
+//! let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+//! let spans = triangle.rasterize_along(Axis::Y).unwrap().spans();
+//! let mut buffer = [0u8; 512];
+//! let mut written = 0;
+//! for (slot, byte) in buffer.iter_mut().zip(spans.clone().encode_rle()) {
+//!   *slot = byte;
+//!   written += 1;
+//! }
+//! assert!(rle::decode(buffer[..written].iter().copied()).eq(spans));
+//! ```
+
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use crate::span::Span;
+use crate::util::wide;
+use crate::SignedNum;
+
+/// Maximum number of bytes of the encoding of a span, three varints of 128 bits
+const MAX_SPAN_BYTES: usize = 3 * 19;
+
+/// Iterator over the bytes of the run-length encoding of the spans yielded by a zip, see the
+/// [module](self) documentation for the format
+#[derive(Clone, PartialEq)]
+pub struct RleEncoder<I> {
+	spans: I,
+	previous: (i128, i128),
+	bytes: [u8; MAX_SPAN_BYTES],
+	next: usize,
+	len: usize,
+}
+
+impl<I> RleEncoder<I> {
+
+	#[inline]
+	pub(crate) fn new(spans: I) -> Self {
+		Self {
+			spans,
+			previous: (0, 0),
+			bytes: [0; MAX_SPAN_BYTES],
+			next: 0,
+			len: 0,
+		}
+	}
+
+	/// Appends the number to the pending bytes as a varint
+	fn push(&mut self, mut value: u128) {
+		loop {
+			let byte = (value & 0x7f) as u8;
+			value >>= 7;
+			if value == 0 {
+				self.bytes[self.len] = byte;
+				self.len += 1;
+				return;
+			}
+			self.bytes[self.len] = byte | 0x80;
+			self.len += 1;
+		}
+	}
+
+}
+
+impl<T: SignedNum, I: Iterator<Item = Span<T>>> Iterator for RleEncoder<I> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.next == self.len {
+			let span = self.spans.next()?;
+			let (value, start, end) = (
+				wide(span.axis_value),
+				wide(*span.range.start()),
+				wide(*span.range.end()),
+			);
+			(self.next, self.len) = (0, 0);
+			self.push(zigzag(value - self.previous.0));
+			self.push(zigzag(start - self.previous.1));
+			self.push((end - start) as u128);
+			self.previous = (value, start);
+		}
+		self.next += 1;
+		Some(self.bytes[self.next - 1])
+	}
+}
+
+impl<I: Debug> Debug for RleEncoder<I> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "RleEncoder [ {:?} ]", self.spans)
+	}
+}
+
+/// Returns the iterator over the spans encoded in the bytes, see [RleDecoder]
+///
+/// * `bytes` - Bytes yielded by a [RleEncoder]
+///
+pub fn decode<T, B: IntoIterator<Item = u8>>(bytes: B) -> RleDecoder<B::IntoIter, T> {
+	RleDecoder {
+		bytes: bytes.into_iter(),
+		previous: (0, 0),
+		phantom: PhantomData,
+	}
+}
+
+/// Iterator over the spans encoded by a [RleEncoder]. It stops at the end of the bytes, dropping
+/// the last span if it's cut, or at the first value not fitting in the type of the coordinates.
+#[derive(Clone)]
+pub struct RleDecoder<B, T> {
+	bytes: B,
+	previous: (i128, i128),
+	phantom: PhantomData<T>,
+}
+
+impl<B: Iterator<Item = u8>, T> RleDecoder<B, T> {
+
+	/// Reads the next varint of the bytes
+	fn pull(&mut self) -> Option<u128> {
+		let mut value = 0;
+		for shift in (0..128).step_by(7) {
+			let byte = self.bytes.next()?;
+			value |= ((byte & 0x7f) as u128) << shift;
+			if byte & 0x80 == 0 {
+				return Some(value);
+			}
+		}
+		None
+	}
+
+}
+
+impl<B: Iterator<Item = u8>, T: SignedNum> Iterator for RleDecoder<B, T> {
+	type Item = Span<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let value = self.previous.0 + unzigzag(self.pull()?);
+		let start = self.previous.1 + unzigzag(self.pull()?);
+		let end = start.checked_add(i128::try_from(self.pull()?).ok()?)?;
+		self.previous = (value, start);
+		let (value, start, end) = (T::from(value)?, T::from(start)?, T::from(end)?);
+		Some(Span { axis_value: value, range: start..=end })
+	}
+}
+
+impl<B: Debug, T> Debug for RleDecoder<B, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "RleDecoder [ {:?} ]", self.bytes)
+	}
+}
+
+/// Maps the signed numbers to unsigned ones alternating the signs: 0, -1, 1, -2, 2...
+fn zigzag(value: i128) -> u128 {
+	((value << 1) ^ (value >> 127)) as u128
+}
+
+/// Reverts [zigzag]
+fn unzigzag(value: u128) -> i128 {
+	(value >> 1) as i128 ^ -((value & 1) as i128)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::span::Span;
+	use crate::zip::BresenhamZip;
+	use super::{decode, unzigzag, zigzag};
+
+	#[test]
+	fn bytes() {
+		let spans = BresenhamZip::new((2, 0), (4, 2), (0, 2), 1).spans();
+		let mut bytes = [0; 9];
+		assert_eq!(spans.encode_rle().zip(&mut bytes).map(|(byte, slot)| *slot = byte).count(), 9);
+		// the second span is one row lower, starts one pixel before and covers three pixels
+		assert_eq!(bytes, [0, 4, 0, 2, 1, 2, 2, 1, 4]);
+	}
+
+	#[test]
+	fn round_trip() {
+		let spans = BresenhamZip::new((0, -300), (-1000, 200), (5000, 200), 1).spans();
+		let mut bytes = [0; 4096];
+		let written = spans.clone().encode_rle().zip(&mut bytes).map(|(b, slot)| *slot = b).count();
+		assert!(written < 4096);
+		assert!(decode(bytes[..written].iter().copied()).eq(spans));
+		// the cut span is dropped
+		assert_eq!(decode::<i32, _>(bytes[..4].iter().copied()).count(), 1);
+	}
+
+	#[test]
+	fn varints() {
+		for value in [0, 1, -1, 63, -64, 64, i64::MAX as i128, i128::MIN, i128::MAX] {
+			assert_eq!(unzigzag(zigzag(value)), value);
+		}
+		let span = Span { axis_value: 200, range: -10..=10 };
+		let mut decoded = decode::<i16, _>([0x90, 0x03, 0x13, 0x14]);
+		assert_eq!(decoded.next(), Some(span));
+		assert_eq!(decoded.next(), None);
+	}
+
+}
@@ -0,0 +1,158 @@
+//! Package with the logic to rasterize filled rectangles with rounded corners
+
+use core::fmt::{Debug, Formatter};
+use crate::circle::CircleZip;
+use crate::fill::FillIterator;
+use crate::span::SpanIterator;
+use crate::util::{count_between, Point};
+use crate::{Axis, Error, Point2, SignedNum};
+
+/// Zip of a filled rectangle with its corners rounded as quarters of a circle, yielding the pair
+/// of points at both ends of each of its rows in the travelled axis, like the zips of the
+/// triangles. The rows at the top and the bottom are the halves of a [CircleZip] split apart, and
+/// the straight rows between them repeat its widest row.
+///
+/// ```
+/// # use bresenham_zip::{Axis, rounded::RoundedRectZip};
+/// let mut zip = RoundedRectZip::new((0, 0), (19, 9), 3, Axis::Y).unwrap();
+/// assert_eq!(zip.len(), 10);
+/// assert_eq!(zip.next(), Some(((2, 0), (17, 0))));
+/// assert_eq!(zip.nth(2), Some(((0, 3), (19, 3))));
+/// assert_eq!(zip.last(), Some(((2, 9), (17, 9))));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct RoundedRectZip<T> {
+	corners: CircleZip<T>,
+	min: Point2<T>,
+	max: Point2<T>,
+	radius: T,
+	row: T,
+	widest: Option<(Point2<T>, Point2<T>)>,
+	straight: usize,
+	axis: u8,
+}
+
+impl<T: SignedNum> RoundedRectZip<T> {
+
+	/// Creates the zip of the rectangle going from its lowest row in the axis to its highest one.
+	/// The radius is reduced to fit in the rectangle, and a radius of zero or lower leaves the
+	/// corners square.
+	///
+	/// * `min` - Corner of the rectangle with the lowest coordinates, included
+	/// * `max` - Corner of the rectangle with the highest coordinates, included
+	/// * `radius` - Radius of the corners, in pixels
+	/// * `axis` - Axis to use in the iteration
+	///
+	/// # Error
+	/// [Error::MissingAxis], if the axis is [Axis::Z].
+	///
+	pub fn new(min: Point2<T>, max: Point2<T>, radius: T, axis: Axis)
+		-> Result<Self, Error<T>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => return Err(Error::MissingAxis),
+		};
+		let two = T::one() + T::one();
+		let fitting = ((max.0 - min.0) / two).min((max.1 - min.1) / two);
+		let radius = radius.min(fitting).max(T::zero());
+		let rows = count_between(min.nth(axis), max.nth(axis));
+		let (corners, straight) = if max.0 < min.0 || max.1 < min.1 {
+			(CircleZip::new(min, -T::one(), Axis::Y)?, 0)
+		} else {
+			let corners = CircleZip::new((T::zero(), T::zero()), radius, Axis::Y)?;
+			let straight = rows - corners.len();
+			(corners, straight)
+		};
+		Ok(Self { corners, min, max, radius, row: min.nth(axis), widest: None, straight, axis })
+	}
+
+	/// Turns the zip into an iterator over every point between each pair of points,
+	/// both included.
+	pub fn pixels(self) -> FillIterator<Self, Point2<T>> {
+		FillIterator::new(self)
+	}
+
+	/// Turns the zip into an iterator over the [Span](crate::span::Span)s defined by each pair
+	/// of points
+	pub fn spans(self) -> SpanIterator<Self> {
+		let axis = self.axis;
+		SpanIterator::new(self, axis)
+	}
+
+}
+
+impl<T: SignedNum> Iterator for RoundedRectZip<T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (_, end) = match self.widest {
+			Some(widest) if self.straight > 0 => {
+				self.straight -= 1;
+				widest
+			}
+			_ => {
+				let pair = self.corners.next()?;
+				if pair.0.1 == T::zero() {
+					self.widest = Some(pair);
+				}
+				pair
+			}
+		};
+		// the half width of the circle row, spread from the centers of the corners
+		let other = 1 - self.axis;
+		let (mut a, mut b) = (self.min, self.max);
+		a.set_nth(self.axis, self.row);
+		b.set_nth(self.axis, self.row);
+		a.set_nth(other, self.min.nth(other) + self.radius - end.0);
+		b.set_nth(other, self.max.nth(other) - self.radius + end.0);
+		self.row += T::one();
+		Some((a, b))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.corners.len() + self.straight;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for RoundedRectZip<T> {}
+
+impl<T: SignedNum> Debug for RoundedRectZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "RoundedRectZip [ {:?}, {:?}, {:?} ]. Remaining: {}",
+			self.min, self.max, self.radius, self.len())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::circle::CircleZip;
+	use crate::{Axis, Error};
+	use super::RoundedRectZip;
+
+	#[test]
+	fn corners() {
+		// the rounded square as wide as its corners is the circle
+		let square = RoundedRectZip::new((-5, -5), (5, 5), 5, Axis::X).unwrap();
+		assert!(square.eq(CircleZip::new((0, 0), 5, Axis::X).unwrap()));
+		let tall = RoundedRectZip::new((0, 0), (6, 30), 100, Axis::Y).unwrap();
+		assert_eq!(tall.len(), 31);
+		assert!(tall.clone().take(4).eq(CircleZip::new((3, 3), 3, Axis::Y).unwrap().take(4)));
+		assert!(tall.clone().skip(27).eq(CircleZip::new((3, 27), 3, Axis::Y).unwrap().skip(3)));
+		assert!(tall.skip(4).take(23).all(|(a, b)| a.0 == 0 && b.0 == 6));
+	}
+
+	#[test]
+	fn square() {
+		let rectangle = RoundedRectZip::new((2, 3), (11, 7), 0, Axis::X).unwrap();
+		assert_eq!(rectangle.len(), 10);
+		assert!(rectangle.clone().all(|(a, b)| a.1 == 3 && b.1 == 7));
+		assert_eq!(rectangle.pixels().count(), 10 * 5);
+		assert_eq!(RoundedRectZip::new((2, 3), (1, 7), 1, Axis::Y).unwrap().count(), 0);
+		let zip = RoundedRectZip::new((2, 3), (11, 7), 1, Axis::Z);
+		assert_eq!(zip.unwrap_err(), Error::MissingAxis);
+	}
+
+}
@@ -0,0 +1,112 @@
+//! Package with the grouping of the spans by their axis value, yielding every span of a scanline
+//! at once like compositors and coverage accumulators consume them. Requires the `alloc` feature.
+
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use core::ops::RangeInclusive;
+use crate::span::Span;
+use crate::SignedNum;
+
+/// Spans sharing the same value of the travelled axis, like the runs of a row of pixels
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Scanline<T> {
+	pub axis_value: T,
+	/// Ranges covered in the other axis, in the order they were yielded
+	pub ranges: Vec<RangeInclusive<T>>,
+}
+
+/// Iterator grouping the consecutive spans of another one with the same axis value into
+/// [Scanline]s. The spans must be sorted by their axis value, like the ones of a
+/// [Polygon](crate::polygon::Polygon), so each scanline is yielded once; the spans of a mesh are
+/// sorted by [MeshSpans::scanlines](crate::mesh::MeshSpans::scanlines).
+///
+/// ```
+/// # use bresenham_zip::{Axis, polygon::{FillRule, Polygon}};
+/// let shape = [(0, 0), (10, 0), (10, 10), (5, 5), (0, 10)];
+/// let spans = Polygon::new(&shape).spans(Axis::Y, FillRule::EvenOdd).unwrap();
+/// let mut scanlines = spans.scanlines().skip(6);
+/// assert_eq!(scanlines.next().unwrap().ranges, [0..=4, 6..=10]);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Scanlines<I, T> {
+	spans: I,
+	pending: Option<Span<T>>,
+}
+
+impl<I, T> Scanlines<I, T> {
+
+	/// Creates the iterator grouping the spans
+	///
+	/// * `spans` - Iterator over the spans, sorted by their axis value
+	///
+	#[inline]
+	pub fn new(spans: I) -> Self {
+		Self { spans, pending: None }
+	}
+
+}
+
+impl<T: SignedNum, I: Iterator<Item = Span<T>>> Iterator for Scanlines<I, T> {
+	type Item = Scanline<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let first = self.pending.take().or_else(|| self.spans.next())?;
+		let mut scanline = Scanline { axis_value: first.axis_value, ranges: Vec::new() };
+		scanline.ranges.push(first.range);
+		for span in self.spans.by_ref() {
+			if span.axis_value != scanline.axis_value {
+				self.pending = Some(span);
+				break;
+			}
+			scanline.ranges.push(span.range);
+		}
+		Some(scanline)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let pending = self.pending.is_some() as usize;
+		let (lower, upper) = self.spans.size_hint();
+		((lower + pending).min(1), upper.map(|upper| upper + pending))
+	}
+}
+
+impl<I: Debug, T: Debug> Debug for Scanlines<I, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Scanlines [ {:?} ]. Pending: {:?}", self.spans, self.pending)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mesh::Mesh;
+	use crate::span::Span;
+	use crate::Axis;
+	use super::Scanlines;
+
+	#[test]
+	fn grouped() {
+		let spans = [(0, 0..=2), (0, 4..=5), (1, 1..=1), (3, 0..=3), (3, 5..=5), (3, 7..=9)];
+		let spans = spans.map(|(axis_value, range)| Span { axis_value, range });
+		let lines = Scanlines::new(spans.into_iter()).map(|line| (line.axis_value, line.ranges));
+		assert!(lines.map(|(y, ranges)| (y, ranges.len())).eq([(0, 2), (1, 1), (3, 3)]));
+		assert_eq!(Scanlines::new(core::iter::empty::<Span<i32>>()).next(), None);
+	}
+
+	#[test]
+	fn mesh() {
+		// two triangles side by side with a gap between them
+		let vertices = [(0, 0), (4, 0), (0, 4), (8, 0), (12, 0), (12, 4)];
+		let indices = [[0, 1, 2], [3, 4, 5]];
+		let scanlines = Mesh::new(&vertices, &indices).spans(Axis::Y).unwrap().scanlines();
+		let mut count = 0;
+		for (y, scanline) in scanlines.enumerate() {
+			assert_eq!(scanline.axis_value, y as i32);
+			assert_eq!(scanline.ranges.len(), 2);
+			assert!(scanline.ranges[0].end() < scanline.ranges[1].start());
+			count += 1;
+		}
+		assert_eq!(count, 5);
+	}
+
+}
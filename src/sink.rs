@@ -0,0 +1,104 @@
+//! Package with the targets where the two-dimensional triangles can be rasterized into
+
+use core::fmt::{Debug, Formatter};
+use crate::SignedNum;
+
+/// Target receiving the spans of the rasterized triangles, like a framebuffer. Only the rows
+/// need to be filled: the columns of the triangles rasterized along [Axis::X](crate::Axis::X)
+/// are filled one pixel per row unless the target has a faster way to do it.
+pub trait SpanSink<T, V> {
+	/// Fills the pixels of the row `y` between `x0` and `x1`, both included and sorted
+	fn fill_span(&mut self, y: T, x0: T, x1: T, value: V);
+
+	/// Fills the pixels of the column `x` between `y0` and `y1`, both included and sorted
+	fn fill_column(&mut self, x: T, y0: T, y1: T, value: V) where T: SignedNum, V: Copy {
+		let mut y = y0;
+		while y <= y1 {
+			self.fill_span(y, x, x, value);
+			y += T::one();
+		}
+	}
+}
+
+/// [SpanSink] writing the pixels in a slice, row by row, with `stride` pixels between the start
+/// of each row. The pixels out of the slice are ignored, so the triangles don't need to be
+/// clipped before.
+///
+/// ```
+/// # use bresenham_zip::{Axis, sink::SliceSink, triangle::Triangle2};
+/// let mut pixels = [0u8; 8 * 8];
+/// let triangle = Triangle2::new((0, 0), (7, 0), (0, 7));
+/// let mut sink = SliceSink::new(&mut pixels, 8);
+/// triangle.rasterize_along(Axis::Y).unwrap().rasterize_into(&mut sink, 1);
+/// assert_eq!(pixels.iter().filter(|pixel| **pixel == 1).count(), 36);
+/// ```
+pub struct SliceSink<'a, V> {
+	pixels: &'a mut [V],
+	stride: usize,
+}
+
+impl<'a, V> SliceSink<'a, V> {
+
+	/// Creates the sink over the slice
+	///
+	/// * `pixels` - Pixels of the target, row by row
+	/// * `stride` - Number of pixels from the start of a row to the start of the next one
+	///
+	pub fn new(pixels: &'a mut [V], stride: usize) -> Self {
+		Self { pixels, stride }
+	}
+
+}
+
+impl<T: SignedNum, V: Copy> SpanSink<T, V> for SliceSink<'_, V> {
+	fn fill_span(&mut self, y: T, x0: T, x1: T, value: V) {
+		let (Some(y), Some(x1)) = (y.to_usize(), x1.to_usize()) else {
+			return;
+		};
+		let x0 = x0.to_usize().unwrap_or_default();
+		let start = y.saturating_mul(self.stride);
+		let end = x1.min(self.stride.saturating_sub(1)) + start;
+		if x0 <= x1 && x0 < self.stride && end < self.pixels.len() {
+			self.pixels[start + x0..=end].fill(value);
+		}
+	}
+}
+
+impl<V> Debug for SliceSink<'_, V> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "SliceSink [ {} pixels, stride {} ]", self.pixels.len(), self.stride)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::triangle::Triangle2;
+	use crate::Axis;
+	use super::{SliceSink, SpanSink};
+
+	#[test]
+	fn pixels() {
+		let triangle = Triangle2::new((-5, 2), (20, 9), (6, 17));
+		for axis in [Axis::X, Axis::Y] {
+			let zip = triangle.rasterize_along(axis).unwrap();
+			let mut pixels = [false; 16 * 16];
+			zip.clone().rasterize_into(&mut SliceSink::new(&mut pixels, 16), true);
+			let mut expected = [false; 16 * 16];
+			for (x, y) in zip.pixels().filter(|(x, y)| (0..16).contains(x) && (0..16).contains(y)) {
+				expected[y as usize * 16 + x as usize] = true;
+			}
+			assert_eq!(pixels, expected);
+		}
+	}
+
+	#[test]
+	fn stride() {
+		let mut pixels = [0; 12];
+		let mut sink = SliceSink::new(&mut pixels, 4);
+		sink.fill_span(1, -2, 1, 1);
+		sink.fill_span(2, 2, 9, 2);
+		sink.fill_span(3, 0, 3, 3);
+		assert_eq!(pixels, [0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 2, 2]);
+	}
+
+}
@@ -0,0 +1,183 @@
+//! Package with the slicing of indexed three-dimensional meshes into layers, like the slicers of
+//! the 3D printers
+
+use core::fmt::{Debug, Formatter};
+use core::slice::Iter;
+use crate::triangle_3d::Triangle3;
+use crate::util::{bounds, Point};
+use crate::{Axis, Point2, Point3, SignedNum};
+
+/// Slicer of a three-dimensional mesh, defined by a list of vertices and the triples of indices
+/// of the vertices conforming each one of its triangles, into the planes perpendicular to an
+/// axis. Each layer is the list of segments where the triangles cross its plane, see
+/// [Triangle3::slice_at]; for closed meshes they join into closed polygons.
+///
+/// ```
+/// # use bresenham_zip::{Axis, slicer::Slicer};
+/// // tetrahedron with a corner at the origin and the others on each axis
+/// let vertices = [(0, 0, 0), (10, 0, 0), (0, 10, 0), (0, 0, 10)];
+/// let indices = [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]];
+/// let slicer = Slicer::new(&vertices, &indices, Axis::Z);
+/// assert_eq!(slicer.layers(5).count(), 3);
+/// // the triangle of the layer in the middle
+/// assert_eq!(slicer.slice_at(5).count(), 3);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Slicer<'a, T> {
+	vertices: &'a [Point3<T>],
+	indices: &'a [[usize; 3]],
+	axis: Axis,
+}
+
+impl<'a, T: SignedNum> Slicer<'a, T> {
+
+	/// Creates the slicer of the mesh
+	///
+	/// * `vertices` - Points of the mesh
+	/// * `indices` - Triples of indices of `vertices` conforming each triangle
+	/// * `axis` - Axis perpendicular to the layers
+	///
+	pub fn new(vertices: &'a [Point3<T>], indices: &'a [[usize; 3]], axis: Axis) -> Self {
+		Self { vertices, indices, axis }
+	}
+
+	/// Returns an iterator over the segments of the layer with the given value in the axis, see
+	/// [MeshSlice]
+	///
+	/// * `value` - Value of the layer in the axis
+	///
+	pub fn slice_at(&self, value: T) -> MeshSlice<'a, T> {
+		MeshSlice { vertices: self.vertices, indices: self.indices.iter(), axis: self.axis, value }
+	}
+
+	/// Returns an iterator over the layers of the mesh every `step` units, from the lowest value
+	/// of its vertices in the axis to the highest one, see [Layers]
+	///
+	/// * `step` - Distance between the layers, positive
+	///
+	pub fn layers(&self, step: T) -> Layers<'a, T> {
+		let axis = match self.axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => 2,
+		};
+		let (next, last) = match self.vertices {
+			[] => (T::one(), T::zero()),
+			vertices => {
+				let (min, max) = bounds(vertices);
+				(min.nth(axis), max.nth(axis))
+			}
+		};
+		Layers { slicer: *self, next, last, step }
+	}
+
+}
+
+/// Iterator over the segments where the triangles of a mesh cross a plane, one triangle after the
+/// other. The triangles not crossing it are skipped.
+///
+/// # Panics
+/// If any of the indices of the mesh is out of the bounds of its vertices.
+#[derive(Clone)]
+pub struct MeshSlice<'a, T> {
+	vertices: &'a [Point3<T>],
+	indices: Iter<'a, [usize; 3]>,
+	axis: Axis,
+	value: T,
+}
+
+impl<T: SignedNum> Iterator for MeshSlice<'_, T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let vertices = self.vertices;
+		self.indices.by_ref()
+			.map(|indices| Triangle3::from(indices.map(|index| vertices[index])))
+			.find_map(|triangle| triangle.slice_at(self.axis, self.value))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.indices.len()))
+	}
+}
+
+impl<T: SignedNum> Debug for MeshSlice<'_, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "MeshSlice [ {:?}, {:?} ]. Remaining triangles: {}",
+			self.axis, self.value, self.indices.len())
+	}
+}
+
+/// Iterator over the layers of a [Slicer], yielding the value of each layer in the axis with the
+/// iterator over its segments
+#[derive(Clone)]
+pub struct Layers<'a, T> {
+	slicer: Slicer<'a, T>,
+	next: T,
+	last: T,
+	step: T,
+}
+
+impl<'a, T: SignedNum> Iterator for Layers<'a, T> {
+	type Item = (T, MeshSlice<'a, T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.next > self.last {
+			return None;
+		}
+		let value = self.next;
+		self.next += self.step;
+		Some((value, self.slicer.slice_at(value)))
+	}
+}
+
+impl<T: SignedNum> Debug for Layers<'_, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Layers [ {:?} ]. Next: {:?}, last: {:?}", self.slicer.axis, self.next, self.last)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::triangle_3d::Triangle3;
+	use crate::Axis;
+	use super::Slicer;
+
+	/// Octahedron around the origin, closed
+	const VERTICES: [(i32, i32, i32); 6] =
+		[(12, 0, 0), (-12, 0, 0), (0, 12, 0), (0, -12, 0), (0, 0, 12), (0, 0, -12)];
+	const INDICES: [[usize; 3]; 8] = [
+		[0, 2, 4], [2, 1, 4], [1, 3, 4], [3, 0, 4], [2, 0, 5], [1, 2, 5], [3, 1, 5], [0, 3, 5],
+	];
+
+	#[test]
+	fn triangle() {
+		let triangle = Triangle3::new((0, 0, 0), (20, 10, 10), (10, 20, 0));
+		assert_eq!(triangle.slice_at(Axis::Z, 5), Some(((10, 5), (15, 15))));
+		assert_eq!(triangle.slice_at(Axis::X, 10), Some(((5, 5), (20, 0))));
+		assert_eq!(triangle.slice_at(Axis::Y, -1), None);
+		// the vertex touching the plane from above is not a crossing
+		assert_eq!(triangle.slice_at(Axis::Y, 0), None);
+	}
+
+	#[test]
+	fn closed() {
+		let slicer = Slicer::new(&VERTICES, &INDICES, Axis::Z);
+		for (z, segments) in slicer.layers(3) {
+			// every end of a segment is shared by exactly two segments
+			let mut ends = [[0; 25]; 25];
+			for (a, b) in segments {
+				assert!(a.0.abs() + a.1.abs() + z.abs() <= 12);
+				for (x, y) in [a, b] {
+					ends[(y + 12) as usize][(x + 12) as usize] += 1;
+				}
+			}
+			assert!(ends.iter().flatten().all(|count| *count == 0 || *count == 2), "{}", z);
+		}
+		assert_eq!(slicer.slice_at(0).count(), 4);
+		assert_eq!(slicer.layers(3).map(|(z, _)| z).last(), Some(12));
+		assert_eq!(Slicer::new(&[], &[], Axis::X).layers(1).count(), 0);
+	}
+
+}
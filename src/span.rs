@@ -0,0 +1,203 @@
+//! Package with the span output of the two-dimensional zips
+
+use core::fmt::{Debug, Formatter};
+use core::ops::RangeInclusive;
+use crate::rle::RleEncoder;
+use crate::sink::SpanSink;
+use crate::stipple::{Pattern, Stipple};
+use crate::util::{count_between, Point};
+use crate::{Point2, SignedNum};
+
+/// Horizontal or vertical line conforming the triangle. It contains the value shared in the
+/// travelled axis and the range of values covered in the other axis, sorted from lowest to highest.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span<T> {
+	pub axis_value: T,
+	pub range: RangeInclusive<T>,
+}
+
+impl<T: SignedNum> Span<T> {
+
+	/// Creates the span joining two points sharing the value of the specified axis
+	pub(crate) fn from_pair(a: Point2<T>, b: Point2<T>, axis: u8) -> Self {
+		let other = 1 - axis;
+		let (start, end) = (a.nth(other), b.nth(other));
+		Self {
+			axis_value: a.nth(axis),
+			range: if start <= end { start..=end } else { end..=start },
+		}
+	}
+
+}
+
+/// Iterator turning the pairs of points of a two-dimensional zip into [Span]s
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+/// for span in triangle.rasterize_along(Axis::Y).unwrap().spans() {
+///   for x in span.range {
+///     // fill (x, span.axis_value)
+///   }
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct SpanIterator<I> {
+	zip: I,
+	axis: u8,
+}
+
+impl<I> SpanIterator<I> {
+
+	#[inline]
+	pub(crate) fn new(zip: I, axis: u8) -> Self {
+		Self { zip, axis }
+	}
+
+}
+
+impl<I> SpanIterator<I> {
+
+	/// Fills every span left in the sink with the value, see [SpanSink]
+	///
+	/// * `sink` - Target of the spans, like a framebuffer
+	/// * `value` - Value to fill the spans with, like a color
+	///
+	pub fn rasterize_into<T, V, S>(self, sink: &mut S, value: V)
+		where T: SignedNum, V: Copy, S: SpanSink<T, V>, I: Iterator<Item = (Point2<T>, Point2<T>)> {
+		let axis = self.axis;
+		for span in self {
+			let (start, end) = span.range.into_inner();
+			if axis == 1 {
+				sink.fill_span(span.axis_value, start, end, value);
+			} else {
+				sink.fill_column(span.axis_value, start, end, value);
+			}
+		}
+	}
+
+	/// Returns the iterator over the bytes of the run-length encoding of the spans left, see
+	/// [rle](crate::rle)
+	pub fn encode_rle(self) -> RleEncoder<Self> {
+		RleEncoder::new(self)
+	}
+
+	/// Returns the iterator over the parts of the spans left kept by the pattern, see
+	/// [Stipple]
+	pub fn stipple<T>(self, pattern: Pattern) -> Stipple<Self, T>
+		where T: SignedNum, I: Iterator<Item = (Point2<T>, Point2<T>)> {
+		Stipple::new(self, pattern)
+	}
+
+}
+
+impl<T: SignedNum, I: Iterator<Item = (Point2<T>, Point2<T>)>> Iterator for SpanIterator<I> {
+	type Item = Span<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.zip.next().map(|(a, b)| Span::from_pair(a, b, self.axis))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.zip.size_hint()
+	}
+}
+
+impl<T: SignedNum, I: ExactSizeIterator<Item = (Point2<T>, Point2<T>)>> ExactSizeIterator for SpanIterator<I> {}
+
+impl<I: Debug> Debug for SpanIterator<I> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "SpanIterator [ {:?} ]", self.zip)
+	}
+}
+
+/// Iterator over the spans of a two-dimensional zip as `(index, axis_value, start, end)` tuples,
+/// being `index` the position of the axis value counted from the lowest one of the zip, like the
+/// row of a row-major buffer covering the triangle, and `start` and `end` the bounds of the span
+/// in the other axis, both included
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 10), (4, 10), (0, 14));
+/// let mut rows = triangle.rasterize_along(Axis::Y).unwrap().enumerate_axis();
+/// assert_eq!(rows.next(), Some((0, 10, 0, 4)));
+/// assert_eq!(rows.last(), Some((4, 14, 0, 0)));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct EnumerateAxis<I, T> {
+	spans: SpanIterator<I>,
+	origin: T,
+}
+
+impl<I, T> EnumerateAxis<I, T> {
+
+	#[inline]
+	pub(crate) fn new(spans: SpanIterator<I>, origin: T) -> Self {
+		Self { spans, origin }
+	}
+
+}
+
+impl<T: SignedNum, I: Iterator<Item = (Point2<T>, Point2<T>)>> Iterator for EnumerateAxis<I, T> {
+	type Item = (usize, T, T, T);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.spans.next().map(|span| {
+			let (start, end) = span.range.into_inner();
+			(count_between(self.origin, span.axis_value) - 1, span.axis_value, start, end)
+		})
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.spans.size_hint()
+	}
+}
+
+impl<T: SignedNum, I: ExactSizeIterator<Item = (Point2<T>, Point2<T>)>> ExactSizeIterator
+	for EnumerateAxis<I, T> {}
+
+impl<I: Debug, T: Debug> Debug for EnumerateAxis<I, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "EnumerateAxis [ {:?} from {:?} ]", self.spans, self.origin)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::zip::BresenhamZip;
+	use super::Span;
+
+	#[test]
+	fn spans() {
+		let mut spans = BresenhamZip::new((2, 0), (4, 2), (0, 2), 1).spans();
+		assert_eq!(spans.next(), Some(Span { axis_value: 0, range: 2..=2 }));
+		assert_eq!(spans.next(), Some(Span { axis_value: 1, range: 1..=3 }));
+		assert_eq!(spans.next(), Some(Span { axis_value: 2, range: 0..=4 }));
+		assert_eq!(spans.next(), None);
+	}
+
+	#[test]
+	fn spans_x() {
+		let mut spans = BresenhamZip::new((0, 5), (3, 2), (3, 8), 0).spans();
+		assert_eq!(spans.next(), Some(Span { axis_value: 0, range: 5..=5 }));
+		assert_eq!(spans.last(), Some(Span { axis_value: 3, range: 2..=8 }));
+	}
+
+	#[test]
+	fn enumerated() {
+		let zip = BresenhamZip::new((-3, 8), (5, 4), (-9, 4), 1);
+		let mut rows = [(0, 0); 5];
+		for (index, y, start, end) in zip.clone().enumerate_axis() {
+			assert_eq!(index as i32, y - 4);
+			rows[index] = (start, end);
+		}
+		assert_eq!(rows[0], (-9, 5));
+		assert_eq!(rows[4], (-4, -2));
+		assert_eq!(zip.clone().enumerate_axis().next(), Some((4, 8, -4, -2)));
+		assert_eq!(zip.enumerate_axis().len(), 5);
+	}
+
+}
@@ -0,0 +1,16 @@
+//! Package with the checkpoints to suspend and resume the zips
+
+/// Position of a zip returned by its `save_state` method. It only contains the values of the
+/// travelled axis still pending at both ends, so it can be stored anywhere and restored later on a
+/// zip built with the same points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ZipState<T> {
+	/// Next value of the axis to be yielded from the front
+	pub front: T,
+	/// Next value of the axis to be yielded from the back, the goal of the zip
+	pub back: T,
+	/// Whether every pair has already been yielded
+	pub done: bool,
+}
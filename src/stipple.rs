@@ -0,0 +1,185 @@
+//! Package with the masking of the spans by a repeating pattern, producing hatched fills and
+//! dithered shading straight from the rasterization
+
+use core::fmt::{Debug, Formatter};
+use crate::dither::BAYER;
+use crate::span::Span;
+use crate::util::{narrow, wide};
+use crate::SignedNum;
+
+/// Repeating pattern deciding which pixels of the spans are kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Pattern {
+	/// Keeps the whole spans of one of every `every` values of the axis, the ones equal to
+	/// `offset` modulo `every`. An `every` of zero keeps every span.
+	Scanlines { every: u32, offset: u32 },
+	/// Keeps the pixels whose bit is set in the 8×8 pattern. The row of the pattern is the axis
+	/// value modulo 8 and its bit `1 << n` covers the values of the other axis equal to `n`
+	/// modulo 8, so the pattern is anchored to the origin and the fills of adjacent triangles
+	/// match.
+	Bits([u8; 8]),
+}
+
+impl Pattern {
+
+	/// Checkerboard keeping half of the pixels, like a 50% dither
+	pub const CHECKERBOARD: Pattern =
+		Pattern::Bits([0x55, 0xaa, 0x55, 0xaa, 0x55, 0xaa, 0x55, 0xaa]);
+
+	/// Diagonal lines going up to the right every four pixels
+	pub const DIAGONAL: Pattern =
+		Pattern::Bits([0x11, 0x22, 0x44, 0x88, 0x11, 0x22, 0x44, 0x88]);
+
+	/// Returns the ordered dither of the intensity with the [BAYER] matrix, keeping the pixels
+	/// whose threshold is below it. The intensities go from zero, keeping none, to 255, keeping
+	/// all of them.
+	///
+	/// ```
+	/// # use bresenham_zip::{Axis, stipple::Pattern, triangle::Triangle2};
+	/// let triangle = Triangle2::new((0, 0), (31, 0), (0, 31));
+	/// let spans = triangle.rasterize_along(Axis::Y).unwrap().spans();
+	/// for span in spans.stipple(Pattern::bayer(96)) {
+	///   for x in span.range {
+	///     // light (x, span.axis_value)
+	///   }
+	/// }
+	/// ```
+	///
+	/// * `intensity` - Intensity of the triangle
+	///
+	pub const fn bayer(intensity: u8) -> Pattern {
+		let level = (intensity as u32 * 64 + 127) / 255;
+		let mut rows = [0; 8];
+		let mut row = 0;
+		while row < 8 {
+			let mut column = 0;
+			while column < 8 {
+				if (BAYER[row][column] as u32) < level {
+					rows[row] |= 1 << column;
+				}
+				column += 1;
+			}
+			row += 1;
+		}
+		Pattern::Bits(rows)
+	}
+
+}
+
+/// Iterator over the parts of the spans of another one kept by a [Pattern]. With
+/// [Pattern::Bits], each span is split into the runs of consecutive pixels kept, yielded in
+/// order.
+///
+/// ```
+/// # use bresenham_zip::{Axis, stipple::Pattern, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (15, 0), (0, 15));
+/// let spans = triangle.rasterize_along(Axis::Y).unwrap().spans();
+/// let hatched = spans.clone().stipple(Pattern::Scanlines { every: 4, offset: 0 });
+/// assert!(hatched.map(|span| span.axis_value).eq([0, 4, 8, 12]));
+/// let pixels = spans.stipple(Pattern::CHECKERBOARD).map(|span| span.range.count());
+/// // the even rows keep the even columns and the odd rows the odd ones
+/// assert_eq!(pixels.sum::<usize>(), 36 + 28);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Stipple<I, T> {
+	spans: I,
+	pattern: Pattern,
+	/// Span being split with its row of the pattern
+	current: Option<(T, u8, i128, i128)>,
+}
+
+impl<I, T> Stipple<I, T> {
+
+	/// Creates the iterator masking the spans
+	///
+	/// * `spans` - Iterator over the spans, like the ones of a zip or a polygon
+	/// * `pattern` - Pattern of the pixels to keep
+	///
+	#[inline]
+	pub fn new(spans: I, pattern: Pattern) -> Self {
+		Self { spans, pattern, current: None }
+	}
+
+}
+
+impl<T: SignedNum, I: Iterator<Item = Span<T>>> Iterator for Stipple<I, T> {
+	type Item = Span<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some((axis_value, row, start, end)) = self.current {
+				let kept = |value: i128| row & (1 << value.rem_euclid(8)) != 0;
+				// both loops stop in less than eight steps unless the row is full or empty
+				let mut first = start;
+				while first <= end && row != 0 && !kept(first) {
+					first += 1;
+				}
+				if first > end || row == 0 {
+					self.current = None;
+					continue;
+				}
+				let mut last = if row == 0xff { end } else { first };
+				while last < end && kept(last + 1) {
+					last += 1;
+				}
+				self.current = (last < end).then_some((axis_value, row, last + 1, end));
+				return Some(Span { axis_value, range: narrow(first)..=narrow(last) });
+			}
+			let span = self.spans.next()?;
+			match self.pattern {
+				Pattern::Scanlines { every, offset } => {
+					let every = i128::from(every.max(1));
+					if wide(span.axis_value).rem_euclid(every) == i128::from(offset) % every {
+						return Some(span);
+					}
+				}
+				Pattern::Bits(rows) => {
+					let row = rows[wide(span.axis_value).rem_euclid(8) as usize];
+					let (start, end) = span.range.into_inner();
+					self.current = Some((span.axis_value, row, wide(start), wide(end)));
+				}
+			}
+		}
+	}
+}
+
+impl<I: Debug, T> Debug for Stipple<I, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Stipple [ {:?} ]. Pattern: {:?}", self.spans, self.pattern)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::span::Span;
+	use super::{Pattern, Stipple};
+
+	#[test]
+	fn bits() {
+		let spans = [(0, -9..=9), (1, -9..=9), (2, 3..=3)];
+		let spans = spans.map(|(axis_value, range)| Span { axis_value, range });
+		let pattern = Pattern::Bits([0xff, 0b0110_0001, 0, 0, 0, 0, 0, 0]);
+		let mut runs = Stipple::new(spans.clone().into_iter(), pattern);
+		assert_eq!(runs.next(), Some(Span { axis_value: 0, range: -9..=9 }));
+		// -8 and 0 are kept with bit 0, -3 and -2, 5 and 6 with bits 5 and 6
+		for range in [-8..=-8, -3..=-2, 0..=0, 5..=6, 8..=8] {
+			assert_eq!(runs.next(), Some(Span { axis_value: 1, range }));
+		}
+		assert_eq!(runs.next(), None);
+		let diagonal = Stipple::new(spans.into_iter(), Pattern::DIAGONAL);
+		assert_eq!(diagonal.filter(|span| span.axis_value == 0).count(), 5);
+	}
+
+	#[test]
+	fn scanlines() {
+		let spans = (-6..6).map(|axis_value| Span { axis_value, range: 0..=1 });
+		let pattern = Pattern::Scanlines { every: 3, offset: 1 };
+		let kept = Stipple::new(spans.clone(), pattern).map(|span| span.axis_value);
+		assert!(kept.eq([-5, -2, 1, 4]));
+		let every = Stipple::new(spans, Pattern::Scanlines { every: 0, offset: 0 });
+		assert_eq!(every.count(), 12);
+	}
+
+}
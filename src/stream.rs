@@ -0,0 +1,119 @@
+//! Package with the asynchronous streams over the zips. Requires the `async` feature.
+
+use core::fmt::{Debug, Formatter};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{FusedStream, Stream};
+
+/// [Stream] yielding the items of a zip, or of any of its adapters like the spans or the pixels,
+/// to rasterize cooperatively in asynchronous executors. After yielding `budget` items in a row it
+/// returns [Poll::Pending] once, waking itself right away, so the executor can run other tasks
+/// in the middle of a big triangle.
+///
+/// ```
+/// # use bresenham_zip::{Axis, stream::ZipStream, triangle::Triangle2};
+/// # use futures_core::Stream;
+/// let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+/// let stream = ZipStream::new(triangle.rasterize_along(Axis::Y).unwrap().spans(), 16);
+/// assert_eq!(stream.size_hint(), (101, Some(101)));
+/// // while let Some(span) = stream.next().await { ... }
+/// ```
+pub struct ZipStream<I> {
+	zip: I,
+	budget: usize,
+	left: usize,
+	done: bool,
+}
+
+impl<I: Iterator> ZipStream<I> {
+
+	/// Creates the stream over the zip
+	///
+	/// * `zip` - Iterator yielding the items of the stream
+	/// * `budget` - Number of items yielded before giving back the control to the executor. A
+	///   budget of zero is treated as one.
+	///
+	pub fn new(zip: I, budget: usize) -> Self {
+		let budget = budget.max(1);
+		Self { zip, budget, left: budget, done: false }
+	}
+
+	/// Returns the wrapped zip, with the items not yielded yet
+	pub fn into_inner(self) -> I {
+		self.zip
+	}
+
+}
+
+impl<I: Iterator + Unpin> Stream for ZipStream<I> {
+	type Item = I::Item;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+		if this.done {
+			return Poll::Ready(None);
+		}
+		if this.left == 0 {
+			this.left = this.budget;
+			cx.waker().wake_by_ref();
+			return Poll::Pending;
+		}
+		this.left -= 1;
+		let item = this.zip.next();
+		this.done = item.is_none();
+		Poll::Ready(item)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.done { (0, Some(0)) } else { self.zip.size_hint() }
+	}
+}
+
+impl<I: Iterator + Unpin> FusedStream for ZipStream<I> {
+	fn is_terminated(&self) -> bool {
+		self.done
+	}
+}
+
+impl<I: Debug> Debug for ZipStream<I> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "ZipStream [ {:?} ] with budget {}", self.zip, self.budget)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use core::pin::Pin;
+	use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+	use futures_core::{FusedStream, Stream};
+	use crate::zip::BresenhamZip;
+	use super::ZipStream;
+
+	static VTABLE: RawWakerVTable = RawWakerVTable::new(
+		|_| RawWaker::new(core::ptr::null(), &VTABLE),
+		|_| (),
+		|_| (),
+		|_| (),
+	);
+
+	#[test]
+	fn budget() {
+		let zip = BresenhamZip::new((50, 50), (0, 100), (100, 100), 1);
+		let mut stream = ZipStream::new(zip.clone(), 20);
+		let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+		let mut cx = Context::from_waker(&waker);
+		let (mut pairs, mut pending) = (zip, 0);
+		loop {
+			match Pin::new(&mut stream).poll_next(&mut cx) {
+				Poll::Ready(Some(pair)) => assert_eq!(pairs.next(), Some(pair)),
+				Poll::Ready(None) => break,
+				Poll::Pending => pending += 1,
+			}
+		}
+		assert_eq!((pairs.next(), pending), (None, 2));
+		assert!(stream.is_terminated());
+		assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None));
+	}
+
+}
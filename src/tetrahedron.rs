@@ -0,0 +1,229 @@
+//! Package with the logic to voxelize solid tetrahedra
+
+use core::fmt::{Debug, Formatter};
+use core::ops::RangeInclusive;
+use crate::span::SpanIterator;
+use crate::util::{bounds, point_at, project, wide, Point};
+use crate::zip::TopLeftZip;
+use crate::{Axis, Point2, Point3, SignedNum};
+
+/// Pairs of vertices joined by the six edges of a tetrahedron
+const EDGES: [(usize, usize); 6] = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+
+/// Solid tetrahedron defined by four arbitrary vertices, the cell of the tetrahedral meshes.
+///
+/// It's voxelized slab by slab along an axis: each slab is the plane section of the tetrahedron,
+/// a triangle or a quad split into two triangles, rasterized with the top-left fill rule of the
+/// [TopLeftZip]. The points where the edges cross the slabs are computed the same way whatever
+/// tetrahedron they belong to, so the tetrahedra of a mesh sharing a face are watertight in each
+/// slab.
+///
+/// ```
+/// # use bresenham_zip::{Axis, tetrahedron::Tetrahedron};
+/// let tetrahedron = Tetrahedron::new((0, 0, 0), (8, 0, 0), (0, 8, 0), (0, 0, 8));
+/// let mut voxels = tetrahedron.voxels(Axis::Z);
+/// assert_eq!(voxels.next(), Some((0, 0, 0)));
+/// assert!(voxels.all(|(x, y, z)| x + y + z <= 8));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tetrahedron<T> {
+	vertices: [Point3<T>; 4],
+}
+
+impl<T: SignedNum> Tetrahedron<T> {
+
+	/// Creates the tetrahedron with the given vertices, in any order
+	pub fn new(a: Point3<T>, b: Point3<T>, c: Point3<T>, d: Point3<T>) -> Self {
+		Self { vertices: [a, b, c, d] }
+	}
+
+	/// Returns the four vertices of the tetrahedron
+	pub fn vertices(&self) -> [Point3<T>; 4] {
+		self.vertices
+	}
+
+	/// Returns an iterator over the voxels inside the tetrahedron, slab by slab along the axis,
+	/// see [TetrahedronVoxels]
+	///
+	/// * `axis` - Axis crossing the slabs
+	///
+	pub fn voxels(&self, axis: Axis) -> TetrahedronVoxels<T> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => 2,
+		};
+		let (min, max) = bounds(&self.vertices);
+		TetrahedronVoxels {
+			tetrahedron: *self,
+			axis,
+			slab: min.nth(axis),
+			last: max.nth(axis),
+			spans: None,
+			pending: None,
+			row: T::zero(),
+			columns: T::one()..=T::zero(),
+		}
+	}
+
+	/// Returns the zips of the section of the tetrahedron in the slab, one for a triangle and two
+	/// for a quad, walking the two axes other than the given one in their order
+	fn section(&self, axis: u8, value: T) -> [Option<TopLeftZip<T>>; 2] {
+		let (mut points, mut count) = ([(T::zero(), T::zero()); 4], 0);
+		let mut push = |point: Point3<T>| {
+			let point = project(point, axis);
+			if count < points.len() && !points[..count].contains(&point) {
+				points[count] = point;
+				count += 1;
+			}
+		};
+		for (i, j) in EDGES {
+			// sorted, so the edges shared by several tetrahedra are always split the same way
+			let (a, b) = sort(self.vertices[i], self.vertices[j]);
+			let (from, to) = (a.nth(axis), b.nth(axis));
+			if from == to && from == value {
+				push(a);
+				push(b);
+			} else if from.min(to) <= value && value <= from.max(to) && from != to {
+				push(point_at(a, b, axis, value));
+			}
+		}
+		let zip = |a, b, c| Some(TopLeftZip::new(a, b, c, 1));
+		match (count, points) {
+			(3, [a, b, c, _]) => [zip(a, b, c), None],
+			(4, [a, b, c, d]) => {
+				// the diagonals of the quad cross, the ends of each one at both sides of the other
+				let diagonal = [(b, c, d), (c, b, d), (d, b, c)].into_iter().position(|(e, f, g)| {
+					side(a, e, f) * side(a, e, g) < 0 && side(f, g, a) * side(f, g, e) < 0
+				});
+				match diagonal {
+					Some(0) => [zip(a, b, c), zip(a, b, d)],
+					Some(1) => [zip(a, c, b), zip(a, c, d)],
+					Some(_) => [zip(a, d, b), zip(a, d, c)],
+					// a corner inside the triangle of the others, in the flat tetrahedra
+					None => {
+						let triangles = [[a, b, c], [a, b, d], [a, c, d], [b, c, d]];
+						let largest = triangles.into_iter()
+							.max_by_key(|[a, b, c]| (wide(b.0 - a.0) * wide(c.1 - a.1)
+								- wide(b.1 - a.1) * wide(c.0 - a.0)).abs());
+						[largest.and_then(|[a, b, c]| zip(a, b, c)), None]
+					}
+				}
+			}
+			_ => [None, None],
+		}
+	}
+
+}
+
+/// Returns the points sorted by their coordinates
+fn sort<T: SignedNum>(a: Point3<T>, b: Point3<T>) -> (Point3<T>, Point3<T>) {
+	if b < a { (b, a) } else { (a, b) }
+}
+
+/// Returns the side of the line `from -> to` where the point is, as the sign of the turn
+fn side<T: SignedNum>(from: Point2<T>, to: Point2<T>, point: Point2<T>) -> i128 {
+	let (dx, dy) = (wide(to.0) - wide(from.0), wide(to.1) - wide(from.1));
+	(dx * (wide(point.1) - wide(from.1)) - dy * (wide(point.0) - wide(from.0))).signum()
+}
+
+/// Iterator over the voxels inside a [Tetrahedron], slab by slab along an axis from its lowest
+/// value to the highest one. Inside each slab, the voxels are yielded row by row along the last
+/// of the other two axes.
+///
+/// The centers of the voxels lying on the faces of the tetrahedron are included if they are on a
+/// top or left edge of the section, see [TopLeftZip]; so the slabs where the tetrahedron is just
+/// a point or a segment are empty.
+#[derive(Clone, PartialEq)]
+pub struct TetrahedronVoxels<T> {
+	tetrahedron: Tetrahedron<T>,
+	axis: u8,
+	slab: T,
+	last: T,
+	spans: Option<SpanIterator<TopLeftZip<T>>>,
+	pending: Option<TopLeftZip<T>>,
+	row: T,
+	columns: RangeInclusive<T>,
+}
+
+impl<T: SignedNum> Iterator for TetrahedronVoxels<T> {
+	type Item = Point3<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if self.columns.start() <= self.columns.end() {
+				let column = *self.columns.start();
+				self.columns = column + T::one()..=*self.columns.end();
+				return Some(match self.axis {
+					0 => (self.slab - T::one(), column, self.row),
+					1 => (column, self.slab - T::one(), self.row),
+					_ => (column, self.row, self.slab - T::one()),
+				});
+			}
+			if let Some(span) = self.spans.as_mut().and_then(Iterator::next) {
+				(self.row, self.columns) = (span.axis_value, span.range);
+				continue;
+			}
+			if let Some(zip) = self.pending.take() {
+				self.spans = Some(zip.spans());
+				continue;
+			}
+			if self.slab > self.last {
+				return None;
+			}
+			let [first, second] = self.tetrahedron.section(self.axis, self.slab);
+			(self.spans, self.pending) = (first.map(TopLeftZip::spans), second);
+			self.slab += T::one();
+		}
+	}
+}
+
+impl<T: SignedNum> Debug for TetrahedronVoxels<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "TetrahedronVoxels [ {:?} ]. Next slab: {:?}", self.tetrahedron, self.slab)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Axis;
+	use super::Tetrahedron;
+
+	#[test]
+	fn cube() {
+		// the six tetrahedra around the diagonal of the cube, one per order of the axes
+		let n = 12;
+		let corner = |axes: &[usize]| {
+			let mut point = [0; 3];
+			axes.iter().for_each(|axis| point[*axis] = n);
+			(point[0], point[1], point[2])
+		};
+		let orders = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+		for axis in [Axis::X, Axis::Y, Axis::Z] {
+			let mut covered = [[[0; 13]; 13]; 13];
+			for order in orders {
+				let tetrahedron = Tetrahedron::new(
+					corner(&[]), corner(&order[..1]), corner(&order[..2]), corner(&order));
+				for (x, y, z) in tetrahedron.voxels(axis) {
+					covered[x as usize][y as usize][z as usize] += 1;
+				}
+			}
+			assert!(covered.iter().flatten().flatten().all(|count| *count <= 1));
+			assert_eq!(covered.iter().flatten().flatten().sum::<i32>(), (n + 1) * n * n);
+		}
+	}
+
+	#[test]
+	fn slabs() {
+		let tetrahedron = Tetrahedron::new((0, 0, 0), (20, 0, 0), (0, 20, 0), (0, 0, 20));
+		let mut z = 0;
+		for (x, y, voxel_z) in tetrahedron.voxels(Axis::Z) {
+			assert!(voxel_z >= z && x >= 0 && y >= 0 && x + y + voxel_z <= 20);
+			z = voxel_z;
+		}
+		let flat = Tetrahedron::new((0, 0, 5), (20, 0, 5), (0, 20, 5), (7, 7, 5));
+		assert_eq!(flat.voxels(Axis::Z).count(), 20 * 21 / 2);
+		assert_eq!(flat.voxels(Axis::X).count(), 0);
+	}
+
+}
@@ -0,0 +1,160 @@
+//! Package with the coverage masks of the two-dimensional triangles
+
+use core::fmt::{Debug, Formatter};
+use crate::triangle::TriangleZip;
+use crate::util::floor_div;
+use crate::SignedNum;
+
+/// Side of the tiles, in pixels
+const SIDE: i32 = 8;
+
+/// Coverage of a tile of 8x8 pixels: its coordinates in tiles, the pixel `(8 * x, 8 * y)` being
+/// its first one, and the mask of its pixels covered by the triangle. The bit `8 * row + column`
+/// of the mask is set if the pixel in that row and column of the tile is covered.
+pub type TileMask<T> = (T, T, u64);
+
+/// Iterator over the [TileMask]s of the tiles of 8x8 pixels touched by a triangle, row of tiles
+/// by row of tiles. The masks cover the same pixels as the triangle rasterized along
+/// [Axis::Y](crate::Axis::Y), and they can be blitted or combined with a stencil a whole tile at a
+/// time.
+///
+/// ```
+/// # use bresenham_zip::triangle::Triangle2;
+/// let triangle = Triangle2::new((0, 0), (7, 0), (0, 7));
+/// let mut tiles = triangle.tiles();
+/// // each row covers one pixel less than the previous one
+/// assert_eq!(tiles.next(), Some((0, 0, 0x0103070f1f3f7fff)));
+/// assert_eq!(tiles.next(), None);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct TileMasks<T> {
+	zip: TriangleZip<T>,
+	spans: [Option<(T, T)>; SIDE as usize],
+	band: T,
+	last_band: T,
+	tile: T,
+	last_tile: T,
+}
+
+impl<T: SignedNum> TileMasks<T> {
+
+	/// Creates the iterator over the masks of the triangle rasterized by the zip, which must not
+	/// be iterated yet
+	pub(crate) fn new(zip: TriangleZip<T>, min_y: T, max_y: T) -> Self {
+		let side = side();
+		Self {
+			zip,
+			spans: [None; SIDE as usize],
+			band: floor_div(min_y, side),
+			last_band: floor_div(max_y, side),
+			tile: T::one(),
+			last_tile: T::zero(),
+		}
+	}
+
+	/// Loads the spans of the next row of tiles, returning `false` if there are no more
+	fn load_band(&mut self) -> bool {
+		if self.band > self.last_band {
+			return false;
+		}
+		let side = side();
+		let (mut min, mut max) = (None, None);
+		let mut y = self.band * side;
+		for span in &mut self.spans {
+			*span = self.zip.span_at(y).map(|(a, b)| (a.0.min(b.0), a.0.max(b.0)));
+			if let Some((start, end)) = *span {
+				min = Some(min.map_or(start, |min: T| min.min(start)));
+				max = Some(max.map_or(end, |max: T| max.max(end)));
+			}
+			y += T::one();
+		}
+		(self.tile, self.last_tile) = match (min, max) {
+			(Some(min), Some(max)) => (floor_div(min, side), floor_div(max, side)),
+			_ => (T::one(), T::zero()),
+		};
+		self.band += T::one();
+		true
+	}
+
+	/// Returns the mask of the current tile in the loaded row of tiles
+	fn mask(&self) -> u64 {
+		let side = side();
+		let (first, last) = (self.tile * side, self.tile * side + side - T::one());
+		let mut mask = 0;
+		for (row, span) in self.spans.iter().enumerate() {
+			let Some((start, end)) = *span else { continue };
+			let (start, end) = (start.max(first) - first, end.min(last) - first);
+			if start > end {
+				continue;
+			}
+			if let (Some(start), Some(end)) = (start.to_usize(), end.to_usize()) {
+				let bits = (1u64 << (end + 1)) - (1 << start);
+				mask |= bits << (SIDE as usize * row);
+			}
+		}
+		mask
+	}
+
+}
+
+/// Side of the tiles as the type of the coordinates
+fn side<T: SignedNum>() -> T {
+	T::from(SIDE).unwrap_or_else(T::one)
+}
+
+impl<T: SignedNum> Iterator for TileMasks<T> {
+	type Item = TileMask<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			while self.tile <= self.last_tile {
+				let (tile, mask) = (self.tile, self.mask());
+				self.tile += T::one();
+				if mask != 0 {
+					return Some((tile, self.band - T::one(), mask));
+				}
+			}
+			if !self.load_band() {
+				return None;
+			}
+		}
+	}
+}
+
+impl<T: SignedNum> Debug for TileMasks<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "TileMasks [ {:?} ]. Row: {:?}", self.zip, self.band)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::triangle::Triangle2;
+	use crate::Axis;
+
+	#[test]
+	fn pixels() {
+		let triangle = Triangle2::new((-9, 3), (30, -12), (11, 25));
+		let mut covered = [[false; 64]; 64];
+		for (x, y, mask) in triangle.tiles() {
+			for bit in (0..64).filter(|bit| mask & (1 << bit) != 0) {
+				let (x, y) = (x * 8 + bit % 8, y * 8 + bit / 8);
+				covered[(y + 32) as usize][(x + 32) as usize] = true;
+			}
+		}
+		let mut expected = [[false; 64]; 64];
+		for (x, y) in triangle.rasterize_along(Axis::Y).unwrap().pixels() {
+			expected[(y + 32) as usize][(x + 32) as usize] = true;
+		}
+		assert_eq!(covered, expected);
+	}
+
+	#[test]
+	fn empty_tiles() {
+		// the thin diagonal skips the tiles of its bounding box far from the diagonal
+		let triangle = Triangle2::new((0, 0), (1, 0), (23, 23));
+		let tiles = triangle.tiles().map(|(x, y, _)| (x, y));
+		assert!(tiles.eq([(0, 0), (1, 1), (2, 2)]));
+	}
+
+}
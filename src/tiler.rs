@@ -0,0 +1,244 @@
+//! Package with the binning of the two-dimensional triangles into screen tiles
+
+use core::fmt::{Debug, Formatter};
+use crate::clip::{Aabb, ClipRect};
+use crate::mesh::Mesh;
+use crate::triangle::{Triangle2, TriangleZip};
+use crate::util::floor_div;
+use crate::{Axis, Point2, SignedNum};
+
+/// Zip of a triangle clipped to a tile, only walking the rows of the tile
+pub type TileZip<T> = ClipRect<TriangleZip<T>, Point2<T>>;
+
+/// Splitter of the screen into tiles of a fixed size, the tile `(x, y)` covering the pixels from
+/// `(x * width, y * height)` to `(x * width + width - 1, y * height + height - 1)`. It bins the
+/// triangles into the tiles they cover, giving an independent [TileZip] for each one, so every
+/// tile can be rendered on its own core.
+///
+/// ```
+/// # use bresenham_zip::{tiler::Tiler, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (63, 0), (0, 63));
+/// let mut pixels = 0;
+/// for ((x, y), zip) in Tiler::new(32, 32).bin(&triangle) {
+///   for (a, b) in zip {
+///     assert!((x * 32..x * 32 + 32).contains(&a.0) && (y * 32..y * 32 + 32).contains(&a.1));
+///     pixels += b.0 - a.0 + 1;
+///   }
+/// }
+/// assert_eq!(pixels, 64 * 65 / 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tiler<T> {
+	width: T,
+	height: T,
+}
+
+impl<T: SignedNum> Tiler<T> {
+
+	/// Creates the splitter of the screen into tiles of the given size
+	///
+	/// * `width`, `height` - Size of the tiles in pixels, both positive
+	///
+	pub fn new(width: T, height: T) -> Self {
+		Self { width, height }
+	}
+
+	/// Returns an iterator over the tiles covered by the triangle with its zip clipped to them,
+	/// see [TriangleTiles]
+	///
+	/// * `triangle` - Triangle to bin
+	///
+	pub fn bin(&self, triangle: &Triangle2<T>) -> TriangleTiles<T> {
+		let (min, max) = triangle.bounding_box();
+		match triangle.rasterize_along(Axis::Y) {
+			Some(zip) => TriangleTiles::new(zip, *self, min.1, max.1),
+			// only the axis Z is rejected
+			None => unreachable!(),
+		}
+	}
+
+	/// Returns an iterator over the tiles covered by each triangle of the mesh, see [MeshTiles]
+	///
+	/// * `mesh` - Mesh to bin
+	///
+	pub fn bin_mesh<'a>(&self, mesh: &Mesh<'a, T>) -> MeshTiles<'a, T> {
+		MeshTiles { mesh: *mesh, tiler: *self, index: 0, current: None }
+	}
+
+	/// Returns the lowest and highest pixels of the tile
+	fn rect(&self, (x, y): Point2<T>) -> Aabb<Point2<T>> {
+		let min = (x * self.width, y * self.height);
+		(min, (min.0 + self.width - T::one(), min.1 + self.height - T::one()))
+	}
+
+}
+
+/// Iterator over the tiles of a [Tiler] covered by a triangle, row of tiles by row of tiles. Each
+/// tile is yielded with its coordinates in tiles and the [TileZip] of the triangle clipped to it,
+/// which yields at least one pair. The zips of all the tiles cover the same pixels as the
+/// triangle rasterized along [Axis::Y].
+#[derive(Clone, PartialEq)]
+pub struct TriangleTiles<T> {
+	zip: TriangleZip<T>,
+	tiler: Tiler<T>,
+	band: T,
+	last_band: T,
+	tile: T,
+	last_tile: T,
+}
+
+impl<T: SignedNum> TriangleTiles<T> {
+
+	fn new(zip: TriangleZip<T>, tiler: Tiler<T>, min_y: T, max_y: T) -> Self {
+		Self {
+			zip,
+			band: floor_div(min_y, tiler.height) - T::one(),
+			last_band: floor_div(max_y, tiler.height),
+			tile: T::one(),
+			last_tile: T::zero(),
+			tiler,
+		}
+	}
+
+	/// Returns the lowest and highest columns of the triangle in each row of the tile, if any
+	fn rows(&self, (min, max): Aabb<Point2<T>>) -> impl Iterator<Item = (T, T)> + '_ {
+		let mut y = min.1;
+		core::iter::from_fn(move || {
+			let row = (y <= max.1).then_some(y)?;
+			y += T::one();
+			Some(self.zip.span_at(row))
+		}).flatten().map(|(a, b)| (a.0.min(b.0), a.0.max(b.0)))
+	}
+
+	/// Moves to the next row of tiles, returning `false` if there are no more
+	fn next_band(&mut self) -> bool {
+		if self.band >= self.last_band {
+			return false;
+		}
+		self.band += T::one();
+		let extent = self.rows(self.tiler.rect((T::zero(), self.band)))
+			.reduce(|(min, max), (start, end)| (min.min(start), max.max(end)));
+		(self.tile, self.last_tile) = match extent {
+			Some((min, max)) => {
+				(floor_div(min, self.tiler.width), floor_div(max, self.tiler.width))
+			}
+			None => (T::one(), T::zero()),
+		};
+		true
+	}
+
+}
+
+impl<T: SignedNum> Iterator for TriangleTiles<T> {
+	type Item = (Point2<T>, TileZip<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			while self.tile <= self.last_tile {
+				let tile = (self.tile, self.band);
+				self.tile += T::one();
+				let rect = self.tiler.rect(tile);
+				let (min, max) = rect;
+				if self.rows(rect).any(|(start, end)| start <= max.0 && end >= min.0) {
+					let mut zip = self.zip.clone();
+					zip.clamp(min.1, max.1);
+					return Some((tile, zip.clip_rect(rect)));
+				}
+			}
+			if !self.next_band() {
+				return None;
+			}
+		}
+	}
+}
+
+impl<T: SignedNum> Debug for TriangleTiles<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "TriangleTiles [ {:?} ]. Row: {:?}", self.zip, self.band)
+	}
+}
+
+/// Iterator over the tiles of a [Tiler] covered by each triangle of a [Mesh], one triangle after
+/// the other. Each tile is yielded with the index of its triangle in the mesh, so they can be
+/// gathered in a list per tile, see [TriangleTiles].
+///
+/// # Panics
+/// If any of the indices of the mesh is out of the bounds of its vertices.
+#[derive(Clone)]
+pub struct MeshTiles<'a, T> {
+	mesh: Mesh<'a, T>,
+	tiler: Tiler<T>,
+	index: usize,
+	current: Option<TriangleTiles<T>>,
+}
+
+impl<T: SignedNum> Iterator for MeshTiles<'_, T> {
+	type Item = (usize, Point2<T>, TileZip<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some((tile, zip)) = self.current.as_mut().and_then(Iterator::next) {
+				return Some((self.index - 1, tile, zip));
+			}
+			let triangle = self.mesh.triangle(self.index)?;
+			self.current = Some(self.tiler.bin(&triangle));
+			self.index += 1;
+		}
+	}
+}
+
+impl<T: SignedNum> Debug for MeshTiles<'_, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "MeshTiles [ {:?} ]. Triangle: {}", self.current, self.index)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mesh::Mesh;
+	use crate::triangle::Triangle2;
+	use crate::Axis;
+	use super::Tiler;
+
+	#[test]
+	fn pixels() {
+		let triangle = Triangle2::new((-9, 3), (30, -12), (11, 25));
+		for (width, height) in [(8, 8), (5, 3), (1, 40)] {
+			let mut covered = [[0; 64]; 64];
+			for ((x, y), zip) in Tiler::new(width, height).bin(&triangle) {
+				let (left, top) = (x * width, y * height);
+				let mut pixels = zip.pixels().peekable();
+				assert!(pixels.peek().is_some());
+				for (x, y) in pixels {
+					assert!((left..left + width).contains(&x) && (top..top + height).contains(&y));
+					covered[(y + 32) as usize][(x + 32) as usize] += 1;
+				}
+			}
+			let mut expected = [[0; 64]; 64];
+			for (x, y) in triangle.rasterize_along(Axis::Y).unwrap().pixels() {
+				expected[(y + 32) as usize][(x + 32) as usize] += 1;
+			}
+			assert_eq!(covered, expected);
+		}
+	}
+
+	#[test]
+	fn empty_tiles() {
+		// thin sliver crossing the tiles diagonally, leaving six tiles of its bounding box empty
+		let triangle = Triangle2::new((0, 0), (1, 0), (23, 23));
+		let tiles = Tiler::new(8, 8).bin(&triangle).map(|(tile, _)| tile);
+		assert!(tiles.eq([(0, 0), (1, 1), (2, 2)]));
+	}
+
+	#[test]
+	fn mesh() {
+		let vertices = [(0, 0), (15, 0), (15, 15), (0, 15)];
+		let indices = [[0, 1, 2], [0, 2, 3]];
+		let mut tiles = Tiler::new(8, 8).bin_mesh(&Mesh::new(&vertices, &indices));
+		let pixels = tiles.clone().map(|(_, _, zip)| zip.pixels().count()).sum::<usize>();
+		assert_eq!(pixels, 2 * 16 * 17 / 2);
+		assert_eq!(tiles.next().map(|(index, tile, _)| (index, tile)), Some((0, (0, 0))));
+		assert_eq!(tiles.last().map(|(index, tile, _)| (index, tile)), Some((1, (1, 1))));
+	}
+
+}
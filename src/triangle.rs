@@ -0,0 +1,613 @@
+//! Package with the logic to rasterize any two-dimensional triangle
+
+use core::fmt::{Debug, Formatter};
+use crate::barycentric::BarycentricIterator;
+use crate::clip::{Aabb, ClipRect};
+use crate::depth::DepthZip;
+use crate::gouraud::GouraudZip;
+use crate::fill::FillIterator;
+use crate::line::Bresenham;
+use crate::sink::SpanSink;
+use crate::span::{EnumerateAxis, SpanIterator};
+use crate::tile::TileMasks;
+use crate::util::{bounds, line_length, point_at, sort_by_axis, wide, Point};
+use crate::zip::{BresenhamZip, ConservativeZip, TopLeftZip};
+use crate::{Axis, FloatNum, Point2, Point2Like, SignedNum};
+
+/// Two-dimensional triangle defined by three arbitrary vertices.
+///
+/// Unlike the [BresenhamZip], the vertices don't need to share any value. When iterated, the
+/// triangle is split at its middle vertex into two triangles with a flat edge, and both of them
+/// are zipped one after the other.
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle::Triangle2};
+/// let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+/// for (a, b) in triangle.rasterize_along(Axis::Y).unwrap() {
+///   assert_eq!(a.1, b.1);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle2<T> {
+	pub a: Point2<T>,
+	pub b: Point2<T>,
+	pub c: Point2<T>,
+}
+
+impl<T: SignedNum> Triangle2<T> {
+
+	/// Creates a new triangle with the three given vertices, in any order, of any [Point2Like]
+	/// type like the tuples or the arrays
+	pub fn new<P: Point2Like<T>>(a: P, b: P, c: P) -> Self {
+		Self { a: a.to_tuple(), b: b.to_tuple(), c: c.to_tuple() }
+	}
+
+	/// Returns an iterator over the pairs of points conforming the triangle in the given axis.
+	/// Each axis value between the two extreme vertices is yielded once, from the lowest one.
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	pub fn rasterize_along(&self, axis: Axis) -> Option<TriangleZip<T>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => return None,
+		};
+		let (top, middle, bottom) = sort_by_axis(self.a, self.b, self.c, axis);
+		let other = 1 - axis;
+
+		if top.nth(axis) == bottom.nth(axis) {
+			let (start, _, end) = sort_by_axis(top, middle, bottom, other);
+			return Some(TriangleZip::single(BresenhamZip::new(start, start, end, axis), axis));
+		}
+		if top.nth(axis) == middle.nth(axis) {
+			let zip = BresenhamZip::new(bottom, top, middle, axis).reversed();
+			return Some(TriangleZip::single(zip, axis));
+		}
+		if middle.nth(axis) == bottom.nth(axis) {
+			return Some(TriangleZip::single(BresenhamZip::new(top, middle, bottom, axis), axis));
+		}
+
+		let split = point_at(top, bottom, axis, middle.nth(axis));
+		// the second half is walked from the split, which is yielded by the first one
+		let mut second = BresenhamZip::new(bottom, middle, split, axis).reversed();
+		second.next();
+		Some(TriangleZip {
+			first: BresenhamZip::new(top, middle, split, axis),
+			second: Some(second),
+			split: middle.nth(axis),
+			axis,
+		})
+	}
+
+	/// Returns an iterator over the pairs of points conforming the triangle in the given axis
+	/// with their depth as third coordinate, interpolated from the depth of the vertices, see
+	/// [DepthZip]
+	///
+	/// * `depths` - Depth of the vertices `a`, `b` and `c`, in that order
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	pub fn rasterize_with_depth(&self, depths: [T; 3], axis: Axis)
+		-> Option<DepthZip<TriangleZip<T>>> {
+		Some(DepthZip::new(self.rasterize_along(axis)?, self.vertices(), depths))
+	}
+
+	/// Returns an iterator over the spans of the triangle in the given axis with the colors of
+	/// their ends, interpolated from the colors of the vertices, see [GouraudZip]
+	///
+	/// * `colors` - Colors of the vertices `a`, `b` and `c`, in that order
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	pub fn rasterize_gouraud<F: FloatNum, const N: usize>(&self, colors: [[F; N]; 3], axis: Axis)
+		-> Option<GouraudZip<TriangleZip<T>, T, F, N>> {
+		let zip = self.rasterize_along(axis)?;
+		let axis = zip.axis;
+		Some(GouraudZip::new(zip, self.vertices(), colors, axis))
+	}
+
+	/// Returns an iterator over the first and last pixels of the triangle in each value of the
+	/// given axis following the top-left fill rule, see [TopLeftZip]. Triangles sharing an edge
+	/// rasterized this way never overlap nor leave gaps between them.
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	pub fn rasterize_top_left(&self, axis: Axis) -> Option<TopLeftZip<T>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => return None,
+		};
+		Some(TopLeftZip::new(self.a, self.b, self.c, axis))
+	}
+
+	/// Returns an iterator over the first and last pixels touched by the triangle, even
+	/// partially, in each value of the given axis, see [ConservativeZip]
+	///
+	/// * `axis` - Axis to use in the iteration, returns `None` if it's [Axis::Z]
+	///
+	pub fn rasterize_conservative(&self, axis: Axis) -> Option<ConservativeZip<T>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => return None,
+		};
+		Some(ConservativeZip::new(self.a, self.b, self.c, axis))
+	}
+
+	/// Returns an iterator over the coverage masks of the tiles of 8x8 pixels touched by the
+	/// triangle, see [TileMasks]
+	pub fn tiles(&self) -> TileMasks<T> {
+		let (min, max) = self.bounding_box();
+		match self.rasterize_along(Axis::Y) {
+			Some(zip) => TileMasks::new(zip, min.1, max.1),
+			// only the axis Z is rejected
+			None => unreachable!(),
+		}
+	}
+
+	/// Returns an iterator over the pixels of the three edges of the triangle, see [Outline]
+	pub fn outline(&self) -> Outline<T> {
+		Outline {
+			vertices: self.vertices(),
+			edge: 0,
+			line: Bresenham::new(self.a, self.b),
+		}
+	}
+
+	/// Returns the three vertices of the triangle
+	pub fn vertices(&self) -> [Point2<T>; 3] {
+		[self.a, self.b, self.c]
+	}
+
+	/// Returns the area of the triangle, truncated. It's widened to `i128`, as the area of a
+	/// triangle can't be represented with the type of its coordinates.
+	pub fn area(&self) -> i128 {
+		cross(self.a, self.b, self.c).abs() / 2
+	}
+
+	/// Returns the smallest axis-aligned box containing the triangle as its lowest and highest
+	/// corners
+	pub fn bounding_box(&self) -> (Point2<T>, Point2<T>) {
+		let (a, b, c) = (self.a, self.b, self.c);
+		(
+			(a.0.min(b.0).min(c.0), a.1.min(b.1).min(c.1)),
+			(a.0.max(b.0).max(c.0), a.1.max(b.1).max(c.1)),
+		)
+	}
+
+	/// Checks if the point is one of the pixels of the triangle rasterized along [Axis::Y], the
+	/// same ones yielded by [pixels](TriangleZip::pixels). The pair of points in the row of the
+	/// point is computed with integers from the slopes of the edges, without walking them, so the
+	/// check agrees with the rendering pixel for pixel.
+	///
+	/// ```
+	/// # use bresenham_zip::{Axis, triangle::Triangle2};
+	/// let triangle = Triangle2::new((0, 0), (100, 40), (30, 100));
+	/// let mut pixels = triangle.rasterize_along(Axis::Y).unwrap().pixels();
+	/// assert!(pixels.all(|pixel| triangle.contains(pixel)));
+	/// assert!(!triangle.contains((0, 50)));
+	/// ```
+	pub fn contains(&self, point: Point2<T>) -> bool {
+		match self.rasterize_along(Axis::Y).and_then(|zip| zip.span_at(point.1)) {
+			Some((a, b)) => a.0.min(b.0) <= point.0 && point.0 <= a.0.max(b.0),
+			None => false,
+		}
+	}
+
+}
+
+impl<T: SignedNum> From<[Point2<T>; 3]> for Triangle2<T> {
+	fn from(vertices: [Point2<T>; 3]) -> Self {
+		Self::new(vertices[0], vertices[1], vertices[2])
+	}
+}
+
+/// Z component of the cross product of `o -> a` and `o -> b`, widened so it can't overflow
+fn cross<T: SignedNum>(o: Point2<T>, a: Point2<T>, b: Point2<T>) -> i128 {
+	let delta = |p: Point2<T>| (wide(p.0) - wide(o.0), wide(p.1) - wide(o.1));
+	let (a, b) = (delta(a), delta(b));
+	a.0 * b.1 - a.1 * b.0
+}
+
+/// Iterator over the pixels of the edges of a [Triangle2], going from `a` to `b`, `c` and back to
+/// `a`. Each edge is drawn with Bresenham and the vertices are yielded only once, so it can be
+/// used to draw the wireframe of the triangle.
+///
+/// ```
+/// # use bresenham_zip::triangle::Triangle2;
+/// let triangle = Triangle2::new((0, 0), (4, 0), (0, 4));
+/// assert_eq!(triangle.outline().count(), 12);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Outline<T> {
+	vertices: [Point2<T>; 3],
+	edge: usize,
+	line: Bresenham<T>,
+}
+
+impl<T: SignedNum> Iterator for Outline<T> {
+	type Item = Point2<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			// the ending point of each edge is the starting one of the next
+			let end = self.vertices[(self.edge + 1) % 3];
+			match self.line.next() {
+				Some(point) if point != end => return Some(point),
+				_ if self.edge == 2 => return None,
+				_ => {
+					self.edge += 1;
+					self.line = Bresenham::new(end, self.vertices[(self.edge + 1) % 3]);
+				}
+			}
+		}
+	}
+}
+
+impl<T: SignedNum> Debug for Outline<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Outline [ {:?}, {:?}, {:?} ]. Edge: {}",
+			self.vertices[0], self.vertices[1], self.vertices[2], self.edge)
+	}
+}
+
+/// Iterator over the pairs of points of a [Triangle2]. It chains the zips of the two halves of the
+/// triangle, yielding the shared axis value only once. The second half is walked from the split,
+/// so the axis values are yielded in order, from the lowest one.
+#[derive(Clone, PartialEq)]
+pub struct TriangleZip<T> {
+	first: BresenhamZip<T>,
+	second: Option<BresenhamZip<T>>,
+	split: T,
+	axis: u8,
+}
+
+impl<T: SignedNum> TriangleZip<T> {
+
+	/// Turns the zip into an iterator over every point of the triangle
+	pub fn pixels(self) -> FillIterator<Self, Point2<T>> {
+		FillIterator::new(self)
+	}
+
+	/// Turns the zip into an iterator over the [Span](crate::span::Span)s of the triangle
+	pub fn spans(self) -> SpanIterator<Self> {
+		let axis = self.axis;
+		SpanIterator::new(self, axis)
+	}
+
+	/// Turns the zip into an iterator over the spans of the triangle along with the position of
+	/// their axis value counted from the lowest one, see [EnumerateAxis]
+	pub fn enumerate_axis(self) -> EnumerateAxis<Self, T> {
+		let axis = self.axis;
+		let origin = self.bounding_box().map_or(T::zero(), |(min, _)| min.nth(axis));
+		EnumerateAxis::new(self.spans(), origin)
+	}
+
+	/// Turns the zip into an iterator yielding the [Barycentric](crate::barycentric::Barycentric)
+	/// coordinates of each point along with it relative to the vertices of the triangle, see
+	/// [BarycentricIterator]
+	pub fn with_barycentric(self) -> BarycentricIterator<Self, Point2<T>> {
+		let vertices = self.vertices();
+		BarycentricIterator::new(self, vertices)
+	}
+
+	/// Fills the spans of the triangle in the sink with the value, see
+	/// [rasterize_into](SpanIterator::rasterize_into)
+	pub fn rasterize_into<V: Copy, S: SpanSink<T, V>>(self, sink: &mut S, value: V) {
+		self.spans().rasterize_into(sink, value);
+	}
+
+	/// Collects the [Span](crate::span::Span)s of the triangle. Requires the `alloc` feature.
+	#[cfg(feature = "alloc")]
+	pub fn collect_spans(self) -> alloc::vec::Vec<crate::span::Span<T>> {
+		self.spans().collect()
+	}
+
+	/// Collects every point of the triangle. Requires the `alloc` feature.
+	#[cfg(feature = "alloc")]
+	pub fn collect_pixels(self) -> alloc::vec::Vec<Point2<T>> {
+		let mut pixels = alloc::vec::Vec::with_capacity(self.pixel_count());
+		pixels.extend(self.pixels());
+		pixels
+	}
+
+	/// Clips each pair of points to the rectangle or box defined by its lowest and highest
+	/// corners, see [ClipRect]
+	pub fn clip_rect(self, aabb: Aabb<Point2<T>>) -> ClipRect<Self, Point2<T>> {
+		ClipRect::new(self, aabb)
+	}
+
+	/// Returns the lowest and highest corners of the smallest axis-aligned box containing the
+	/// pairs left to yield, or `None` if the zip is exhausted. The zip isn't consumed.
+	pub fn bounding_box(&self) -> Option<Aabb<Point2<T>>> {
+		let second = self.second.as_ref().and_then(BresenhamZip::bounding_box);
+		match (self.first.bounding_box(), second) {
+			(Some(first), Some(second)) => Some(bounds(&[first.0, first.1, second.0, second.1])),
+			(first, second) => first.or(second),
+		}
+	}
+
+	/// Returns the number of points of the triangle left to yield, so the buffers to fill can be
+	/// allocated beforehand. The zip isn't consumed.
+	pub fn pixel_count(&self) -> usize {
+		self.clone().map(|(a, b)| line_length(&a, &b)).sum()
+	}
+
+	/// Returns the vertices of the triangle, taken from the zips of both halves
+	fn vertices(&self) -> [Point2<T>; 3] {
+		let [top, middle, split] = self.first.vertices();
+		[top, middle, self.second.as_ref().map_or(split, |second| second.vertices()[0])]
+	}
+
+	/// Returns the pair of points yielded in the axis value `value` by a zip not iterated yet
+	pub(crate) fn span_at(&self, value: T) -> Option<(Point2<T>, Point2<T>)> {
+		self.first.span_at(value).or_else(|| self.second.as_ref()?.span_at(value))
+	}
+
+	/// Skips the pairs out of the axis values between `min` and `max`. When the range leaves out
+	/// the split, the half still inside is kept as the only zip, so the vertices of the triangle
+	/// are lost and the zip must not be used to interpolate over it.
+	pub(crate) fn clamp(&mut self, min: T, max: T) {
+		self.first.clamp(min, max);
+		let Some(mut second) = self.second.take() else {
+			return;
+		};
+		second.clamp(min, max);
+		if (min..=max).contains(&self.split) {
+			self.second = Some(second);
+		} else if self.first.len() == 0 {
+			self.first = second;
+		}
+	}
+
+	/// Returns the pair yielded `n` positions ahead without iterating, or `None` if there are
+	/// fewer pairs left
+	#[cfg(feature = "rayon")]
+	pub(crate) fn pair_at(&self, n: usize) -> Option<(Point2<T>, Point2<T>)> {
+		if n >= self.len() {
+			return None;
+		}
+		let first = self.first.len();
+		let value = if n < first {
+			self.first.value_at(n)
+		} else {
+			self.second.as_ref()?.value_at(n - first)
+		};
+		self.span_at(value)
+	}
+
+	fn single(zip: BresenhamZip<T>, axis: u8) -> Self {
+		Self {
+			first: zip,
+			second: None,
+			split: T::zero(),
+			axis,
+		}
+	}
+
+}
+
+impl<T: SignedNum> Iterator for TriangleZip<T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(pair) = self.first.next() {
+			return Some(pair);
+		}
+		self.second.as_mut()?.next()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let second = self.second.as_ref().map_or(0, ExactSizeIterator::len);
+		let remaining = self.first.len() + second;
+		(remaining, Some(remaining))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let first = self.first.len();
+		if n < first {
+			return self.first.nth(n);
+		}
+		self.first.nth(first);
+		self.second.as_mut()?.nth(n - first)
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.nth(self.len().checked_sub(1)?)
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for TriangleZip<T> {}
+
+impl<T: SignedNum> Debug for TriangleZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "TriangleZip [ {:?}, {:?} ]", self.first, self.second)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Axis;
+	use super::Triangle2;
+
+	macro_rules! covers {
+	    ($a:tt, $b:tt, $c:tt, $axis:expr, $i:tt, $from:expr, $to:expr) => {
+		    let mut values = [0; ($to - $from + 1) as usize];
+		    let mut previous = $from - 1;
+		    for (a, b) in Triangle2::new($a, $b, $c).rasterize_along($axis).unwrap() {
+			    assert_eq!(a.$i, b.$i);
+			    values[(a.$i - $from) as usize] += 1;
+			    assert_eq!(a.$i, previous + 1);
+			    previous = a.$i;
+		    }
+		    assert!(values.iter().all(|count| *count == 1));
+	    };
+	}
+
+	#[test]
+	fn flat() {
+		let triangle = Triangle2::new((100, 100), (50, 50), (0, 100));
+		let pairs = triangle.rasterize_along(Axis::Y).unwrap();
+		assert_eq!(pairs.last(), Some(((100, 100), (0, 100))));
+		covers!((100, 100), (50, 50), (0, 100), Axis::Y, 1, 50, 100);
+		covers!((50, 50), (100, 100), (100, 0), Axis::X, 0, 50, 100);
+		covers!((0, 50), (100, 50), (30, 100), Axis::Y, 1, 50, 100);
+	}
+
+	#[test]
+	fn split() {
+		covers!((0, 0), (100, 40), (30, 100), Axis::Y, 1, 0, 100);
+		covers!((0, 0), (100, 40), (30, 100), Axis::X, 0, 0, 100);
+		covers!((-20, 70), (5, -30), (60, 10), Axis::Y, 1, -30, 70);
+	}
+
+	#[test]
+	fn split_point() {
+		let triangle = Triangle2::new((0, 0), (100, 50), (0, 100));
+		let pairs = triangle.rasterize_along(Axis::Y).unwrap();
+		for (a, b) in pairs.filter(|(a, _)| a.1 == 50) {
+			assert_eq!(a, (100, 50));
+			assert_eq!(b, (0, 50));
+		}
+	}
+
+	#[test]
+	fn degenerate() {
+		let triangle = Triangle2::new((20, 0), (0, 0), (50, 0));
+		let mut pairs = triangle.rasterize_along(Axis::Y).unwrap();
+		assert_eq!(pairs.next(), Some(((0, 0), (50, 0))));
+		assert_eq!(pairs.next(), None);
+	}
+
+	#[test]
+	fn large() {
+		let triangle = Triangle2::new((0, 0), (0, 50000), (100000, 100000));
+		let mut pairs = triangle.rasterize_along(Axis::Y).unwrap();
+		assert_eq!(pairs.len(), 100001);
+		assert_eq!(pairs.nth(50000), Some(((0, 50000), (50000, 50000))));
+	}
+
+	#[test]
+	fn exact_size() {
+		let mut pairs = Triangle2::new((0, 0), (100, 40), (30, 100)).rasterize_along(Axis::Y).unwrap();
+		assert_eq!(pairs.len(), 101);
+		for remaining in (0..101).rev() {
+			pairs.next();
+			assert_eq!(pairs.len(), remaining);
+		}
+		assert_eq!(pairs.next(), None);
+		let spans = Triangle2::new((0, 0), (10, 0), (0, 10)).rasterize_along(Axis::Y).unwrap().spans();
+		assert_eq!(spans.len(), 11);
+	}
+
+	#[test]
+	fn nth() {
+		let pairs = Triangle2::new((0, 0), (100, 40), (30, 100)).rasterize_along(Axis::Y).unwrap();
+		for n in [0, 39, 40, 41, 100, 101] {
+			let mut stepped = pairs.clone();
+			for _ in 0..n {
+				stepped.next();
+			}
+			assert_eq!(pairs.clone().nth(n), stepped.next());
+		}
+		assert_eq!(pairs.clone().count(), 101);
+		assert_eq!(pairs.last().map(|(a, _)| a.1), Some(100));
+	}
+
+	#[test]
+	fn cloned() {
+		let pairs = Triangle2::new((0, 0), (100, 40), (30, 100)).rasterize_along(Axis::Y).unwrap();
+		assert!(pairs.clone().pixels().eq(pairs.pixels()));
+	}
+
+	#[test]
+	fn area() {
+		assert_eq!(Triangle2::new((0, 0), (10, 0), (0, 10)).area(), 50);
+		assert_eq!(Triangle2::new((0, 0), (0, 10), (10, 0)).area(), 50);
+		assert_eq!(Triangle2::new((0, 0), (5, 5), (10, 10)).area(), 0);
+		assert_eq!(Triangle2::new((0, 0), (100000, 0), (0, 100000)).area(), 5000000000);
+		assert_eq!(Triangle2::<i16>::new((0, 0), (-200, 200), (200, 200)).area(), 40000);
+	}
+
+	#[test]
+	fn bounding_box() {
+		let triangle = Triangle2::new((-20, 70), (5, -30), (60, 10));
+		assert_eq!(triangle.bounding_box(), ((-20, -30), (60, 70)));
+		let mut pairs = triangle.rasterize_along(Axis::Y).unwrap();
+		assert_eq!(pairs.bounding_box(), Some(triangle.bounding_box()));
+		assert_eq!(pairs.pixel_count(), pairs.clone().pixels().count());
+		pairs.nth(60);
+		assert_eq!(pairs.bounding_box(), Some(((-20, 31), (32, 70))));
+		assert_eq!(pairs.pixel_count(), pairs.clone().pixels().count());
+	}
+
+	#[test]
+	fn contains() {
+		let triangle = Triangle2::from([(0, 0), (10, 0), (0, 10)]);
+		assert!(triangle.contains((0, 0)));
+		assert!(triangle.contains((5, 5)));
+		assert!(triangle.contains((2, 3)));
+		assert!(!triangle.contains((6, 5)));
+		assert!(!triangle.contains((-1, 0)));
+		let line = Triangle2::new((0, 0), (5, 5), (10, 10));
+		assert!(line.contains((3, 3)));
+		assert!(!line.contains((11, 11)));
+
+		let triangle = Triangle2::new((3, 0), (-25, 11), (17, 30));
+		let mut rendered = [[false; 64]; 64];
+		for (x, y) in triangle.rasterize_along(Axis::Y).unwrap().pixels() {
+			rendered[(y + 32) as usize][(x + 32) as usize] = true;
+		}
+		for (y, row) in rendered.iter().enumerate() {
+			for (x, pixel) in row.iter().enumerate() {
+				assert_eq!(triangle.contains((x as i32 - 32, y as i32 - 32)), *pixel);
+			}
+		}
+	}
+
+	#[test]
+	fn contains_large() {
+		let triangle = Triangle2::new((3, 0), (-100000, 41000), (70000, 100000));
+		for (a, b) in triangle.rasterize_along(Axis::Y).unwrap().step_by(997) {
+			let (low, high) = (a.0.min(b.0), a.0.max(b.0));
+			assert!(triangle.contains((low, a.1)) && triangle.contains((high, a.1)));
+			assert!(!triangle.contains((low - 1, a.1)) && !triangle.contains((high + 1, a.1)));
+		}
+		let triangle = Triangle2::<i16>::new((0, 0), (-200, 200), (200, 200));
+		assert!(triangle.contains((-150, 150)) && !triangle.contains((-151, 150)));
+	}
+
+	#[test]
+	fn outline() {
+		let triangle = Triangle2::new((0, 0), (6, 2), (1, 5));
+		let mut pixels = [[0; 7]; 7];
+		for (x, y) in triangle.outline() {
+			pixels[y as usize][x as usize] += 1;
+		}
+		assert!(pixels.iter().flatten().all(|count| *count <= 1));
+		assert_eq!(pixels[0][0] + pixels[2][6] + pixels[5][1], 3);
+		assert_eq!(triangle.outline().count(), 6 + 5 + 5);
+	}
+
+	#[test]
+	fn invalid_axis() {
+		assert!(Triangle2::new((0, 0), (1, 1), (2, 0)).rasterize_along(Axis::Z).is_none());
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn collect() {
+		let zip = Triangle2::new((3, -4), (-20, 9), (16, 11)).rasterize_along(Axis::Y).unwrap();
+		let pixels = zip.clone().collect_pixels();
+		assert_eq!(pixels.len(), zip.pixel_count());
+		assert!(pixels.into_iter().eq(zip.clone().pixels()));
+		assert!(zip.clone().collect_spans().into_iter().eq(zip.spans()));
+	}
+
+}
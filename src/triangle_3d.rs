@@ -0,0 +1,352 @@
+//! Package with the logic to rasterize any three-dimensional triangle
+
+use core::fmt::{Debug, Formatter};
+use crate::barycentric::BarycentricIterator;
+use crate::clip::{Aabb, ClipRect};
+use crate::fill::FillIterator;
+use crate::util::{bounds, line_length, point_at, project, sort_by_axis, Point};
+use crate::zip_3d::{line_point_at, Bresenham3dZip};
+use crate::{Axis, Point2, Point3, Point3Like, SignedNum};
+
+/// Three-dimensional triangle defined by three arbitrary vertices.
+///
+/// Unlike the [Bresenham3dZip], the vertices don't need to share any value. When iterated, the
+/// triangle is split at its middle vertex into two triangles with a flat edge, and both of them
+/// are zipped one after the other.
+///
+/// ```
+/// # use bresenham_zip::{Axis, triangle_3d::Triangle3};
+/// let triangle = Triangle3::new((0, 0, 0), (100, 40, 20), (30, 100, 50));
+/// for (a, b) in triangle.rasterize_along(Axis::Z) {
+///   assert_eq!(a.2, b.2);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle3<T> {
+	pub a: Point3<T>,
+	pub b: Point3<T>,
+	pub c: Point3<T>,
+}
+
+impl<T: SignedNum> Triangle3<T> {
+
+	/// Creates a new triangle with the three given vertices, in any order, of any [Point3Like]
+	/// type like the tuples or the arrays
+	pub fn new<P: Point3Like<T>>(a: P, b: P, c: P) -> Self {
+		Self { a: a.to_tuple(), b: b.to_tuple(), c: c.to_tuple() }
+	}
+
+	/// Returns an iterator over the pairs of points conforming the triangle in the given axis.
+	/// Each axis value between the two extreme vertices is yielded once, from the lowest one.
+	///
+	/// * `axis` - Axis to use in the iteration
+	///
+	pub fn rasterize_along(&self, axis: Axis) -> Triangle3dZip<T> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => 2,
+		};
+		let (top, middle, bottom) = sort_by_axis(self.a, self.b, self.c, axis);
+
+		if top.nth(axis) == bottom.nth(axis) {
+			let other = if axis == 0 { 1 } else { 0 };
+			let (start, _, end) = sort_by_axis(top, middle, bottom, other);
+			return Triangle3dZip::single(Bresenham3dZip::new(start, start, end, axis), axis);
+		}
+		if top.nth(axis) == middle.nth(axis) {
+			let zip = Bresenham3dZip::new(bottom, top, middle, axis).reversed();
+			return Triangle3dZip::single(zip, axis);
+		}
+		if middle.nth(axis) == bottom.nth(axis) {
+			return Triangle3dZip::single(Bresenham3dZip::new(top, middle, bottom, axis), axis);
+		}
+
+		let split = point_at(top, bottom, axis, middle.nth(axis));
+		// the second half is walked from the split, which is yielded by the first one
+		let mut second = Bresenham3dZip::new(bottom, middle, split, axis).reversed();
+		second.next();
+		Triangle3dZip {
+			first: Bresenham3dZip::new(top, middle, split, axis),
+			second: Some(second),
+			axis,
+		}
+	}
+
+	/// Checks if the point is one of the pixels of the triangle rasterized along the given axis,
+	/// the same ones yielded by [pixels](Triangle3dZip::pixels), which lie on the plane of the
+	/// triangle. The pair of points in the axis value of the point and the line between them are
+	/// computed with integers from their slopes, without walking them, so the check agrees with
+	/// the rendering pixel for pixel.
+	///
+	/// * `point` - Point to check
+	/// * `axis` - Axis used to rasterize the triangle
+	///
+	pub fn contains_on_plane(&self, point: Point3<T>, axis: Axis) -> bool {
+		let zip = self.rasterize_along(axis);
+		let Some((a, b)) = zip.span_at(point.nth(zip.axis)) else {
+			return false;
+		};
+		let distance = [(b.0 - a.0).abs(), (b.1 - a.1).abs(), (b.2 - a.2).abs()];
+		let major = if distance[0] >= distance[1] && distance[0] >= distance[2] {
+			0
+		} else if distance[1] >= distance[2] { 1 } else { 2 };
+		// the line between the pair has a single point in each value of its major coordinate
+		let (from, to, value) = (a.nth(major), b.nth(major), point.nth(major));
+		from.min(to) <= value && value <= from.max(to)
+			&& line_point_at(a, b, major, value, true) == point
+	}
+
+	/// Returns the segment where the triangle crosses the plane with the given value in the axis,
+	/// with its points in the two other axes, in their order. The points are computed from the
+	/// edges crossing the plane the same way whatever triangle they belong to, so the segments of
+	/// the triangles of a closed mesh join into closed polygons, ready to be filled with the
+	/// rasterizer of the `polygon` module.
+	///
+	/// The vertices on the plane count as above it, so an edge lying on the plane between two
+	/// triangles is only returned by the one below it. Returns `None` if the triangle doesn't
+	/// cross the plane, or if it just touches it at a single point.
+	///
+	/// * `axis` - Axis perpendicular to the plane
+	/// * `value` - Value of the plane in the axis
+	///
+	/// ```
+	/// # use bresenham_zip::{Axis, triangle_3d::Triangle3};
+	/// let triangle = Triangle3::new((0, 0, 0), (10, 0, 10), (0, 10, 10));
+	/// assert_eq!(triangle.slice_at(Axis::Z, 5), Some(((5, 0), (0, 5))));
+	/// assert_eq!(triangle.slice_at(Axis::Z, 10), Some(((10, 0), (0, 10))));
+	/// assert_eq!(triangle.slice_at(Axis::Z, 11), None);
+	/// ```
+	pub fn slice_at(&self, axis: Axis, value: T) -> Option<(Point2<T>, Point2<T>)> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => 2,
+		};
+		let mut crossings = [(self.a, self.b), (self.a, self.c), (self.b, self.c)].into_iter()
+			.filter(|(a, b)| (a.nth(axis) < value) != (b.nth(axis) < value))
+			.map(|(a, b)| {
+				// sorted, so the edges shared by several triangles are always split the same way
+				let (a, b) = if b < a { (b, a) } else { (a, b) };
+				project(point_at(a, b, axis, value), axis)
+			});
+		let (a, b) = (crossings.next()?, crossings.next()?);
+		(a != b).then_some((a, b))
+	}
+
+	/// Returns the three vertices of the triangle
+	pub fn vertices(&self) -> [Point3<T>; 3] {
+		[self.a, self.b, self.c]
+	}
+
+	/// Returns the smallest axis-aligned box containing the triangle as its lowest and highest
+	/// corners
+	pub fn bounding_box(&self) -> (Point3<T>, Point3<T>) {
+		let (a, b, c) = (self.a, self.b, self.c);
+		(
+			(a.0.min(b.0).min(c.0), a.1.min(b.1).min(c.1), a.2.min(b.2).min(c.2)),
+			(a.0.max(b.0).max(c.0), a.1.max(b.1).max(c.1), a.2.max(b.2).max(c.2)),
+		)
+	}
+
+}
+
+impl<T: SignedNum> From<[Point3<T>; 3]> for Triangle3<T> {
+	fn from(vertices: [Point3<T>; 3]) -> Self {
+		Self::new(vertices[0], vertices[1], vertices[2])
+	}
+}
+
+/// Iterator over the pairs of points of a [Triangle3]. It chains the zips of the two halves of the
+/// triangle, yielding the shared axis value only once. The second half is walked from the split,
+/// so the axis values are yielded in order, from the lowest one.
+#[derive(Clone, PartialEq)]
+pub struct Triangle3dZip<T> {
+	first: Bresenham3dZip<T>,
+	second: Option<Bresenham3dZip<T>>,
+	axis: u8,
+}
+
+impl<T: SignedNum> Triangle3dZip<T> {
+
+	/// Turns the zip into an iterator over every point of the triangle
+	pub fn pixels(self) -> FillIterator<Self, Point3<T>> {
+		FillIterator::new(self)
+	}
+
+	/// Turns the zip into an iterator yielding the [Barycentric](crate::barycentric::Barycentric)
+	/// coordinates of each point along with it relative to the vertices of the triangle, see
+	/// [BarycentricIterator]
+	pub fn with_barycentric(self) -> BarycentricIterator<Self, Point3<T>> {
+		let vertices = self.vertices();
+		BarycentricIterator::new_3d(self, vertices)
+	}
+
+	/// Clips each pair of points to the rectangle or box defined by its lowest and highest
+	/// corners, see [ClipRect]
+	pub fn clip_rect(self, aabb: Aabb<Point3<T>>) -> ClipRect<Self, Point3<T>> {
+		ClipRect::new(self, aabb)
+	}
+
+	/// Returns the lowest and highest corners of the smallest axis-aligned box containing the
+	/// pairs left to yield, or `None` if the zip is exhausted. The zip isn't consumed.
+	pub fn bounding_box(&self) -> Option<Aabb<Point3<T>>> {
+		let second = self.second.as_ref().and_then(Bresenham3dZip::bounding_box);
+		match (self.first.bounding_box(), second) {
+			(Some(first), Some(second)) => Some(bounds(&[first.0, first.1, second.0, second.1])),
+			(first, second) => first.or(second),
+		}
+	}
+
+	/// Returns the number of points of the triangle left to yield, so the buffers to fill can be
+	/// allocated beforehand. The zip isn't consumed.
+	pub fn pixel_count(&self) -> usize {
+		self.clone().map(|(a, b)| line_length(&a, &b)).sum()
+	}
+
+	/// Returns the vertices of the triangle, taken from the zips of both halves
+	fn vertices(&self) -> [Point3<T>; 3] {
+		let [top, middle, split] = self.first.vertices();
+		[top, middle, self.second.as_ref().map_or(split, |second| second.vertices()[0])]
+	}
+
+	/// Returns the pair of points yielded in the axis value `value` by a zip not iterated yet
+	fn span_at(&self, value: T) -> Option<(Point3<T>, Point3<T>)> {
+		self.first.span_at(value).or_else(|| self.second.as_ref()?.span_at(value))
+	}
+
+	fn single(zip: Bresenham3dZip<T>, axis: u8) -> Self {
+		Self {
+			first: zip,
+			second: None,
+			axis,
+		}
+	}
+
+}
+
+impl<T: SignedNum> Iterator for Triangle3dZip<T> {
+	type Item = (Point3<T>, Point3<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(pair) = self.first.next() {
+			return Some(pair);
+		}
+		self.second.as_mut()?.next()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let second = self.second.as_ref().map_or(0, ExactSizeIterator::len);
+		let remaining = self.first.len() + second;
+		(remaining, Some(remaining))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let first = self.first.len();
+		if n < first {
+			return self.first.nth(n);
+		}
+		self.first.nth(first);
+		self.second.as_mut()?.nth(n - first)
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.nth(self.len().checked_sub(1)?)
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for Triangle3dZip<T> {}
+
+impl<T: SignedNum> Debug for Triangle3dZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Triangle3dZip [ {:?}, {:?} ]", self.first, self.second)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Axis;
+	use super::Triangle3;
+
+	macro_rules! covers {
+	    ($a:tt, $b:tt, $c:tt, $axis:expr, $i:tt, $from:expr, $to:expr) => {
+		    let mut values = [0; ($to - $from + 1) as usize];
+		    let mut previous = $from - 1;
+		    for (a, b) in Triangle3::new($a, $b, $c).rasterize_along($axis) {
+			    assert_eq!(a.$i, b.$i);
+			    values[(a.$i - $from) as usize] += 1;
+			    assert_eq!(a.$i, previous + 1);
+			    previous = a.$i;
+		    }
+		    assert!(values.iter().all(|count| *count == 1));
+	    };
+	}
+
+	#[test]
+	fn flat() {
+		let triangle = Triangle3::new((100, 100, 0), (50, 50, 50), (0, 100, 0));
+		let pairs = triangle.rasterize_along(Axis::Z);
+		assert_eq!(pairs.last(), Some(((50, 50, 50), (50, 50, 50))));
+		covers!((50, 50, 50), (0, 0, 100), (100, 100, 100), Axis::Z, 2, 50, 100);
+		covers!((0, 0, 50), (100, 0, 50), (30, 60, 100), Axis::Z, 2, 50, 100);
+	}
+
+	#[test]
+	fn split() {
+		covers!((0, 0, 0), (100, 40, 20), (30, 100, 50), Axis::X, 0, 0, 100);
+		covers!((0, 0, 0), (100, 40, 20), (30, 100, 50), Axis::Y, 1, 0, 100);
+		covers!((0, 0, 0), (100, 40, 20), (30, 100, 50), Axis::Z, 2, 0, 50);
+	}
+
+	#[test]
+	fn exact_size() {
+		let pairs = Triangle3::new((0, 0, 0), (100, 40, 20), (30, 100, 50)).rasterize_along(Axis::Y);
+		assert_eq!(pairs.len(), 101);
+		assert_eq!(pairs.count(), 101);
+	}
+
+	#[test]
+	fn bounding_box() {
+		let triangle = Triangle3::from([(0, 0, 0), (100, 40, -20), (30, 100, 50)]);
+		assert_eq!(triangle.bounding_box(), ((0, 0, -20), (100, 100, 50)));
+		let pairs = triangle.rasterize_along(Axis::Y);
+		assert_eq!(pairs.bounding_box(), Some(triangle.bounding_box()));
+		assert_eq!(pairs.pixel_count(), pairs.clone().pixels().count());
+	}
+
+	#[test]
+	fn contains_on_plane() {
+		let triangle = Triangle3::new((3, 0, -9), (-13, 11, 4), (9, 14, 12));
+		for axis in [Axis::X, Axis::Y, Axis::Z] {
+			let mut rendered = [[[false; 32]; 32]; 32];
+			for (x, y, z) in triangle.rasterize_along(axis).pixels() {
+				rendered[(x + 16) as usize][(y + 16) as usize][(z + 16) as usize] = true;
+			}
+			for (x, plane) in rendered.iter().enumerate() {
+				for (y, row) in plane.iter().enumerate() {
+					for (z, pixel) in row.iter().enumerate() {
+						let point = (x as i32 - 16, y as i32 - 16, z as i32 - 16);
+						assert_eq!(triangle.contains_on_plane(point, axis), *pixel);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn split_point() {
+		let triangle = Triangle3::new((0, 0, 0), (100, 20, 50), (0, 40, 100));
+		let pairs = triangle.rasterize_along(Axis::Z);
+		for (a, b) in pairs.filter(|(a, _)| a.2 == 50) {
+			assert_eq!(a, (100, 20, 50));
+			assert_eq!(b, (0, 20, 50));
+		}
+	}
+
+}
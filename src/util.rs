@@ -1,4 +1,4 @@
-use crate::{Point2, Point3, SignedNum};
+use crate::{FloatNum, Point2, Point3, SignedNum, Snap};
 
 macro_rules! nth {
     ($x:expr, $axis:tt) => {
@@ -22,19 +22,282 @@ macro_rules! nth3 {
 }
 
 pub trait Point<T> {
+	/// Number of coordinates of the point
+	const DIMENSIONS: u8;
+
 	fn nth(&self, index: u8) -> T;
+
+	fn set_nth(&mut self, index: u8, value: T);
 }
 
-impl<T: SignedNum> Point<T> for Point2<T> {
+impl<T: Copy> Point<T> for Point2<T> {
+	const DIMENSIONS: u8 = 2;
+
 	fn nth(&self, index: u8) -> T {
 		nth!(self, index)
 	}
+
+	fn set_nth(&mut self, index: u8, value: T) {
+		match index {
+			0 => self.0 = value,
+			1 => self.1 = value,
+			_ => unreachable!()
+		}
+	}
 }
 
-impl<T: SignedNum> Point<T> for Point3<T> {
+impl<T: Copy> Point<T> for Point3<T> {
+	const DIMENSIONS: u8 = 3;
+
 	fn nth(&self, index: u8) -> T {
 		nth3!(self, index)
 	}
+
+	fn set_nth(&mut self, index: u8, value: T) {
+		match index {
+			0 => self.0 = value,
+			1 => self.1 = value,
+			2 => self.2 = value,
+			_ => unreachable!()
+		}
+	}
+}
+
+/// Returns the only axis where both points share the same value, if there is exactly one
+pub(crate) fn shared_axis<T: SignedNum, P: Point<T>>(a: P, b: P) -> Option<u8> {
+	let mut shared = (0..P::DIMENSIONS).filter(|i| a.nth(*i) == b.nth(*i));
+	match (shared.next(), shared.next()) {
+		(Some(axis), None) => Some(axis),
+		_ => None,
+	}
+}
+
+/// Moves both points to a common value in the axis if they are no further than `tolerance`.
+/// Returns `None` if they are too far.
+pub(crate) fn snap_axis<T, P>(a: P, b: P, axis: u8, tolerance: T, snap: Snap) -> Option<(P, P)>
+	where T: SignedNum, P: Point<T> + Copy {
+	let (low, high) = if a.nth(axis) <= b.nth(axis) {
+		(a.nth(axis), b.nth(axis))
+	} else {
+		(b.nth(axis), a.nth(axis))
+	};
+	if high - low > tolerance {
+		return None;
+	}
+	let value = match snap {
+		Snap::Min => low,
+		Snap::Max => high,
+		Snap::Midpoint => low + (high - low) / (T::one() + T::one()),
+	};
+	let (mut a, mut b) = (a, b);
+	a.set_nth(axis, value);
+	b.set_nth(axis, value);
+	Some((a, b))
 }
 
+/// Adds `step` to every coordinate of `point`
+pub(crate) fn advance<F: FloatNum, P: Point<F>>(point: &mut P, step: &P) {
+	for i in 0..P::DIMENSIONS {
+		point.set_nth(i, point.nth(i) + step.nth(i));
+	}
+}
+
+/// Floor of the division by a positive divisor
+pub(crate) fn floor_div<T: SignedNum>(dividend: T, divisor: T) -> T {
+	let quotient = dividend / divisor;
+	if dividend % divisor < T::zero() { quotient - T::one() } else { quotient }
+}
+
+/// Returns the number of values between `from` and `to`, both included
+pub(crate) fn count_between<T: SignedNum>(from: T, to: T) -> usize {
+	match (from.to_i128(), to.to_i128()) {
+		(Some(from), Some(to)) => (to - from).unsigned_abs() as usize + 1,
+		_ => 0,
+	}
+}
+
+/// Returns the number of points of the Bresenham line between `from` and `to`, both included
+pub(crate) fn line_length<T: SignedNum, P: Point<T>>(from: &P, to: &P) -> usize {
+	(0..P::DIMENSIONS).map(|i| count_between(from.nth(i), to.nth(i))).max().unwrap_or_default()
+}
+
+/// Returns the lowest and highest corners of the smallest axis-aligned box containing the points
+pub(crate) fn bounds<T: SignedNum, P: Point<T> + Copy>(points: &[P]) -> (P, P) {
+	let (mut min, mut max) = (points[0], points[0]);
+	for point in &points[1..] {
+		for i in 0..P::DIMENSIONS {
+			min.set_nth(i, min.nth(i).min(point.nth(i)));
+			max.set_nth(i, max.nth(i).max(point.nth(i)));
+		}
+	}
+	(min, max)
+}
+
+/// Converts the number to `i128`, where the products of the coordinates can't overflow
+pub(crate) fn wide<T: SignedNum>(value: T) -> i128 {
+	value.to_i128().unwrap_or_default()
+}
+
+/// Checks if the lines from `from` to each point of `to` can be walked with `T`, which must fit
+/// `margin` times the difference of every coordinate, as the error terms of the lines add up to it
+pub(crate) fn fits_lines<T: SignedNum, P: Point<T>>(from: &P, to: &[P], margin: i128) -> bool {
+	to.iter().all(|point| (0..P::DIMENSIONS).all(|i| {
+		let delta = (wide(point.nth(i)) - wide(from.nth(i))).abs();
+		T::from(delta * margin).is_some()
+	}))
+}
+
+/// Converts back a number computed with [wide], which must fit in `T`
+pub(crate) fn narrow<T: SignedNum>(value: i128) -> T {
+	T::from(value).unwrap_or_else(T::zero)
+}
+
+/// Converts the integer to a number with fractional part, built with additions as [FloatNum] has
+/// no conversion from integers
+pub(crate) fn float<F: FloatNum>(value: i128) -> F {
+	let (mut result, mut power) = (F::ZERO, F::ONE);
+	let mut rest = value.unsigned_abs();
+	while rest > 0 {
+		if rest & 1 == 1 {
+			result += power;
+		}
+		power += power;
+		rest >>= 1;
+	}
+	if value < 0 { -result } else { result }
+}
 
+/// Returns the greatest integer lower than or equal to the number with fractional part
+pub(crate) fn floor<F: FloatNum>(value: F) -> i128 {
+	if value >= F::ZERO {
+		return value.to_usize() as i128;
+	}
+	let whole = -((-value).to_usize() as i128);
+	if float::<F>(whole) == value { whole } else { whole - 1 }
+}
+
+/// Returns the point in the plane of the two axes other than the given one, in their order
+pub(crate) fn project<T: Copy>(point: Point3<T>, axis: u8) -> Point2<T> {
+	match axis {
+		0 => (point.1, point.2),
+		1 => (point.0, point.2),
+		_ => (point.0, point.1),
+	}
+}
+
+/// Square root of the non-negative number rounded down, with the Newton's method
+pub(crate) fn isqrt(value: i128) -> i128 {
+	if value < 2 {
+		return value.max(0);
+	}
+	let mut root = value;
+	let mut next = value / 2 + 1;
+	while next < root {
+		root = next;
+		next = (root + value / root) / 2;
+	}
+	root
+}
+
+/// Fraction rounded down, kept as its value and the remainder of the division, so it can be
+/// increased without dividing again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Ratio<T> {
+	value: T,
+	error: T,
+	divisor: T,
+}
+
+/// Amount added to a [Ratio], split into the whole part and the remainder over its divisor
+pub(crate) type Increment<T> = (T, T);
+
+impl<T: SignedNum> Ratio<T> {
+
+	/// Creates the fraction `dividend / divisor`. They are given as `i128`, so the products
+	/// computing them can't overflow, but the divisor, positive, and the value must fit in `T`.
+	pub(crate) fn new(dividend: i128, divisor: i128) -> Self {
+		Self {
+			value: narrow(dividend.div_euclid(divisor)),
+			error: narrow(dividend.rem_euclid(divisor)),
+			divisor: narrow(divisor),
+		}
+	}
+
+	/// Returns the fraction rounded down
+	#[inline]
+	pub(crate) fn value(&self) -> T {
+		self.value
+	}
+
+	/// Splits the amount `dividend / divisor` to be added with [add](Self::add)
+	pub(crate) fn increment(&self, dividend: i128) -> Increment<T> {
+		let divisor = wide(self.divisor);
+		(narrow(dividend.div_euclid(divisor)), narrow(dividend.rem_euclid(divisor)))
+	}
+
+	/// Adds the increment, returning if the remainder carried one unit to the value
+	#[inline]
+	pub(crate) fn add(&mut self, (whole, part): Increment<T>) -> bool {
+		self.value += whole;
+		self.error += part;
+		let carried = self.error >= self.divisor;
+		if carried {
+			self.value += T::one();
+			self.error -= self.divisor;
+		}
+		carried
+	}
+
+	/// Adds the increment `times` times at once, returning how many units the remainder carried
+	pub(crate) fn add_times(&mut self, (whole, part): Increment<T>, times: i128) -> i128 {
+		let (error, divisor) = (wide(self.error) + wide(part) * times, wide(self.divisor));
+		let carried = error.div_euclid(divisor);
+		self.value = narrow(wide(self.value) + wide(whole) * times + carried);
+		self.error = narrow(error.rem_euclid(divisor));
+		carried
+	}
+
+}
+
+/// Reorders the vertices of a triangle as `(start, end1, end2)`, where the two ending points are
+/// the first pair found sharing the value of the given axis. If none of them do, the vertices are
+/// returned as they are.
+pub(crate) fn flat_edge_last<T, P>(a: P, b: P, c: P, axis: u8) -> (P, P, P)
+	where T: SignedNum, P: Point<T> + Copy {
+	if b.nth(axis) == c.nth(axis) {
+		(a, b, c)
+	} else if a.nth(axis) == c.nth(axis) {
+		(b, a, c)
+	} else if a.nth(axis) == b.nth(axis) {
+		(c, a, b)
+	} else {
+		(a, b, c)
+	}
+}
+
+/// Returns the three points sorted by their value in the given axis, keeping the order of the
+/// points with the same value
+pub(crate) fn sort_by_axis<T, P>(a: P, b: P, c: P, axis: u8) -> (P, P, P)
+	where T: SignedNum, P: Point<T> + Copy {
+	let (a, b) = if b.nth(axis) < a.nth(axis) { (b, a) } else { (a, b) };
+	let (b, c) = if c.nth(axis) < b.nth(axis) { (c, b) } else { (b, c) };
+	let (a, b) = if b.nth(axis) < a.nth(axis) { (b, a) } else { (a, b) };
+	(a, b, c)
+}
+
+/// Returns the point of the segment `from -> to` with the specified value in the given axis.
+/// The rest of the coordinates are interpolated in `i128`, truncating the result.
+pub(crate) fn point_at<T: SignedNum, P: Point<T> + Copy>(from: P, to: P, axis: u8, value: T) -> P {
+	let mut point = from;
+	let length = wide(to.nth(axis)) - wide(from.nth(axis));
+	if length == 0 {
+		return point;
+	}
+	let travelled = wide(value) - wide(from.nth(axis));
+	for i in (0..P::DIMENSIONS).filter(|i| *i != axis) {
+		let delta = wide(to.nth(i)) - wide(from.nth(i));
+		point.set_nth(i, narrow(wide(from.nth(i)) + delta * travelled / length));
+	}
+	point.set_nth(axis, value);
+	point
+}
@@ -0,0 +1,235 @@
+//! Package with the voxelization of the interior of closed three-dimensional meshes.
+//! Requires the `alloc` feature.
+
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use crate::util::{floor_div, narrow, project, wide, Point};
+use crate::zip::TopLeftZip;
+use crate::{Axis, Point3, SignedNum};
+
+/// Target receiving the runs of voxels inside a mesh, like a voxel grid or an octree
+pub trait VoxelSink<T> {
+	/// Fills the voxels from `start` to `end`, both included, which only differ in the value of
+	/// the axis crossed by the rays and are sorted by it
+	fn fill_run(&mut self, start: Point3<T>, end: Point3<T>);
+}
+
+/// Point where a ray crosses a triangle of the mesh, sorted by ray and then along it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Crossing<T> {
+	ray: (T, T),
+	/// Value in the axis of the ray, as the fraction `numerator / denominator`
+	numerator: i128,
+	denominator: i128,
+}
+
+/// Returns the voxels inside the closed mesh, casting a ray along the axis through the center
+/// of each row of voxels and filling them between every pair of crossings with the triangles of
+/// the mesh, see [SolidVoxels].
+///
+/// The rays crossing the triangles are found rasterizing their projections with the top-left
+/// fill rule of the [TopLeftZip], so the rays going through an edge or a vertex shared by several
+/// triangles cross the mesh only once.
+///
+/// * `vertices` - Vertices of the mesh
+/// * `indices` - Indices of the vertices of each triangle, in any order
+/// * `axis` - Axis of the rays
+///
+/// # Panics
+/// If any of the indices is out of the bounds of the vertices.
+///
+/// ```
+/// # use bresenham_zip::{Axis, voxelize};
+/// // tetrahedron with a corner at the origin and the others on each axis
+/// let vertices = [(0, 0, 0), (10, 0, 0), (0, 10, 0), (0, 0, 10)];
+/// let indices = [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]];
+/// for (x, y, z) in voxelize::solid(&vertices, &indices, Axis::Z) {
+///   assert!(x >= 0 && y >= 0 && z >= 0 && x + y + z < 10);
+/// }
+/// ```
+pub fn solid<T: SignedNum>(vertices: &[Point3<T>], indices: &[[usize; 3]], axis: Axis)
+	-> SolidVoxels<T> {
+	let axis = match axis {
+		Axis::X => 0,
+		Axis::Y => 1,
+		Axis::Z => 2,
+	};
+	let project = |point| project(point, axis);
+	let mut crossings = Vec::new();
+	for [a, b, c] in indices.iter().map(|triangle| triangle.map(|index| vertices[index])) {
+		// the plane of the triangle as `normal · (point - a) = 0`, with the ray axis last
+		let [ab, ac] = [b, c].map(|point| {
+			let (u, v) = (project(point), project(a));
+			let w = wide(point.nth(axis)) - wide(a.nth(axis));
+			(wide(u.0) - wide(v.0), wide(u.1) - wide(v.1), w)
+		});
+		let normal = (
+			ab.1 * ac.2 - ab.2 * ac.1,
+			ab.2 * ac.0 - ab.0 * ac.2,
+			ab.0 * ac.1 - ab.1 * ac.0,
+		);
+		// the triangles parallel to the rays are never crossed
+		if normal.2 == 0 {
+			continue;
+		}
+		let sign = normal.2.signum();
+		let origin = project(a);
+		let rays = TopLeftZip::new(origin, project(b), project(c), 1).spans();
+		for span in rays {
+			let (mut u, last) = span.range.into_inner();
+			while u <= last {
+				let offset = (wide(u) - wide(origin.0), wide(span.axis_value) - wide(origin.1));
+				crossings.push(Crossing {
+					ray: (u, span.axis_value),
+					numerator: sign * (wide(a.nth(axis)) * normal.2
+						- normal.0 * offset.0 - normal.1 * offset.1),
+					denominator: sign * normal.2,
+				});
+				u += T::one();
+			}
+		}
+	}
+	crossings.sort_unstable_by(|a, b| {
+		a.ray.cmp(&b.ray).then((a.numerator * b.denominator).cmp(&(b.numerator * a.denominator)))
+	});
+	SolidVoxels { crossings, next: 0, axis, run: None }
+}
+
+/// Iterator over the voxels inside a closed mesh, see [solid]. They are yielded ray by ray, and
+/// each ray in order along its axis. The voxels with their centers inside the mesh are yielded,
+/// and the ones just on a triangle only where the ray enters the mesh. The rays with an odd number
+/// of crossings, which are only possible in meshes with holes, ignore the last one.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SolidVoxels<T> {
+	crossings: Vec<Crossing<T>>,
+	next: usize,
+	axis: u8,
+	run: Option<(Point3<T>, Point3<T>)>,
+}
+
+impl<T: SignedNum> SolidVoxels<T> {
+
+	/// Fills every run of voxels left in the sink, see [VoxelSink]
+	///
+	/// * `sink` - Target of the runs, like a voxel grid
+	///
+	pub fn rasterize_into<S: VoxelSink<T>>(mut self, sink: &mut S) {
+		if let Some((start, end)) = self.run.take() {
+			sink.fill_run(start, end);
+		}
+		while let Some((start, end)) = self.next_run() {
+			sink.fill_run(start, end);
+		}
+	}
+
+	/// Returns the next run of voxels between an entering crossing and the exiting one
+	fn next_run(&mut self) -> Option<(Point3<T>, Point3<T>)> {
+		loop {
+			let enter = self.crossings.get(self.next)?;
+			let exit = self.crossings.get(self.next + 1)?;
+			if enter.ray != exit.ray {
+				self.next += 1;
+				continue;
+			}
+			self.next += 2;
+			// the first center at or after the entering point, and the last one before the exit
+			let ceil = |crossing: &Crossing<T>| {
+				-floor_div(-crossing.numerator, crossing.denominator)
+			};
+			let (from, to) = (ceil(enter), ceil(exit) - 1);
+			if from <= to {
+				let point = |value: i128| {
+					let (u, v) = enter.ray;
+					let value = narrow(value);
+					match self.axis {
+						0 => (value, u, v),
+						1 => (u, value, v),
+						_ => (u, v, value),
+					}
+				};
+				return Some((point(from), point(to)));
+			}
+		}
+	}
+
+}
+
+impl<T: SignedNum> Iterator for SolidVoxels<T> {
+	type Item = Point3<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (start, end) = match self.run {
+			Some(run) => run,
+			None => self.next_run()?,
+		};
+		self.run = (start != end).then(|| {
+			let mut start = start;
+			start.set_nth(self.axis, start.nth(self.axis) + T::one());
+			(start, end)
+		});
+		Some(start)
+	}
+}
+
+impl<T: SignedNum> Debug for SolidVoxels<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "SolidVoxels [ {:?} ]. Remaining crossings: {}",
+			self.run, self.crossings.len() - self.next)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use crate::{Axis, Point3};
+	use super::{solid, VoxelSink};
+
+	/// Returns the twelve triangles of the cube from `min` with the given side
+	fn cube(min: Point3<i32>, side: i32) -> ([Point3<i32>; 8], [[usize; 3]; 12]) {
+		let corner = |i: i32| {
+			(min.0 + side * (i & 1), min.1 + side * (i >> 1 & 1), min.2 + side * (i >> 2))
+		};
+		let vertices = [0, 1, 2, 3, 4, 5, 6, 7].map(corner);
+		let indices = [
+			[0, 1, 3], [0, 3, 2], [4, 5, 7], [4, 7, 6], [0, 1, 5], [0, 5, 4],
+			[2, 3, 7], [2, 7, 6], [0, 2, 6], [0, 6, 4], [1, 3, 7], [1, 7, 5],
+		];
+		(vertices, indices)
+	}
+
+	struct Count(usize);
+
+	impl VoxelSink<i32> for Count {
+		fn fill_run(&mut self, start: Point3<i32>, end: Point3<i32>) {
+			self.0 += (end.0 - start.0 + end.1 - start.1 + end.2 - start.2) as usize + 1;
+		}
+	}
+
+	#[test]
+	fn cubes() {
+		let (vertices, indices) = cube((-3, 2, 5), 8);
+		for axis in [Axis::X, Axis::Y, Axis::Z] {
+			let voxels: Vec<_> = solid(&vertices, &indices, axis).collect();
+			assert_eq!(voxels.len(), 8 * 8 * 8);
+			assert!(voxels.iter().all(|(x, y, z)| {
+				(-3..5).contains(x) && (2..10).contains(y) && (5..13).contains(z)
+			}));
+		}
+		// two cubes in a single mesh, one inside the other, leave a hollow shell
+		let (inner, _) = cube((-1, 4, 7), 4);
+		let vertices: Vec<_> = vertices.into_iter().chain(inner).collect();
+		let indices: Vec<_> = indices.iter().chain(indices.map(|t| t.map(|i| i + 8)).iter())
+			.copied().collect();
+		let mut count = Count(0);
+		solid(&vertices, &indices, Axis::Y).rasterize_into(&mut count);
+		assert_eq!(count.0, 8 * 8 * 8 - 4 * 4 * 4);
+	}
+
+	#[test]
+	fn open() {
+		let vertices = [(0, 0, 0), (10, 0, 0), (0, 10, 5)];
+		assert_eq!(solid(&vertices, &[[0, 1, 2]], Axis::Z).count(), 0);
+		assert_eq!(solid(&vertices, &[], Axis::Z).count(), 0);
+	}
+
+}
@@ -0,0 +1,172 @@
+//! Package with the depth buffer to draw the three-dimensional triangles into
+
+use core::fmt::{Debug, Formatter};
+use crate::clip::ClipRect;
+use crate::depth::{DepthFill, DepthZip};
+use crate::triangle::{Triangle2, TriangleZip};
+use crate::triangle_3d::Triangle3;
+use crate::{Axis, Point2, Point3, SignedNum};
+
+/// Depth buffer over a slice provided by the caller, storing the depth of the closest point drawn
+/// in each pixel row by row. The triangles are drawn with their coordinates `x` and `y` in the
+/// screen and their coordinate `z` as depth, so they must be already projected.
+///
+/// ```
+/// # use bresenham_zip::{triangle_3d::Triangle3, zbuffer::ZBuffer};
+/// let mut depths = [i32::MAX; 64 * 64];
+/// let mut zbuffer = ZBuffer::new(&mut depths, 64);
+/// let back = Triangle3::new((0, 0, 50), (63, 0, 50), (0, 63, 50));
+/// assert_eq!(zbuffer.draw(&back).count(), 64 * 65 / 2);
+/// let front = Triangle3::new((0, 0, 10), (10, 0, 10), (0, 10, 10));
+/// assert_eq!(zbuffer.draw(&front).count(), 11 * 12 / 2);
+/// assert_eq!(zbuffer.draw(&back).count(), 0);
+/// ```
+pub struct ZBuffer<'a, T> {
+	depths: &'a mut [T],
+	width: usize,
+	height: usize,
+	compare: fn(T, T) -> bool,
+}
+
+impl<'a, T: SignedNum> ZBuffer<'a, T> {
+
+	/// Creates the depth buffer over the slice. A pixel passes the depth test if it's closer than
+	/// the one stored, with a lower depth, so the slice should be filled with the highest one.
+	///
+	/// * `depths` - Depths of the pixels, row by row. Its length must be a multiple of the width
+	/// * `width` - Number of pixels of each row
+	///
+	pub fn new(depths: &'a mut [T], width: usize) -> Self {
+		let height = depths.len().checked_div(width).unwrap_or_default();
+		Self { depths, width, height, compare: |depth, stored| depth < stored }
+	}
+
+	/// Changes the depth test, which passes when `compare(depth, stored)` is true
+	///
+	/// * `compare` - Test of the depth of the drawn pixel against the stored one
+	///
+	pub fn with_compare(mut self, compare: fn(T, T) -> bool) -> Self {
+		self.compare = compare;
+		self
+	}
+
+	/// Returns the depth stored in the pixel, or `None` if it's out of the buffer
+	pub fn depth(&self, (x, y): Point2<T>) -> Option<T> {
+		index(x, y, self.width, self.height).map(|index| self.depths[index])
+	}
+
+	/// Returns an iterator rasterizing the triangle into the buffer, see [DepthTest]. The triangle
+	/// is clipped to the buffer and rasterized in rows with the depth of its vertices interpolated
+	/// over it.
+	///
+	/// * `triangle` - Triangle to draw, with the depth as coordinate `z`
+	///
+	pub fn draw(&mut self, triangle: &Triangle3<T>) -> DepthTest<'_, T> {
+		let [a, b, c] = triangle.vertices();
+		let screen = Triangle2::new((a.0, a.1), (b.0, b.1), (c.0, c.1));
+		// the sizes not fitting in the type can't clip any point of the triangle
+		let (_, corner) = screen.bounding_box();
+		let max = (
+			T::from(self.width.saturating_sub(1)).unwrap_or(corner.0),
+			T::from(self.height.saturating_sub(1)).unwrap_or(corner.1),
+		);
+		let pixels = screen.rasterize_along(Axis::Y)
+			.map(|zip| zip.clip_rect(((T::zero(), T::zero()), max)))
+			.map(|zip| DepthZip::new(zip, screen.vertices(), [a.2, b.2, c.2]).pixels());
+		DepthTest {
+			depths: &mut *self.depths,
+			width: self.width,
+			height: self.height,
+			compare: self.compare,
+			pixels,
+		}
+	}
+
+}
+
+/// Returns the index in the slice of the pixel, or `None` if it's out of the buffer
+fn index<T: SignedNum>(x: T, y: T, width: usize, height: usize) -> Option<usize> {
+	let (x, y) = (x.to_usize()?, y.to_usize()?);
+	(x < width && y < height).then_some(y * width + x)
+}
+
+impl<T: Debug> Debug for ZBuffer<'_, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "ZBuffer [ {}x{} ]", self.width, self.height)
+	}
+}
+
+/// Iterator drawing a triangle into a [ZBuffer], yielding the pixels passing the depth test with
+/// their depth as coordinate `z`. The depth of each pixel is written into the buffer as it's
+/// yielded, so the iterator must be consumed to draw the whole triangle.
+#[must_use = "the triangle is only drawn as the iterator is consumed"]
+pub struct DepthTest<'b, T: SignedNum> {
+	depths: &'b mut [T],
+	width: usize,
+	height: usize,
+	compare: fn(T, T) -> bool,
+	pixels: Option<Pixels<T>>,
+}
+
+/// Points of a triangle clipped to a [ZBuffer] with their depth
+type Pixels<T> = DepthFill<ClipRect<TriangleZip<T>, Point2<T>>, T>;
+
+impl<T: SignedNum> Iterator for DepthTest<'_, T> {
+	type Item = Point3<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (depths, compare) = (&mut *self.depths, self.compare);
+		let (width, height) = (self.width, self.height);
+		self.pixels.as_mut()?.find(|&(x, y, depth)| {
+			let Some(index) = index(x, y, width, height) else {
+				return false;
+			};
+			let passed = compare(depth, depths[index]);
+			if passed {
+				depths[index] = depth;
+			}
+			passed
+		})
+	}
+}
+
+impl<T: SignedNum> Debug for DepthTest<'_, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "DepthTest [ {:?} ] into {}x{}", self.pixels, self.width, self.height)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::triangle::Triangle2;
+	use crate::triangle_3d::Triangle3;
+	use crate::Axis;
+	use super::ZBuffer;
+
+	#[test]
+	fn overlap() {
+		let mut depths = [100; 16 * 8];
+		let mut zbuffer = ZBuffer::new(&mut depths, 16);
+		let slope = Triangle3::new((0, 0, 0), (15, 0, 30), (0, 7, 0));
+		let screen = Triangle2::new((0, 0), (15, 0), (0, 7)).rasterize_along(Axis::Y).unwrap();
+		assert_eq!(zbuffer.draw(&slope).count(), screen.pixel_count());
+		// the depth of the slope is twice its x, so the wall is behind it until x is 5
+		let wall = Triangle3::new((0, 0, 10), (15, 0, 10), (0, 15, 10));
+		zbuffer.draw(&wall).for_each(drop);
+		assert_eq!(zbuffer.depth((0, 0)), Some(0));
+		assert_eq!(zbuffer.depth((5, 0)), Some(10));
+		assert_eq!(zbuffer.depth((4, 0)), Some(8));
+		assert_eq!(zbuffer.depth((3, 7)), Some(10));
+		assert_eq!(zbuffer.depth((16, 0)), None);
+	}
+
+	#[test]
+	fn clipped() {
+		let mut depths = [0; 10 * 10];
+		let mut zbuffer = ZBuffer::new(&mut depths, 10).with_compare(|depth, stored| depth >= stored);
+		let triangle = Triangle3::new((-20, -20, 1), (40, -20, 1), (-20, 40, 1));
+		assert_eq!(zbuffer.draw(&triangle).count(), 100);
+		assert!(depths.iter().all(|depth| *depth == 1));
+	}
+
+}
@@ -1,21 +1,48 @@
 //! Package with the logic of the two-dimensional BresenhamZip
 
+mod antialiased;
 mod builder;
+mod conservative;
+mod dda;
+mod edge;
+mod half_plane;
+mod supercover;
+mod top_left;
+mod trapezoid;
+mod typed_builder;
 
-use crate::util::Point;
-use crate::{Point2, SignedNum};
+use crate::barycentric::BarycentricIterator;
+use crate::clip::{Aabb, ClipRect};
+use crate::convert::{FromTuple, PairsAs};
+use crate::depth::DepthZip;
+use crate::fill::FillIterator;
+use crate::fuel::Fueled;
+use crate::sink::SpanSink;
+use crate::span::{EnumerateAxis, SpanIterator};
+use crate::state::ZipState;
+use crate::util::{bounds, fits_lines, flat_edge_last, floor_div, line_length, narrow, wide, Point};
+use crate::zip_core::ZipCore;
+use crate::{Axis, AxisSelect, Error, Point2, SignedNum};
 use core::fmt::{Debug, Formatter};
-use line_drawing::Bresenham;
+use edge::Edge;
 
+pub use antialiased::{AntialiasedZip, Coverage};
 pub use builder::Builder;
+pub use conservative::ConservativeZip;
+pub use dda::Dda;
+pub use half_plane::HalfPlaneZip;
+pub use supercover::{Run, SupercoverZip};
+pub use top_left::TopLeftZip;
+pub use trapezoid::{Base, TrapezoidZip};
+pub use typed_builder::{Set, TypedBuilder, Unset};
 
-pub struct BresenhamZip<T> {
-    a: Bresenham<T>,
-    b: Bresenham<T>,
-    prev_a: Point2<T>,
-    prev_b: Point2<T>,
-    goal: T,
-    axis: u8,
+#[derive(Clone, PartialEq)]
+pub struct BresenhamZip<T, A = u8> {
+    start: Point2<T>,
+    end_a: Point2<T>,
+    end_b: Point2<T>,
+    core: ZipCore<T, Edge<T, A>>,
+    axis: A,
 }
 
 impl<T: SignedNum> BresenhamZip<T> {
@@ -26,69 +53,439 @@ impl<T: SignedNum> BresenhamZip<T> {
         end2: Point2<T>,
         axis: u8,
     ) -> BresenhamZip<T> {
+        Self::with_axis(start, end1, end2, axis)
+    }
+
+    /// Creates the zip of a triangle given its three vertices in any order. The two vertices
+    /// sharing the value of the axis are used as the ending points.
+    ///
+    /// ```
+    /// # use bresenham_zip::{Axis, zip::BresenhamZip};
+    /// let zip = BresenhamZip::from_vertices((0, 100), (50, 50), (100, 100), Axis::Y).unwrap();
+    /// assert_eq!(zip.last(), Some(((0, 100), (100, 100))));
+    /// ```
+    ///
+    /// * `a`, `b`, `c` - Vertices of the triangle
+    /// * `axis` - Axis to use in the iteration
+    ///
+    /// # Error
+    /// The same errors of [Builder::build] can be generated. When no pair of vertices shares the
+    /// value of the axis, the invalid values reported are the ones of `b` and `c`.
+    ///
+    pub fn from_vertices(
+        a: Point2<T>,
+        b: Point2<T>,
+        c: Point2<T>,
+        axis: Axis,
+    ) -> Result<BresenhamZip<T>, Error<T>> {
+        let index = if axis == Axis::X { 0 } else { 1 };
+        let (start, end1, end2) = flat_edge_last(a, b, c, index);
+        Builder::new()
+            .axis(axis)
+            .start_point(start)
+            .first_ending_point(end1)
+            .second_ending_point(end2)
+            .build()
+    }
+
+    /// Creates the zip like [Builder::build], checking first that the lines can be computed with
+    /// the type of the coordinates. The error terms of the lines are kept in `T`, so with small
+    /// types like `i8` or `i16`, points too far apart would make them overflow.
+    ///
+    /// ```
+    /// # use bresenham_zip::{Axis, Error, zip::BresenhamZip};
+    /// let zip = BresenhamZip::<i8>::try_new_checked((0, -100), (-50, 100), (50, 100), Axis::Y);
+    /// assert_eq!(zip.unwrap_err(), Error::Overflow);
+    /// let zip = BresenhamZip::<i8>::try_new_checked((0, -30), (-50, 30), (50, 30), Axis::Y);
+    /// assert_eq!(zip.unwrap().len(), 61);
+    /// ```
+    ///
+    /// * `start` - Starting point of both lines
+    /// * `end1`, `end2` - Ending points of the lines, which must share the value of the axis
+    /// * `axis` - Axis to use in the iteration
+    ///
+    /// # Error
+    /// [Error::Overflow] if twice the difference of any coordinate between the starting point
+    /// and an ending point doesn't fit in `T`, or the same errors of [Builder::build].
+    ///
+    pub fn try_new_checked(
+        start: Point2<T>,
+        end1: Point2<T>,
+        end2: Point2<T>,
+        axis: Axis,
+    ) -> Result<BresenhamZip<T>, Error<T>> {
+        if !fits_lines(&start, &[end1, end2], 2) {
+            return Err(Error::Overflow);
+        }
+        Builder::new()
+            .axis(axis)
+            .start_point(start)
+            .first_ending_point(end1)
+            .second_ending_point(end2)
+            .build()
+    }
+}
+
+impl<T: SignedNum, A: AxisSelect> BresenhamZip<T, A> {
+    #[inline]
+    pub(crate) fn with_axis(
+        start: Point2<T>,
+        end1: Point2<T>,
+        end2: Point2<T>,
+        axis: A,
+    ) -> BresenhamZip<T, A> {
+        let edges = [
+            Edge::new(start, end1, axis, true),
+            Edge::new(start, end2, axis, true),
+            Edge::new(end1, start, axis, false),
+            Edge::new(end2, start, axis, false),
+        ];
         Self {
-            a: Bresenham::new(start, end1),
-            b: Bresenham::new(start, end2),
-            prev_a: start,
-            prev_b: start,
-            goal: end1.nth(axis),
+            start,
+            end_a: end1,
+            end_b: end2,
+            core: ZipCore::new(edges, start.nth(axis.index()), end1.nth(axis.index())),
             axis,
         }
     }
+
+    /// Turns the zip into an iterator over every point between each pair of points,
+    /// both included.
+    pub fn pixels(self) -> FillIterator<Self, Point2<T>> {
+        FillIterator::new(self)
+    }
+
+    /// Turns the zip into an iterator yielding the pairs as the point type `P`, like a vertex
+    /// type implementing [Point2Like](crate::Point2Like), see [PairsAs]
+    pub fn pairs_as<P: FromTuple<Point2<T>>>(self) -> PairsAs<Self, P> {
+        PairsAs::new(self)
+    }
+
+    /// Turns the zip into an iterator yielding the [Barycentric](crate::barycentric::Barycentric)
+    /// coordinates of each point along with it, see [BarycentricIterator]
+    pub fn with_barycentric(self) -> BarycentricIterator<Self, Point2<T>> {
+        let vertices = self.vertices();
+        BarycentricIterator::new(self, vertices)
+    }
+
+    /// Turns the zip into one yielding the depth of each point as its third coordinate, see
+    /// [DepthZip]
+    ///
+    /// * `depths` - Depth of the starting point and both ending points, in that order
+    ///
+    pub fn with_depth(self, depths: [T; 3]) -> DepthZip<Self> {
+        let vertices = self.vertices();
+        DepthZip::new(self, vertices, depths)
+    }
+
+    /// Turns the zip into one yielding at most `fuel` pairs, a hard bound on the iterations of
+    /// the loops driving it, see [Fueled]
+    pub fn with_fuel(self, fuel: usize) -> Fueled<Self> {
+        Fueled::new(self, fuel)
+    }
+
+    /// Turns the zip into an iterator over the [Span](crate::span::Span)s defined by each pair
+    /// of points
+    pub fn spans(self) -> SpanIterator<Self> {
+        let axis = self.axis.index();
+        SpanIterator::new(self, axis)
+    }
+
+    /// Turns the zip into an iterator over its spans along with the position of their axis value
+    /// counted from the lowest one, like the rows of a buffer covering the zip, see
+    /// [EnumerateAxis]
+    pub fn enumerate_axis(self) -> EnumerateAxis<Self, T> {
+        let axis = self.axis.index();
+        let origin = self.bounding_box().map_or(T::zero(), |(min, _)| min.nth(axis));
+        EnumerateAxis::new(self.spans(), origin)
+    }
+
+    /// Fills the spans of the triangle in the sink with the value, see
+    /// [rasterize_into](SpanIterator::rasterize_into)
+    pub fn rasterize_into<V: Copy, S: SpanSink<T, V>>(self, sink: &mut S, value: V) {
+        self.spans().rasterize_into(sink, value);
+    }
+
+    /// Collects the [Span](crate::span::Span)s defined by each pair of points. Requires the
+    /// `alloc` feature.
+    #[cfg(feature = "alloc")]
+    pub fn collect_spans(self) -> alloc::vec::Vec<crate::span::Span<T>> {
+        self.spans().collect()
+    }
+
+    /// Collects every point between each pair of points, both included. Requires the `alloc`
+    /// feature.
+    #[cfg(feature = "alloc")]
+    pub fn collect_pixels(self) -> alloc::vec::Vec<Point2<T>> {
+        let mut pixels = alloc::vec::Vec::with_capacity(self.pixel_count());
+        pixels.extend(self.pixels());
+        pixels
+    }
+
+    /// Clips each pair of points to the rectangle or box defined by its lowest and highest
+    /// corners, see [ClipRect]
+    pub fn clip_rect(self, aabb: Aabb<Point2<T>>) -> ClipRect<Self, Point2<T>> {
+        ClipRect::new(self, aabb)
+    }
+
+    /// Returns the pair of points of the triangle in the axis value `value`, or `None` if the
+    /// value is out of the triangle. The pair is computed from the slopes of the lines without
+    /// walking them, and regardless of the pairs already yielded, so it can be used for queries
+    /// like collision checks. The pairs of the zips starting at the flat edge are the ones
+    /// yielded walking the lines from the ending points, like when iterating from the back.
+    ///
+    /// ```
+    /// # use bresenham_zip::{Axis, zip::BresenhamZip};
+    /// let zip = BresenhamZip::from_vertices((50, 0), (0, 100), (100, 100), Axis::Y).unwrap();
+    /// assert_eq!(zip.span_at(50), Some(((25, 50), (75, 50))));
+    /// assert_eq!(zip.span_at(101), None);
+    /// ```
+    ///
+    /// * `value` - Axis value of the pair
+    ///
+    pub fn span_at(&self, value: T) -> Option<(Point2<T>, Point2<T>)> {
+        let axis = self.axis.index();
+        let (from, to) = (self.start.nth(axis), self.end_a.nth(axis));
+        if value < from.min(to) || value > from.max(to) {
+            return None;
+        }
+        // the zips starting at the flat edge walk the lines from the ending points
+        let point_at = |end| if self.core.reversed {
+            line_point_at(end, self.start, axis, value, false)
+        } else {
+            line_point_at(self.start, end, axis, value, true)
+        };
+        Some((point_at(self.end_a), point_at(self.end_b)))
+    }
+
+    /// Returns the lowest and highest corners of the smallest rectangle containing the pairs left
+    /// to yield, or `None` if the zip is exhausted. Both lines are monotonic, so only the pairs
+    /// at both ends are computed and the zip isn't consumed.
+    ///
+    /// ```
+    /// # use bresenham_zip::{Axis, zip::BresenhamZip};
+    /// let zip = BresenhamZip::from_vertices((50, 0), (0, 100), (100, 100), Axis::Y).unwrap();
+    /// assert_eq!(zip.bounding_box(), Some(((0, 0), (100, 100))));
+    /// ```
+    pub fn bounding_box(&self) -> Option<Aabb<Point2<T>>> {
+        Some(bounds(&self.core.end_points()?))
+    }
+
+    /// Returns the number of points between each pair left to yield, both included, so the
+    /// buffers to fill can be allocated beforehand. The zip isn't consumed.
+    pub fn pixel_count(&self) -> usize {
+        self.clone().map(|(a, b)| line_length(&a, &b)).sum()
+    }
+
+    /// Returns the position of the zip, so the iteration can be resumed later with
+    /// [restore_state](Self::restore_state)
+    pub fn save_state(&self) -> ZipState<T> {
+        self.core.save_state()
+    }
+
+    /// Advances the zip to the position saved in `state`. The zip must be built with the same
+    /// points than the one that saved it and it can't be ahead of the saved position, which is
+    /// checked in the debug builds.
+    ///
+    /// * `state` - Position returned by [save_state](Self::save_state)
+    ///
+    pub fn restore_state(&mut self, state: ZipState<T>) {
+        self.core.restore_state(state);
+    }
+
+    /// Skips the pairs before the axis value `value`, so the next pair yielded is the one in that
+    /// value. If the value was already yielded, nothing is skipped, and if it's out of the
+    /// triangle past its end, the zip is exhausted.
+    ///
+    /// * `value` - Axis value of the next pair to yield
+    ///
+    pub fn advance_to(&mut self, value: T) {
+        self.core.advance_to(value);
+    }
+
+    /// Splits the zip into two independent zips, the first one yielding the pairs before the
+    /// axis value `value` and the second one yielding the rest, starting at that value. This way
+    /// the work can be divided between several threads.
+    ///
+    /// * `value` - Axis value of the first pair of the second zip
+    ///
+    pub fn split_at(self, value: T) -> (Self, Self) {
+        let mut second = self.clone();
+        let (first, rest) = self.core.split_at(value);
+        second.core = rest;
+        (Self { core: first, ..self }, second)
+    }
+
+    /// Returns the starting point and both ending points of the zip
+    pub(crate) fn vertices(&self) -> [Point2<T>; 3] {
+        [self.start, self.end_a, self.end_b]
+    }
+
+    /// Returns the axis value of the pair yielded `n` positions ahead
+    #[cfg(feature = "rayon")]
+    pub(crate) fn value_at(&self, n: usize) -> T {
+        self.core.value_at(n)
+    }
+
+    /// Turns the zip around, so it's yielded from the ending points to the starting point
+    pub(crate) fn reversed(mut self) -> Self {
+        self.core.reversed = true;
+        self
+    }
+
+    /// Skips the pairs at both ends of the zip out of the axis values between `min` and `max`
+    pub(crate) fn clamp(&mut self, min: T, max: T) {
+        self.core.clamp(min, max);
+    }
+
+    /// Calls `f` with every pair left, walking the edges directly instead of yielding the pairs
+    fn run<F: FnMut(Point2<T>, Point2<T>)>(self, f: F) {
+        self.core.run(f);
+    }
 }
 
-impl<T: SignedNum> Iterator for BresenhamZip<T> {
+impl<T: SignedNum, A: AxisSelect> Iterator for BresenhamZip<T, A> {
     type Item = (Point2<T>, Point2<T>);
 
-    #[allow(clippy::while_let_on_iterator)] // needs to be like that to keep using the iterator
     fn next(&mut self) -> Option<Self::Item> {
-        let axis = self.axis;
-
-        let mut a = None;
-        while let Some(point) = self.a.next() {
-            if (point.nth(axis) - self.prev_a.nth(axis)).abs() > T::zero() {
-                a = Some(self.prev_a);
-                self.prev_a = point;
-                break;
-            }
-            self.prev_a = point;
-        }
+        self.core.next()
+    }
 
-        let mut b = None;
-        while let Some(point) = self.b.next() {
-            if (point.nth(axis) - self.prev_b.nth(axis)).abs() > T::zero() {
-                b = Some(self.prev_b);
-                self.prev_b = point;
-                break;
-            }
-            self.prev_b = point;
-        }
-        if b.is_some() {
-            if let Some(point) = a {
-                Some((point, b.unwrap()))
-            } else if self.prev_a.nth(axis) == self.goal {
-                self.goal -= T::one();
-                Some((self.prev_a, self.prev_b))
-            } else {
-                None
-            }
-        }
-		else{
-			None
-		}
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.core.len(), Some(self.core.len()))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.core.nth(n)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 }
 
-impl<T: SignedNum> Debug for BresenhamZip<T> {
+impl<T: SignedNum, A: AxisSelect> ExactSizeIterator for BresenhamZip<T, A> {}
+
+/// Iterating from the back walks both lines from the ending points, so when the slope of a line
+/// leaves a tie, the point yielded for an axis value can differ by one unit from the one yielded
+/// iterating from the front.
+impl<T: SignedNum, A: AxisSelect> DoubleEndedIterator for BresenhamZip<T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.core.next_back()
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.core.nth_back(n)
+    }
+}
+
+impl<T: SignedNum, A: AxisSelect> Debug for BresenhamZip<T, A> {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (a, b) = self.core.heads();
         write!(
             f,
             "BresenhamZip [ ({:?}, {:?}), ({:?}, {:?}) ]. Goal: {:?}",
-            self.prev_a.0, self.prev_a.1, self.prev_b.0, self.prev_b.1, self.goal
+            a.0, a.1, b.0, b.1, self.core.goal()
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: SignedNum + defmt::Format, A: AxisSelect> defmt::Format for BresenhamZip<T, A> {
+    fn format(&self, f: defmt::Formatter) {
+        let (a, b) = self.core.heads();
+        defmt::write!(
+            f,
+            "BresenhamZip [ ({}, {}), ({}, {}) ]. Goal: {}",
+            a.0,
+            a.1,
+            b.0,
+            b.1,
+            self.core.goal()
         )
     }
 }
 
+/// Returns the point of the line `start -> end` with the value `value` in the axis. When the line
+/// has several points with that value, the last one walked from `start` is returned if `last`, or
+/// the first one otherwise, like the edges of the zip.
+fn line_point_at<T: SignedNum>(
+    start: Point2<T>,
+    end: Point2<T>,
+    axis: u8,
+    value: T,
+    last: bool,
+) -> Point2<T> {
+    let other = 1 - axis;
+    let length = (wide(end.nth(axis)) - wide(start.nth(axis))).abs();
+    if length == 0 {
+        return if last { end } else { start };
+    }
+    let travelled = (wide(value) - wide(start.nth(axis))).abs();
+    let delta = wide(end.nth(other)) - wide(start.nth(other));
+    let distance = delta.abs();
+    // Bresenham truncates the minor offset, so when the axis is the minor one, the last point
+    // is the one before the offset reaches the next axis value, and the first one is the one
+    // where it reaches the current value. The products are widened so they can't overflow.
+    let offset = if length >= distance {
+        floor_div(travelled * distance, length)
+    } else if last {
+        floor_div((travelled + 1) * distance - 1, length).min(distance)
+    } else {
+        floor_div(travelled * distance + length - 1, length)
+    };
+    let mut point = start;
+    point.set_nth(axis, value);
+    point.set_nth(other, narrow(wide(start.nth(other)) + delta.signum() * offset));
+    point
+}
+
+/// Runs the whole zip of the triangle calling `f` with each pair of points, in the same order
+/// as iterating it. The axis is the one shared by the ending points, like with
+/// [Builder::infer_axis]. Taking a closure lets the compiler inline the body filling each span.
+///
+/// ```
+/// # use bresenham_zip::zip::rasterize;
+/// let mut pixels = 0;
+/// rasterize((50, 50), (0, 100), (100, 100), |left, right| pixels += right.0 - left.0 + 1)?;
+/// assert_eq!(pixels, 51 * 51);
+/// # Ok::<(), bresenham_zip::Error<i32>>(())
+/// ```
+///
+/// * `start` - Starting point of the zip
+/// * `end1`, `end2` - Ending points of the zip, which must share the value of exactly one axis
+/// * `f` - Function called with the point of each line for every value of the axis
+///
+/// # Error
+/// The same errors of [Builder::build] can be generated.
+///
+pub fn rasterize<T, F>(
+    start: Point2<T>,
+    end1: Point2<T>,
+    end2: Point2<T>,
+    f: F,
+) -> Result<(), Error<T>>
+where
+    T: SignedNum,
+    F: FnMut(Point2<T>, Point2<T>),
+{
+    Builder::new()
+        .infer_axis()
+        .start_point(start)
+        .first_ending_point(end1)
+        .second_ending_point(end2)
+        .build()?
+        .run(f);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::BresenhamZip;
@@ -150,6 +547,241 @@ mod tests {
         };
     }
 
+    #[test]
+    fn static_axis() {
+        use crate::{AxisX, AxisY};
+
+        let dynamic = BresenhamZip::new((50, 50), (0, 400), (800, 400), 1);
+        let fixed = BresenhamZip::with_axis((50, 50), (0, 400), (800, 400), AxisY);
+        assert!(dynamic.eq(fixed));
+        let dynamic = BresenhamZip::new((50, 50), (400, 0), (400, 800), 0);
+        let fixed = BresenhamZip::with_axis((50, 50), (400, 0), (400, 800), AxisX);
+        assert!(dynamic.eq(fixed));
+    }
+
+    #[test]
+    fn reversed() {
+        let mut zip = BresenhamZip::new((50, 50), (0, 100), (100, 100), 1).rev();
+        assert_eq!(zip.next(), Some(((0, 100), (100, 100))));
+        let mut y = 99;
+        for (a, b) in zip {
+            assert_eq!((a, b), ((50 - (y - 50), y), (y, y)));
+            y -= 1;
+        }
+        assert_eq!(y, 49);
+    }
+
+    #[test]
+    fn both_ends() {
+        let mut zip = BresenhamZip::new((50, 50), (0, 0), (100, 0), 1);
+        assert_eq!(zip.next(), Some(((50, 50), (50, 50))));
+        assert_eq!(zip.next_back(), Some(((0, 0), (100, 0))));
+        assert_eq!(zip.next_back(), Some(((1, 1), (99, 1))));
+        assert_eq!(zip.by_ref().count(), 48);
+        assert_eq!(zip.next(), None);
+        assert_eq!(zip.next_back(), None);
+
+        let mut zip = BresenhamZip::new((0, 0), (-5, 0), (5, 0), 1);
+        assert_eq!(zip.next_back(), Some(((-5, 0), (5, 0))));
+        assert_eq!(zip.next(), None);
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut zip = BresenhamZip::new((50, 50), (0, 0), (100, 0), 1);
+        assert_eq!(zip.len(), 51);
+        zip.next();
+        zip.next_back();
+        assert_eq!(zip.size_hint(), (49, Some(49)));
+        assert_eq!(zip.by_ref().count(), 49);
+        assert_eq!(zip.len(), 0);
+        assert_eq!(BresenhamZip::new((0, 0), (-5, 0), (5, 0), 1).len(), 1);
+    }
+
+    #[test]
+    fn cloned() {
+        let mut zip = BresenhamZip::new((50, 50), (0, 100), (100, 100), 1);
+        zip.next();
+        let copy = zip.clone();
+        assert!(copy == zip);
+        assert!(copy.eq(zip));
+    }
+
+    #[test]
+    fn checkpoint() {
+        let mut zip = BresenhamZip::new((50, 50), (0, 0), (100, 0), 1);
+        zip.by_ref().take(20).for_each(drop);
+        zip.next_back();
+        let state = zip.save_state();
+
+        let mut resumed = BresenhamZip::new((50, 50), (0, 0), (100, 0), 1);
+        resumed.restore_state(state);
+        assert_eq!(resumed.save_state(), state);
+        assert_eq!(resumed, zip);
+
+        zip.by_ref().for_each(drop);
+        let mut resumed = BresenhamZip::new((50, 50), (0, 0), (100, 0), 1);
+        resumed.restore_state(zip.save_state());
+        assert_eq!(resumed.next(), None);
+    }
+
+    #[test]
+    fn from_vertices() {
+        use crate::{Axis, Error};
+
+        let expected = BresenhamZip::new((50, 50), (0, 100), (100, 100), 1);
+        let zip = BresenhamZip::from_vertices((0, 100), (100, 100), (50, 50), Axis::Y).unwrap();
+        assert_eq!(zip, expected);
+        let zip = BresenhamZip::from_vertices((0, 100), (50, 50), (100, 100), Axis::Y).unwrap();
+        assert_eq!(zip, expected);
+        let mut zip = BresenhamZip::from_vertices((0, 0), (50, 50), (0, 100), Axis::X).unwrap();
+        assert_eq!(zip.next_back(), Some(((0, 0), (0, 100))));
+        let zip = BresenhamZip::from_vertices((0, 0), (50, 50), (0, 100), Axis::Y);
+        assert_eq!(zip.unwrap_err(), Error::InvalidY(50, 100));
+        let zip = BresenhamZip::from_vertices((0, 0), (50, 50), (0, 100), Axis::Z);
+        assert_eq!(zip.unwrap_err(), Error::MissingAxis);
+    }
+
+    #[test]
+    fn checked() {
+        use crate::{Axis, Error};
+
+        let zip = BresenhamZip::<i8>::try_new_checked((0, 0), (-63, 63), (63, 63), Axis::Y);
+        assert_eq!(zip.unwrap().last(), Some(((-63, 63), (63, 63))));
+        let zip = BresenhamZip::<i8>::try_new_checked((0, 0), (-63, 64), (63, 64), Axis::Y);
+        assert_eq!(zip.unwrap_err(), Error::Overflow);
+        let zip = BresenhamZip::<i16>::try_new_checked((0, i16::MIN), (-4, -8), (4, -8), Axis::Y);
+        assert_eq!(zip.unwrap_err(), Error::Overflow);
+        let zip = BresenhamZip::<i8>::try_new_checked((0, 0), (-4, 4), (4, 5), Axis::Y);
+        assert_eq!(zip.unwrap_err(), Error::InvalidY(4, 5));
+    }
+
+    #[test]
+    fn advance_to() {
+        let mut zip = BresenhamZip::new((50, 50), (0, 0), (100, 0), 1);
+        zip.advance_to(10);
+        assert_eq!(zip.len(), 11);
+        assert_eq!(zip.next(), Some(((10, 10), (90, 10))));
+        zip.advance_to(20);
+        assert_eq!(zip.next(), Some(((9, 9), (91, 9))));
+        zip.advance_to(-1);
+        assert_eq!(zip.next(), None);
+    }
+
+    #[test]
+    fn nth() {
+        let zip = BresenhamZip::new((0, 0), (-37, 11), (23, 11), 1);
+        for n in 0..13 {
+            let (mut stepped, mut skipped) = (zip.clone(), zip.clone());
+            for _ in 0..n {
+                stepped.next();
+            }
+            assert_eq!(skipped.nth(n), stepped.next());
+            assert!(skipped.eq(stepped));
+
+            let (mut stepped, mut skipped) = (zip.clone(), zip.clone());
+            for _ in 0..n {
+                stepped.next_back();
+            }
+            assert_eq!(skipped.nth_back(n), stepped.next_back());
+            assert!(skipped.rev().eq(stepped.rev()));
+        }
+        assert_eq!(zip.clone().count(), 12);
+        assert_eq!(zip.clone().last(), Some(((-37, 11), (23, 11))));
+        assert_eq!(zip.clone().rev().nth(11), Some(((0, 0), (0, 0))));
+        assert_eq!(zip.step_by(5).nth(2), Some(((-36, 10), (22, 10))));
+    }
+
+    #[test]
+    fn split_at() {
+        let zip = BresenhamZip::new((50, 50), (0, 0), (100, 0), 1);
+        let (first, second) = zip.clone().split_at(20);
+        assert_eq!((first.len(), second.len()), (30, 21));
+        assert_eq!(second.clone().next(), Some(((20, 20), (80, 20))));
+        assert!(first.chain(second).eq(zip.clone()));
+        let (first, second) = zip.clone().split_at(60);
+        assert_eq!((first.len(), second.len()), (0, 51));
+        let (first, second) = zip.split_at(-1);
+        assert_eq!((first.len(), second.len()), (51, 0));
+    }
+
+    #[test]
+    fn span_at() {
+        let triangles = [
+            ((50, 50), (0, 0), (100, 0), 1),
+            ((0, 0), (-37, 11), (23, 11), 1),
+            ((3, -2), (20, 40), (20, -30), 0),
+            ((0, 0), (-5, 0), (5, 0), 1),
+        ];
+        for (start, end_a, end_b, axis) in triangles {
+            let zip = BresenhamZip::new(start, end_a, end_b, axis);
+            for zip in [zip.clone(), zip.reversed()] {
+                for (a, b) in zip.clone() {
+                    let value = if axis == 0 { a.0 } else { a.1 };
+                    assert_eq!(zip.span_at(value), Some((a, b)));
+                }
+            }
+        }
+        let zip = BresenhamZip::new((0, 0), (-37, 11), (23, 11), 1);
+        assert_eq!(zip.span_at(0), Some(((-3, 0), (2, 0))));
+        assert_eq!(zip.span_at(11), Some(((-37, 11), (23, 11))));
+        assert_eq!(zip.span_at(-1), None);
+        let zip = BresenhamZip::<i16>::new((0, 0), (-300, 300), (300, 300), 1);
+        assert!(zip.clone().eq((0..=300).filter_map(|value| zip.span_at(value))));
+        let zip = BresenhamZip::new((0, 0), (-100000, 100000), (100000, 100000), 1);
+        assert!(zip.clone().eq((0..=100000).filter_map(|value| zip.span_at(value))));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut zip = BresenhamZip::new((0, 0), (-37, 11), (23, 11), 1);
+        assert_eq!(zip.bounding_box(), Some(((-37, 0), (23, 11))));
+        assert_eq!(zip.pixel_count(), zip.clone().pixels().count());
+        zip.nth(4);
+        zip.next_back();
+        let (min, max) = zip.bounding_box().unwrap();
+        for (a, b) in zip.clone() {
+            assert!(min.0 <= a.0 && b.0 <= max.0 && min.1 <= a.1 && b.1 <= max.1);
+        }
+        assert!(zip.clone().any(|(a, _)| a.0 == min.0) && zip.clone().any(|(_, b)| b.0 == max.0));
+        assert_eq!(zip.pixel_count(), zip.clone().pixels().count());
+        zip.by_ref().for_each(drop);
+        assert_eq!((zip.bounding_box(), zip.pixel_count()), (None, 0));
+    }
+
+    #[test]
+    fn rasterize() {
+        use super::Builder;
+
+        let (start, end1, end2) = ((30, -7), (-40, 60), (90, 60));
+        let mut zip = BresenhamZip::new(start, end1, end2, 1);
+        let mut pairs = zip.clone();
+        super::rasterize(start, end1, end2, |a, b| assert_eq!(pairs.next(), Some((a, b)))).unwrap();
+        assert_eq!(pairs.next(), None);
+        zip.nth(10);
+        zip.nth_back(20);
+        let mut count = 0;
+        zip.clone().run(|a, b| {
+            assert_eq!(zip.next(), Some((a, b)));
+            count += 1;
+        });
+        assert_eq!((count, zip.next()), (36, None));
+        let mut zip = Builder::new().direction(crate::Direction::FromBase).start_point(start)
+            .first_ending_point(end1).second_ending_point(end2).infer_axis().build().unwrap();
+        zip.next();
+        let mut pairs = zip.clone();
+        zip.run(|a, b| assert_eq!(pairs.next(), Some((a, b))));
+        assert_eq!(pairs.next(), None);
+        let error = super::rasterize(start, end1, (90, 61), |_, _| ());
+        assert_eq!(error, Err(crate::Error::MissingAxis));
+    }
+
+    #[test]
+    fn flat_edge() {
+        let last = BresenhamZip::new((50, 50), (0, 100), (100, 100), 1).next_back();
+        assert_eq!(last, Some(((0, 100), (100, 100))));
+    }
+
     mod x_axis {
         use super::BresenhamZip;
 
@@ -0,0 +1,206 @@
+//! Contains the anti-aliased variant of the two-dimensional zip
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
+use crate::util::{count_between, floor_div, narrow, wide, Point};
+use crate::{Point2, SignedNum};
+
+/// Point of an edge of the triangle along with the portion of it covered by the triangle, from
+/// 0 to 255
+pub type Coverage<T> = (Point2<T>, u8);
+
+/// Zip yielding, along with the points of both lines, how much of each of those pixels is covered
+/// by the triangle, in the style of the Xiaolin Wu's line algorithm. This way the edges of the
+/// triangle can be alpha-blended instead of being hard-stepped.
+///
+/// Pixels are considered squares of size one centered in their coordinates. For each value of the
+/// axis, the pixel yielded for a line is the closest one to the exact crossing of the line, and
+/// its coverage is the part of it between the crossing and the inside of the triangle. A line
+/// crossing the center of a pixel covers half of it.
+///
+/// ```
+/// # use std::error::Error;
+/// # pub fn main() -> Result<(), Box<dyn Error>> {
+/// let zip = bresenham_zip::zip::Builder::new()
+///   .axis(bresenham_zip::Axis::Y)
+///   .start_point((50, 50))
+///   .first_ending_point((0, 100))
+///   .second_ending_point((100, 100))
+///   .build_antialiased()?;
+/// for ((left, left_alpha), (right, right_alpha)) in zip {
+///   assert_eq!(left.1, right.1);
+///   assert!(left_alpha > 0 && right_alpha > 0);
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct AntialiasedZip<T> {
+	start: Point2<T>,
+	end_a: Point2<T>,
+	end_b: Point2<T>,
+	length: T,
+	travelled: T,
+	step: T,
+	done: bool,
+	axis: u8,
+}
+
+impl<T: SignedNum> AntialiasedZip<T> {
+
+	pub(crate) fn new(start: Point2<T>, end_a: Point2<T>, end_b: Point2<T>, axis: u8) -> Self {
+		let length = end_a.nth(axis) - start.nth(axis);
+		Self {
+			start,
+			end_a,
+			end_b,
+			length: length.abs(),
+			travelled: T::zero(),
+			step: if length < T::zero() { -T::one() } else { T::one() },
+			done: false,
+			axis,
+		}
+	}
+
+	/// Returns the crossing of the line towards `end` with the current axis value
+	fn crossing(&self, end: Point2<T>) -> Crossing<T> {
+		let other = 1 - self.axis;
+		let two = T::one() + T::one();
+		let origin = self.start.nth(other);
+		let (delta, length) = if self.length == T::zero() {
+			(end.nth(other) - origin, T::one())
+		} else {
+			((end.nth(other) - origin) * self.travelled, self.length)
+		};
+		// the exact crossing is origin + delta / length, rounded to the closest pixel
+		let offset = floor_div(two * delta + length, two * length);
+		Crossing {
+			pixel: origin + offset,
+			delta: wide(delta),
+			offset: wide(offset),
+			length: wide(length),
+		}
+	}
+
+}
+
+/// Crossing of a line with an axis value, placed at `delta / length` from the starting point
+/// and rounded to the pixel at `offset`
+struct Crossing<T> {
+	pixel: T,
+	delta: i128,
+	offset: i128,
+	length: i128,
+}
+
+impl<T> Crossing<T> {
+
+	/// Returns the portion of the pixel covered by the triangle, which lies on the side of the
+	/// highest values if `low` or on the side of the lowest ones otherwise
+	fn coverage(&self, low: bool) -> u8 {
+		let (delta, offset, length) = (self.delta, self.offset, self.length);
+		let inside = if low {
+			2 * offset * length + length - 2 * delta
+		} else {
+			2 * delta - 2 * offset * length + length
+		};
+		((255 * inside + length) / (2 * length)) as u8
+	}
+
+}
+
+impl<T: SignedNum> Iterator for AntialiasedZip<T> {
+	type Item = (Coverage<T>, Coverage<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let other = 1 - self.axis;
+		let (a, b) = (self.crossing(self.end_a), self.crossing(self.end_b));
+		let a_is_low = match a.delta.cmp(&b.delta) {
+			Ordering::Equal => self.end_a.nth(other) <= self.end_b.nth(other),
+			ordering => ordering == Ordering::Less,
+		};
+
+		let value = self.start.nth(self.axis) + self.step * self.travelled;
+		let (mut point_a, mut point_b) = (self.start, self.start);
+		point_a.set_nth(self.axis, value);
+		point_b.set_nth(self.axis, value);
+		point_a.set_nth(other, a.pixel);
+		point_b.set_nth(other, b.pixel);
+
+		if self.travelled == self.length {
+			self.done = true;
+		} else {
+			self.travelled += T::one();
+		}
+		Some(((point_a, a.coverage(a_is_low)), (point_b, b.coverage(!a_is_low))))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = if self.done { 0 } else { count_between(self.travelled, self.length) };
+		(remaining, Some(remaining))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.len() {
+			self.done = true;
+			return None;
+		}
+		self.travelled += narrow(n as i128);
+		self.next()
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.nth(self.len().checked_sub(1)?)
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for AntialiasedZip<T> {}
+
+impl<T: SignedNum> Debug for AntialiasedZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "AntialiasedZip [ {:?} -> {:?}, {:?} ]. Travelled: {:?}",
+			self.start, self.end_a, self.end_b, self.travelled)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::AntialiasedZip;
+
+	#[test]
+	fn coverage() {
+		let mut zip = AntialiasedZip::new((0, 0), (-2, 4), (4, 4), 1);
+		assert_eq!(zip.len(), 5);
+		assert_eq!(zip.next(), Some((((0, 0), 128), ((0, 0), 128))));
+		assert_eq!(zip.next(), Some((((0, 1), 255), ((1, 1), 128))));
+		assert_eq!(zip.next(), Some((((-1, 2), 128), ((2, 2), 128))));
+		assert_eq!(zip.next(), Some((((-1, 3), 255), ((3, 3), 128))));
+		assert_eq!(zip.next(), Some((((-2, 4), 128), ((4, 4), 128))));
+		assert_eq!(zip.next(), None);
+	}
+
+	#[test]
+	fn partial() {
+		let mut zip = AntialiasedZip::new((0, 0), (3, -4), (-1, -4), 1);
+		let ((a, alpha_a), (b, alpha_b)) = zip.nth(1).unwrap();
+		// crossings at 0.75 and -0.25
+		assert_eq!((a, b), ((1, -1), (0, -1)));
+		assert_eq!((alpha_a, alpha_b), (64, 191));
+	}
+
+	#[test]
+	fn flat() {
+		let mut zip = AntialiasedZip::new((0, 0), (5, 0), (-5, 0), 1);
+		assert_eq!(zip.next(), Some((((5, 0), 128), ((-5, 0), 128))));
+		assert_eq!(zip.next(), None);
+	}
+
+}
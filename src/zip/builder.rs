@@ -1,14 +1,24 @@
 //! Contains the logic to build new two-dimensional BresenhamZips
 
-use crate::{Axis, Point2, SignedNum};
-use crate::util::Point;
-use crate::zip::BresenhamZip;
+use crate::{Axis, AxisSelect, BuilderOption, Direction, Error, Point2, Point2Like, PointRole};
+use crate::{SignedNum, Snap};
+use crate::util::{shared_axis, snap_axis, Point};
+use crate::clip::HalfPlane;
+use crate::triangle::Triangle2;
+use crate::zip::{AntialiasedZip, BresenhamZip, ConservativeZip, HalfPlaneZip, SupercoverZip};
+use crate::zip::TopLeftZip;
 
 const MAX_ACCEPTED_AXIS: u8 = 1;
+const INFERRED_AXIS: u8 = u8::MAX;
+
+/// Starting point and both ending points of a zip
+type Vertices<T> = (Point2<T>, Point2<T>, Point2<T>);
 
 /// Builder to construct a new [BresenhamZip]. It is required to specify the starting point and two
 /// ending points, both of them **must share the same value in the axis** of the zip to build.
 /// This axis is also required in the building pipeline.
+/// The setters with the `with_` prefix take and return the builder by value, so a configured
+/// builder can be kept without a mutable binding.
 ///
 /// ```
 /// # use std::error::Error;
@@ -21,12 +31,25 @@ const MAX_ACCEPTED_AXIS: u8 = 1;
 ///   .build()?;
 /// # Ok(()) }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Builder<T> {
 	start: Option<Point2<T>>,
 	end_a: Option<Point2<T>>,
 	end_b: Option<Point2<T>>,
-	axis: u8
+	axis: u8,
+	tolerance: T,
+	snap: Snap,
+	apex: bool,
+	flat_edge: bool,
+	sorted: bool,
+	direction: Direction,
+	clamp: Option<(T, T)>,
+}
+
+impl<T: SignedNum> Default for Builder<T> {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 impl<T: SignedNum> Builder<T> {
@@ -37,10 +60,41 @@ impl<T: SignedNum> Builder<T> {
 			start: None,
 			end_a: None,
 			end_b: None,
-			axis: MAX_ACCEPTED_AXIS + 1
+			axis: MAX_ACCEPTED_AXIS + 1,
+			tolerance: T::zero(),
+			snap: Snap::Midpoint,
+			apex: true,
+			flat_edge: true,
+			sorted: false,
+			direction: Direction::FromApex,
+			clamp: None,
 		}
 	}
 
+	/// Creates a new Builder with the vertices of a triangle with a flat edge, taking the first
+	/// pair of vertices sharing the value of an axis as the ending points. The axis of the zip is
+	/// inferred from them unless it's specified with [Builder::axis].
+	///
+	/// * `triangle` - Triangle with a flat edge to zip
+	///
+	/// ```
+	/// # use bresenham_zip::{triangle::Triangle2, zip::Builder};
+	/// let triangle = Triangle2::new((0, 100), (50, 50), (100, 100));
+	/// let mut zip = Builder::from_triangle(triangle).build().unwrap();
+	/// assert_eq!(zip.next(), Some(((50, 50), (50, 50))));
+	/// assert_eq!(zip.last(), Some(((0, 100), (100, 100))));
+	/// ```
+	pub fn from_triangle(triangle: Triangle2<T>) -> Builder<T> {
+		let Triangle2 { a, b, c } = triangle;
+		let (start, end_a, end_b) = [(a, b, c), (b, a, c), (c, a, b)].into_iter()
+			.find(|(_, end_a, end_b)| shared_axis(*end_a, *end_b).is_some())
+			.unwrap_or((a, b, c));
+		let mut builder = Self::new();
+		builder.infer_axis().start_point(start)
+			.first_ending_point(end_a).second_ending_point(end_b);
+		builder
+	}
+
 	/// Specifies the axis that will be used to generate the points of the lines during the iteration.
 	/// The returned tuples will share the same value in the specified axis.
 	///
@@ -55,30 +109,200 @@ impl<T: SignedNum> Builder<T> {
 		self
 	}
 
+	/// Deduces the axis of the zip from the ending points when building it, instead of specifying
+	/// it with [Builder::axis]. The ending points must share the value of exactly one axis.
+	///
+	/// ```
+	/// # use bresenham_zip::zip::Builder;
+	/// let mut builder = Builder::new();
+	/// builder.infer_axis().start_point((50, 50)).first_ending_point((0, 100)).second_ending_point((100, 100));
+	/// assert!(builder.build().is_ok());
+	/// ```
+	pub fn infer_axis(&mut self) -> &mut Builder<T> {
+		self.axis = INFERRED_AXIS;
+		self
+	}
+
+	/// Allows the ending points to differ in the axis of the zip up to the given tolerance.
+	/// When they differ, both of them are moved to a common value chosen with `snap`
+	/// instead of generating an error.
+	///
+	/// * `tolerance` - Maximum difference accepted between the ending points in the axis
+	/// * `snap` - Common value to move the ending points to
+	///
+	pub fn axis_tolerance(&mut self, tolerance: T, snap: Snap) -> &mut Builder<T> {
+		self.tolerance = tolerance;
+		self.snap = snap;
+		self
+	}
+
+	/// Specifies if the first pair, where both lines are at the starting point, is yielded.
+	/// It is included by default. Only the [BresenhamZip] can exclude it, building the other
+	/// zips without it fails with [Error::UnsupportedOption].
+	///
+	/// * `include` - Whether to yield the starting point
+	///
+	pub fn include_apex(&mut self, include: bool) -> &mut Builder<T> {
+		self.apex = include;
+		self
+	}
+
+	/// Specifies if the last pair, the flat edge between both ending points, is yielded. It is
+	/// included by default, excluding it avoids yielding twice the axis value shared by two
+	/// halves of a triangle. Only the [BresenhamZip] can exclude it, building the other zips
+	/// without it fails with [Error::UnsupportedOption].
+	///
+	/// * `include` - Whether to yield the flat edge
+	///
+	pub fn include_flat_edge(&mut self, include: bool) -> &mut Builder<T> {
+		self.flat_edge = include;
+		self
+	}
+
+	/// Specifies if the points of each pair are sorted, so the first one always has the lowest
+	/// value in the other axis. Otherwise, the first point of each pair belongs to the line
+	/// towards the first ending point.
+	///
+	/// * `sorted` - Whether to sort the points of each pair
+	///
+	pub fn sorted(&mut self, sorted: bool) -> &mut Builder<T> {
+		self.sorted = sorted;
+		self
+	}
+
+	/// Specifies the end of the triangle where the iteration starts, regardless of which point
+	/// is the starting one. By default, it starts at the starting point. Only the [BresenhamZip]
+	/// can start at the flat edge, building the other zips from it fails with
+	/// [Error::UnsupportedOption].
+	///
+	/// * `direction` - End of the triangle yielded first
+	///
+	pub fn direction(&mut self, direction: Direction) -> &mut Builder<T> {
+		self.direction = direction;
+		self
+	}
+
+	/// Restricts the zip to the axis values between `min` and `max`, both included, so the pairs
+	/// out of them are never yielded. Only applied building the [BresenhamZip], building the
+	/// other zips with it fails with [Error::UnsupportedOption].
+	///
+	/// * `min` - Lowest axis value to yield
+	/// * `max` - Highest axis value to yield
+	///
+	pub fn clamp_axis(&mut self, min: T, max: T) -> &mut Builder<T> {
+		self.clamp = Some((min, max));
+		self
+	}
+
 	/// Specifies the starting point for both the lines to be drawn in the BresenhamZip
 	///
-	/// * `start` - Starting point of type (T, T)
+	/// * `start` - Starting point, any [Point2Like] like (T, T)
 	///
-	pub fn start_point(&mut self, start: Point2<T>) -> &mut Builder<T> {
-		self.start = Some(start);
+	pub fn start_point<P: Point2Like<T>>(&mut self, start: P) -> &mut Builder<T> {
+		self.start = Some(start.to_tuple());
 		self
 	}
 
 	/// Specifies the ending point for one of the lines to be drawn in the BresenhamZip
 	///
-	/// * `end` - Ending point of one line, must be of type (T, T)
+	/// * `end` - Ending point of one line, any [Point2Like] like (T, T)
 	///
-	pub fn first_ending_point(&mut self, end: Point2<T>) -> &mut Builder<T> {
-		self.end_a = Some(end);
+	pub fn first_ending_point<P: Point2Like<T>>(&mut self, end: P) -> &mut Builder<T> {
+		self.end_a = Some(end.to_tuple());
 		self
 	}
 
 	/// Specifies the ending point for one of the lines to be drawn in the BresenhamZip
 	///
-	/// * `end` - Ending point of one line, must be of type (T, T)
+	/// * `end` - Ending point of one line, any [Point2Like] like (T, T)
+	///
+	pub fn second_ending_point<P: Point2Like<T>>(&mut self, end: P) -> &mut Builder<T> {
+		self.end_b = Some(end.to_tuple());
+		self
+	}
+
+	/// Returns the builder with another starting point, keeping the rest of the configuration.
+	/// As the builders are [Copy], a configured builder can create the zips of many triangles.
+	///
+	/// * `start` - Starting point, any [Point2Like] like (T, T)
+	///
+	pub fn with_start<P: Point2Like<T>>(mut self, start: P) -> Self {
+		self.start_point(start);
+		self
+	}
+
+	/// Returns the builder with another first ending point, keeping the rest of the configuration
+	///
+	/// * `end` - Ending point of one line, any [Point2Like] like (T, T)
 	///
-	pub fn second_ending_point(&mut self, end: Point2<T>) -> &mut Builder<T> {
-		self.end_b = Some(end);
+	pub fn with_first_end<P: Point2Like<T>>(mut self, end: P) -> Self {
+		self.first_ending_point(end);
+		self
+	}
+
+	/// Returns the builder with another second ending point, keeping the rest of the configuration
+	///
+	/// * `end` - Ending point of one line, any [Point2Like] like (T, T)
+	///
+	pub fn with_second_end<P: Point2Like<T>>(mut self, end: P) -> Self {
+		self.second_ending_point(end);
+		self
+	}
+
+	/// Same as [Builder::axis], taking and returning the builder by value
+	pub fn with_axis(mut self, axis: Axis) -> Self {
+		self.axis(axis);
+		self
+	}
+
+	/// Same as [Builder::infer_axis], taking and returning the builder by value
+	pub fn with_inferred_axis(mut self) -> Self {
+		self.infer_axis();
+		self
+	}
+
+	/// Same as [Builder::axis_tolerance], taking and returning the builder by value
+	pub fn with_axis_tolerance(mut self, tolerance: T, snap: Snap) -> Self {
+		self.axis_tolerance(tolerance, snap);
+		self
+	}
+
+	/// Same as [Builder::include_apex], taking and returning the builder by value
+	pub fn with_apex(mut self, include: bool) -> Self {
+		self.include_apex(include);
+		self
+	}
+
+	/// Same as [Builder::include_flat_edge], taking and returning the builder by value
+	pub fn with_flat_edge(mut self, include: bool) -> Self {
+		self.include_flat_edge(include);
+		self
+	}
+
+	/// Same as [Builder::sorted], taking and returning the builder by value
+	pub fn with_sorted(mut self, sorted: bool) -> Self {
+		self.sorted(sorted);
+		self
+	}
+
+	/// Same as [Builder::direction], taking and returning the builder by value
+	pub fn with_direction(mut self, direction: Direction) -> Self {
+		self.direction(direction);
+		self
+	}
+
+	/// Same as [Builder::clamp_axis], taking and returning the builder by value
+	pub fn with_clamp_axis(mut self, min: T, max: T) -> Self {
+		self.clamp_axis(min, max);
+		self
+	}
+
+	/// Removes the three points, keeping the axis and the rest of the configuration to build
+	/// the zip of another triangle
+	pub fn reset(&mut self) -> &mut Builder<T> {
+		self.start = None;
+		self.end_a = None;
+		self.end_b = None;
 		self
 	}
 
@@ -87,56 +311,476 @@ impl<T: SignedNum> Builder<T> {
 	/// # Error
 	/// This call can generate the following errors
 	///
-	/// * [Error::MissingAxis], if no axis was specified.
+	/// * [Error::MissingAxis], if no axis was specified or it was [Axis::Z], or if it had to be
+	///   inferred and the ending points don't share exactly one axis.
 	/// * [Error::MissingPoint], if any of the three points is missing.
 	/// * [Error::InvalidX], if the axis is X and the two ending points have divergent X values.
 	/// * [Error::InvalidY], if the axis is Y and the two ending points have divergent Y values.
 	///
-	pub fn build<'a, 'b>(&'b self) -> BresenhamZip<T> {
-		if self.axis > MAX_ACCEPTED_AXIS {
-			
+	/// The ending points are divergent when they differ by more than the
+	/// [axis tolerance](Builder::axis_tolerance).
+	///
+	pub fn build(&self) -> Result<BresenhamZip<T>, Error<T>> {
+		self.build_along(self.resolved_axis()?)
+	}
+
+	/// Builds the BresenhamZip corresponding with the defined arguments, travelling the axis
+	/// selected at compile time with one of the [AxisSelect] markers. The axis specified with
+	/// [Builder::axis], if any, is ignored.
+	///
+	/// ```
+	/// # use std::error::Error;
+	/// # pub fn main() -> Result<(), Box<dyn Error>> {
+	/// let zip = bresenham_zip::zip::Builder::new()
+	///   .start_point((50, 50))
+	///   .first_ending_point((0, 100))
+	///   .second_ending_point((100, 100))
+	///   .build_along(bresenham_zip::AxisY)?;
+	/// # Ok(()) }
+	/// ```
+	///
+	/// # Error
+	/// The same errors of [Builder::build] can be generated, with [Error::MissingAxis] happening
+	/// when [crate::AxisZ] is selected.
+	///
+	pub fn build_along<A>(&self, axis: A) -> Result<BresenhamZip<T, A>, Error<T>>
+		where A: AxisSelect {
+		let (start, end_a, end_b) = self.points(axis.index())?;
+		let mut zip = BresenhamZip::with_axis(start, end_a, end_b, axis);
+		if !self.apex {
+			zip.next();
+		}
+		if !self.flat_edge {
+			zip.next_back();
+		}
+		if let Some((min, max)) = self.clamp {
+			zip.clamp(min, max);
+		}
+		zip.core.reversed = self.direction == Direction::FromBase;
+		Ok(zip)
+	}
+
+	/// Builds the [HalfPlaneZip] corresponding with the defined arguments, restricted to the
+	/// part of the triangle inside the half of the plane
+	///
+	/// * `plane` - Half of the plane to keep
+	///
+	/// # Error
+	/// The same errors of [Builder::build] can be generated.
+	///
+	pub fn build_half_plane(&self, plane: HalfPlane<T>) -> Result<HalfPlaneZip<T>, Error<T>> {
+		Ok(HalfPlaneZip::new(self.build()?, plane))
+	}
+
+	/// Builds the [AntialiasedZip] corresponding with the defined arguments
+	///
+	/// # Error
+	/// The same errors of [Builder::build] can be generated, and [Error::UnsupportedOption] if
+	/// an option only applied to the [BresenhamZip] is set.
+	///
+	pub fn build_antialiased(&self) -> Result<AntialiasedZip<T>, Error<T>> {
+		self.check_plain_options()?;
+		let axis = self.resolved_axis()?;
+		let (start, end_a, end_b) = self.points(axis)?;
+		Ok(AntialiasedZip::new(start, end_a, end_b, axis))
+	}
+
+	/// Builds the [TopLeftZip] corresponding with the defined arguments, which follows the top-left
+	/// fill rule so triangles sharing an edge don't rasterize it twice
+	///
+	/// # Error
+	/// The same errors of [Builder::build] can be generated, and [Error::UnsupportedOption] if
+	/// an option only applied to the [BresenhamZip] is set.
+	///
+	pub fn build_top_left(&self) -> Result<TopLeftZip<T>, Error<T>> {
+		self.check_plain_options()?;
+		let axis = self.resolved_axis()?;
+		let (start, end_a, end_b) = self.points(axis)?;
+		Ok(TopLeftZip::new(start, end_a, end_b, axis))
+	}
+
+	/// Builds the [ConservativeZip] corresponding with the defined arguments, which includes every
+	/// pixel touched by the triangle
+	///
+	/// # Error
+	/// The same errors of [Builder::build] can be generated, and [Error::UnsupportedOption] if
+	/// an option only applied to the [BresenhamZip] is set.
+	///
+	pub fn build_conservative(&self) -> Result<ConservativeZip<T>, Error<T>> {
+		self.check_plain_options()?;
+		let axis = self.resolved_axis()?;
+		let (start, end_a, end_b) = self.points(axis)?;
+		Ok(ConservativeZip::new(start, end_a, end_b, axis))
+	}
+
+	/// Builds the [SupercoverZip] corresponding with the defined arguments, which drives both
+	/// lines with a supercover traversal so they don't have diagonal gaps
+	///
+	/// # Error
+	/// The same errors of [Builder::build] can be generated, and [Error::UnsupportedOption] if
+	/// an option only applied to the [BresenhamZip] is set.
+	///
+	pub fn build_supercover(&self) -> Result<SupercoverZip<T>, Error<T>> {
+		self.check_plain_options()?;
+		let axis = self.resolved_axis()?;
+		let (start, end_a, end_b) = self.points(axis)?;
+		Ok(SupercoverZip::new(start, end_a, end_b, axis))
+	}
+
+	/// Checks that none of the options only applied to the [BresenhamZip] is set, for the zips
+	/// taking just the points and the axis
+	fn check_plain_options(&self) -> Result<(), Error<T>> {
+		let options = [
+			(self.apex, BuilderOption::Apex),
+			(self.flat_edge, BuilderOption::FlatEdge),
+			(self.direction == Direction::FromApex, BuilderOption::Direction),
+			(self.clamp.is_none(), BuilderOption::ClampAxis),
+		];
+		match options.into_iter().find(|(default, _)| !default) {
+			Some((_, option)) => Err(Error::UnsupportedOption(option)),
+			None => Ok(()),
+		}
+	}
+
+	/// Returns the specified axis, or the inferred one if it had to be inferred
+	fn resolved_axis(&self) -> Result<u8, Error<T>> {
+		if self.axis != INFERRED_AXIS {
+			return Ok(self.axis);
+		}
+		match (self.end_a, self.end_b) {
+			(Some(end_a), Some(end_b)) => shared_axis(end_a, end_b).ok_or(Error::MissingAxis),
+			// any valid axis reports the missing point
+			_ => Ok(0),
+		}
+	}
+
+	/// Returns the starting and ending points once validated for the given axis
+	fn points(&self, axis: u8) -> Result<Vertices<T>, Error<T>> {
+		if axis > MAX_ACCEPTED_AXIS {
+			return Err(Error::MissingAxis);
 		}
-		let axis = self.axis;
 
 		match (&self.start, &self.end_a, &self.end_b) {
+			(None, _, _) => Err(Error::MissingPoint(PointRole::Start)),
+			(_, None, _) => Err(Error::MissingPoint(PointRole::FirstEnding)),
+			(_, _, None) => Err(Error::MissingPoint(PointRole::SecondEnding)),
 			(Some(start), Some(end_a), Some(end_b)) => {
-				if !(end_a.nth(axis) != end_b.nth(axis)) {
-					return BresenhamZip::new(*start, *end_a, *end_b, self.axis);
-				}
-				else{
-					return BresenhamZip::new(*start, *end_a, *end_b, self.axis);
+				let snapped = snap_axis(*end_a, *end_b, axis, self.tolerance, self.snap);
+				if let Some((end_a, end_b)) = snapped {
+					if self.sorted && end_a.nth(1 - axis) > end_b.nth(1 - axis) {
+						return Ok((*start, end_b, end_a));
+					}
+					Ok((*start, end_a, end_b))
+				} else {
+					Err(match axis {
+						0 => Error::InvalidX(end_a.0, end_b.0),
+						_ => Error::InvalidY(end_a.1, end_b.1),
+					})
 				}
-			},
-			_ => panic!(),
+			}
 		}
 	}
 
 }
 
+/// Implements the conversion of the starting point and both ending points into a zip, built with
+/// the given method of the [Builder] and the axis inferred from the ending points
+macro_rules! try_from_vertices {
+	($zip:ident, $build:ident) => {
+		impl<T: SignedNum> TryFrom<Vertices<T>> for $zip<T> {
+			type Error = Error<T>;
+
+			fn try_from((start, end_a, end_b): Vertices<T>) -> Result<Self, Self::Error> {
+				Builder::new().infer_axis().start_point(start)
+					.first_ending_point(end_a).second_ending_point(end_b).$build()
+			}
+		}
+	};
+}
+
+try_from_vertices!(BresenhamZip, build);
+try_from_vertices!(AntialiasedZip, build_antialiased);
+try_from_vertices!(TopLeftZip, build_top_left);
+try_from_vertices!(ConservativeZip, build_conservative);
+try_from_vertices!(SupercoverZip, build_supercover);
+
 #[cfg(test)]
 mod test {
-	use crate::{Axis, build_zip};
-	use core::error::Error;
-	use crate::zip::Builder;
+	use crate::{Axis, AxisX, AxisY, AxisZ, build_zip, BuilderOption, Direction, Error, PointRole};
+	use crate::{rasterize, Snap};
+	use crate::triangle::Triangle2;
+	use crate::zip::{BresenhamZip, Builder, TopLeftZip};
 
 	#[test]
-	fn invalid_axis() {
+	fn from_geometry() {
+		let triangle = Triangle2::new((10, 0), (0, 10), (10, 20));
+		let zip = Builder::from_triangle(triangle).build().unwrap();
+		assert_eq!(zip.clone().next(), Some(((0, 10), (0, 10))));
+		assert_eq!(zip.last(), Some(((10, 0), (10, 20))));
+		let zip = BresenhamZip::try_from(((0, 0), (4, 4), (-4, 4))).unwrap();
+		assert!(zip.eq(build_zip!(2D:Y - (0, 0) -> (4, 4), (-4, 4)).unwrap()));
+		assert!(TopLeftZip::try_from(((0, 0), (4, 4), (-4, 4))).is_ok());
+		let zip = BresenhamZip::try_from(((0, 0), (4, 4), (5, 5)));
+		assert_eq!(zip.unwrap_err(), Error::MissingAxis);
+	}
 
+	#[test]
+	fn invalid_axis() {
+		let mut builder = Builder::new();
+		builder.start_point((0, 0)).first_ending_point((10, 10)).second_ending_point((20, 10));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingAxis);
+		assert_eq!(builder.axis(Axis::Z).build().unwrap_err(), Error::MissingAxis);
+		assert!(builder.axis(Axis::Y).build().is_ok());
 	}
 
 	#[test]
 	fn missing_point() {
-
+		let mut builder = Builder::new();
+		builder.axis(Axis::X);
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::Start));
+		builder.start_point((0, 0));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::FirstEnding));
+		builder.first_ending_point((10, 10));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::SecondEnding));
+		builder.second_ending_point((10, 20));
+		assert!(builder.build().is_ok());
 	}
 
 	#[test]
 	fn invalid_points() {
+		let zip = build_zip!(2D:X - (0, 0) -> (10, 10), (20, 10));
+		assert_eq!(zip.unwrap_err(), Error::InvalidX(10, 20));
+		let zip = build_zip!(2D:Y - (0, 0) -> (10, 10), (20, 20));
+		assert_eq!(zip.unwrap_err(), Error::InvalidY(10, 20));
+	}
 
+	#[test]
+	fn macro_expressions() {
+		let vertices = [(0, 0), (10, 10), (-10, 10)];
+		let offset = (5, -2);
+		let zip = build_zip!(2D:Y - (vertices[0].0 + offset.0, offset.1) -> vertices[1], (-10, 10));
+		assert_eq!(zip.unwrap().next(), Some(((5, -2), (4, -2))));
+		let start = vertices[0];
+		let zip = build_zip!(2D:X - start -> (10, -10), vertices[1]).unwrap();
+		assert_eq!(zip.last(), Some(((10, -10), (10, 10))));
 	}
 
 	#[test]
-	fn valid() {
+	fn macro_inference() {
+		let zip = build_zip!(2d:y - (0, 0) -> (-4, 4), (4, 4)).unwrap();
+		assert!(zip.eq(build_zip!(Y - [0, 0] -> (-4, 4), (4, 4)).unwrap()));
+		let zip = build_zip!(x - (0, 0, 0) -> (4, -4, 0), (4, 4, 8)).unwrap();
+		assert!(zip.eq(build_zip!(3D:X - (0, 0, 0) -> (4, -4, 0), (4, 4, 8)).unwrap()));
+		// the Z axis is only travelled by the 3D zips
+		let start = (0, 0, 0);
+		let zip = build_zip!(z - start -> (4, -4, 4), (4, 4, 4)).unwrap();
+		assert_eq!(zip.len(), 5);
+	}
+
+	#[test]
+	fn rasterize_macro() {
+		let mut rows = [0; 5];
+		let vertices = [(0, 0, 0), (-4, -4, 4), (4, 4, 4)];
+		let result = rasterize!(3d:z - vertices[0] -> vertices[1], vertices[2] => |a, b| {
+			rows[a.2 as usize] += b.0 - a.0 + 1;
+		});
+		assert_eq!((result, rows), (Ok(()), [1, 3, 5, 7, 9]));
+		let result = rasterize!(2D:X - (0, 0) -> (4, 4), (5, 5) => |_, _| unreachable!());
+		assert_eq!(result, Err(Error::InvalidX(4, 5)));
+	}
+
+	#[test]
+	fn inferred_axis() {
+		let mut builder = Builder::new();
+		builder.infer_axis().first_ending_point((0, 10)).second_ending_point((20, 10));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::Start));
+		let mut zip = builder.start_point((10, 0)).build().unwrap();
+		assert_eq!(zip.next_back(), Some(((0, 10), (20, 10))));
+		builder.second_ending_point((0, 20));
+		assert!(builder.build().unwrap().all(|(a, b)| a.0 == b.0));
+		builder.second_ending_point((0, 10));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingAxis);
+		builder.second_ending_point((20, 20));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingAxis);
+	}
+
+	#[test]
+	fn reusable() {
+		let mut builder = Builder::new();
+		builder.axis(Axis::Y).include_flat_edge(false).start_point((0, 0));
+		let zip = builder.with_first_end((-4, 4)).with_second_end((4, 4)).build().unwrap();
+		assert_eq!(zip.last(), Some(((-3, 3), (3, 3))));
+		let zip = builder.with_start((0, 8)).with_first_end((-4, 4)).with_second_end((4, 4));
+		assert_eq!(zip.build().unwrap().len(), 4);
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::FirstEnding));
+		builder.first_ending_point((0, 2)).second_ending_point((2, 2)).reset();
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::Start));
+		let configured = Builder::default().with_axis(Axis::X).with_sorted(true).with_apex(false);
+		let zip = configured.with_start((0, 0)).with_first_end((2, 2)).with_second_end((2, -2));
+		assert_eq!(zip.build().unwrap().next(), Some(((1, -1), (1, 1))));
+	}
+
+	#[test]
+	fn tolerance() {
+		let mut builder = Builder::new();
+		builder.axis(Axis::Y).start_point((0, 0))
+			.first_ending_point((-10, 9)).second_ending_point((10, 12));
+		assert_eq!(builder.build().unwrap_err(), Error::InvalidY(9, 12));
+		builder.axis_tolerance(2, Snap::Midpoint);
+		assert_eq!(builder.build().unwrap_err(), Error::InvalidY(9, 12));
+		builder.axis_tolerance(3, Snap::Midpoint);
+		assert_eq!(builder.build().unwrap().next_back(), Some(((-10, 10), (10, 10))));
+		builder.axis_tolerance(3, Snap::Min);
+		assert_eq!(builder.build().unwrap().next_back(), Some(((-10, 9), (10, 9))));
+		builder.axis_tolerance(3, Snap::Max);
+		assert_eq!(builder.build().unwrap().next_back(), Some(((-10, 12), (10, 12))));
+	}
+
+	#[test]
+	fn endpoints() {
+		let mut builder = Builder::new();
+		builder.axis(Axis::Y).start_point((0, 0)).first_ending_point((-4, 4)).second_ending_point((4, 4));
+		let mut zip = builder.include_apex(false).build().unwrap();
+		assert_eq!(zip.len(), 4);
+		assert_eq!(zip.next(), Some(((-1, 1), (1, 1))));
+		let mut zip = builder.include_apex(true).include_flat_edge(false).build().unwrap();
+		assert_eq!(zip.len(), 4);
+		assert_eq!(zip.next_back(), Some(((-3, 3), (3, 3))));
+		let mut zip = builder.include_apex(false).build_along(AxisY).unwrap();
+		assert_eq!((zip.next(), zip.next_back()), (Some(((-1, 1), (1, 1))), Some(((-3, 3), (3, 3)))));
+	}
+
+	#[test]
+	fn plain_options() {
+		let errors = |builder: &Builder<i32>| [
+			builder.build_antialiased().err(),
+			builder.build_top_left().err(),
+			builder.build_conservative().err(),
+			builder.build_supercover().err(),
+		];
+		let mut builder = Builder::new();
+		builder.axis(Axis::Y).start_point((0, 0))
+			.first_ending_point((-4, 4)).second_ending_point((4, 4));
+		assert_eq!(errors(&builder), [None; 4]);
+		let unsupported = Some(Error::UnsupportedOption(BuilderOption::Apex));
+		assert_eq!(errors(builder.include_apex(false)), [unsupported; 4]);
+		let unsupported = Some(Error::UnsupportedOption(BuilderOption::FlatEdge));
+		assert_eq!(errors(builder.include_apex(true).include_flat_edge(false)), [unsupported; 4]);
+		builder.include_flat_edge(true).direction(Direction::FromBase);
+		let unsupported = Some(Error::UnsupportedOption(BuilderOption::Direction));
+		assert_eq!(errors(&builder), [unsupported; 4]);
+		builder.direction(Direction::FromApex).clamp_axis(1, 3);
+		let unsupported = Some(Error::UnsupportedOption(BuilderOption::ClampAxis));
+		assert_eq!(errors(&builder), [unsupported; 4]);
+	}
+
+	#[test]
+	fn sorted() {
+		let mut builder = Builder::new();
+		builder.axis(Axis::X).start_point((0, 0)).first_ending_point((6, 2)).second_ending_point((6, -9));
+		assert!(builder.build().unwrap().skip(1).all(|(a, b)| a.1 > b.1));
+		assert!(builder.sorted(true).build().unwrap().all(|(a, b)| a.1 <= b.1));
+		let zip = builder.build_antialiased().unwrap();
+		assert!(zip.skip(1).all(|((a, _), (b, _))| a.1 < b.1));
+	}
+
+	#[test]
+	fn direction() {
+		let mut builder = Builder::new();
+		builder.axis(Axis::Y).start_point((0, 4)).first_ending_point((-4, 0)).second_ending_point((4, 0));
+		let mut zip = builder.direction(Direction::FromBase).build().unwrap();
+		assert_eq!(zip.next(), Some(((-4, 0), (4, 0))));
+		assert_eq!(zip.next_back(), Some(((0, 4), (0, 4))));
+		assert!(zip.map(|(a, _)| a.1).eq(1..=3));
+		let mut zip = builder.include_flat_edge(false).build().unwrap();
+		assert_eq!(zip.next(), Some(((-3, 1), (3, 1))));
+		let mut resumed = builder.build().unwrap();
+		resumed.restore_state(zip.save_state());
+		assert_eq!(resumed, zip);
+		assert_eq!(resumed.next(), Some(((-2, 2), (2, 2))));
+	}
+
+	#[test]
+	fn clamped() {
+		let mut builder = Builder::new();
+		builder.axis(Axis::Y).start_point((0, 0)).first_ending_point((-9, 9)).second_ending_point((9, 9));
+		let zip = builder.clamp_axis(3, 5).build().unwrap();
+		assert!(zip.map(|(a, b)| (a.1, b.0)).eq([(3, 3), (4, 4), (5, 5)]));
+		let zip = builder.clamp_axis(-5, 0).direction(Direction::FromBase).build().unwrap();
+		assert!(zip.eq([((0, 0), (0, 0))]));
+		assert_eq!(builder.clamp_axis(10, 20).build().unwrap().len(), 0);
+	}
+
+	#[test]
+	fn half_plane() {
+		use crate::clip::HalfPlane;
 
+		let builder = Builder::new().with_start((2, 0)).with_first_end((-17, 15))
+			.with_second_end((20, 15)).with_axis(Axis::Y);
+		let planes = [((20, 4), (-20, 9)), ((-5, -5), (8, 20)), ((0, 7), (1, 7)), ((9, 0), (9, 1))];
+		for (from, to) in planes {
+			let plane = HalfPlane::new(from, to);
+			let zip = builder.build_half_plane(plane).unwrap();
+			let full = builder.build().unwrap();
+			assert!(zip.clone().pixels().eq(full.pixels().filter(|point| plane.contains(*point))));
+			assert!(zip.rev().all(|(a, b)| plane.contains(a) && plane.contains(b)));
+		}
+		let outside = builder.build_half_plane(HalfPlane::new((0, 16), (1, 16))).unwrap();
+		assert_eq!(outside.count(), 0);
+		// the pairs in the axis values 5 to 3 are out only by rounding
+		let plane = HalfPlane::new((15, 18), (12, -6));
+		let builder = builder.with_start((18, 28)).with_first_end((11, -4))
+			.with_second_end((-24, -4));
+		let zip = builder.build_half_plane(plane).unwrap();
+		assert_eq!(zip.clone().count(), 25);
+		assert!(zip.clone().rev().map(|(a, _)| a.1).take(3).eq([1, 2, 6]));
+		let builder = builder.with_direction(Direction::FromBase);
+		let reversed = builder.build_half_plane(plane).unwrap();
+		let full = builder.build().unwrap();
+		assert!(reversed.pixels().eq(full.pixels().filter(|point| plane.contains(*point))));
+	}
+
+	#[test]
+	fn antialiased() {
+		let mut builder = Builder::new();
+		builder.infer_axis().start_point((0, 0)).first_ending_point((-2, 4));
+		let error = builder.build_antialiased().unwrap_err();
+		assert_eq!(error, Error::MissingPoint(PointRole::SecondEnding));
+		builder.second_ending_point((4, 4));
+		let zip = builder.build_antialiased().unwrap();
+		assert_eq!(zip.last(), Some((((-2, 4), 128), ((4, 4), 128))));
+	}
+
+	#[test]
+	fn static_axis() {
+		let mut builder = Builder::new();
+		builder.start_point((0, 0)).first_ending_point((10, 10)).second_ending_point((-10, 10));
+		assert_eq!(builder.build_along(AxisZ).unwrap_err(), Error::MissingAxis);
+		assert_eq!(builder.build_along(AxisX).unwrap_err(), Error::InvalidX(10, -10));
+		let mut zip = builder.build_along(AxisY).unwrap();
+		assert_eq!(zip.next(), Some(((0, 0), (0, 0))));
+		assert_eq!(zip.next_back(), Some(((10, 10), (-10, 10))));
+	}
+
+	#[test]
+	fn valid() {
+		// Direct building
+		let mut zip = build_zip!(2D:X - (50, 50) -> (0, 0), (0, 100)).unwrap();
+		assert_eq!(zip.next(), Some(((50, 50), (50, 50))));
+		assert_eq!(zip.next_back(), Some(((0, 0), (0, 100))));
+		// Modified building
+		let mut built = Builder::new()
+			.axis(Axis::X)
+			.axis(Axis::Y)
+			.start_point((25, 25))
+			.second_ending_point((50, 50))
+			.start_point((10, 10))
+			.first_ending_point((0, 100))
+			.second_ending_point((100, 100))
+			.build()
+			.unwrap();
+		assert_eq!(built.next(), Some(((10, 10), (10, 10))));
+		assert_eq!(built.next_back(), Some(((0, 100), (100, 100))));
 	}
 
 }
\ No newline at end of file
@@ -0,0 +1,217 @@
+//! Contains the conservative variant of the two-dimensional zip
+
+use core::fmt::{Debug, Formatter};
+use crate::span::SpanIterator;
+use crate::util::{count_between, floor_div, narrow, sort_by_axis, Point};
+use crate::{Point2, SignedNum};
+
+/// Zip performing a conservative rasterization, where every pixel touched by the triangle, even
+/// partially, is included. It is meant for occlusion culling, collision broad-phases and any
+/// other use where missing a pixel of the triangle is worse than taking some extra ones.
+///
+/// Pixels are considered squares of size one centered in their coordinates. Each pair contains
+/// the first and the last pixel touched by the triangle in an axis value, sorted from lowest to
+/// highest, and the axis values are yielded from lowest to highest.
+///
+/// Like the [TopLeftZip](crate::zip::TopLeftZip), it can rasterize any triangle, so it can also be
+/// obtained with
+/// [Triangle2::rasterize_conservative](crate::triangle::Triangle2::rasterize_conservative).
+///
+/// ```
+/// # use std::error::Error;
+/// # pub fn main() -> Result<(), Box<dyn Error>> {
+/// let zip = bresenham_zip::zip::Builder::new()
+///   .axis(bresenham_zip::Axis::Y)
+///   .start_point((2, 0))
+///   .first_ending_point((0, 4))
+///   .second_ending_point((4, 4))
+///   .build_conservative()?;
+/// for (left, right) in zip {
+///   assert_eq!(left.1, right.1);
+///   assert!(left.0 <= right.0);
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ConservativeZip<T> {
+	top: Point2<T>,
+	middle: Point2<T>,
+	bottom: Point2<T>,
+	current: T,
+	done: bool,
+	axis: u8,
+}
+
+impl<T: SignedNum> ConservativeZip<T> {
+
+	pub(crate) fn new(a: Point2<T>, b: Point2<T>, c: Point2<T>, axis: u8) -> Self {
+		let (top, middle, bottom) = sort_by_axis(a, b, c, axis);
+		Self {
+			top,
+			middle,
+			bottom,
+			current: top.nth(axis),
+			done: false,
+			axis,
+		}
+	}
+
+	/// Turns the zip into an iterator over the [Span](crate::span::Span)s defined by each pair
+	/// of points
+	pub fn spans(self) -> SpanIterator<Self> {
+		let axis = self.axis;
+		SpanIterator::new(self, axis)
+	}
+
+	/// Returns the first and last pixels touched by the triangle in the current axis value
+	fn row(&self) -> (T, T) {
+		let edges = [(self.top, self.middle), (self.middle, self.bottom), (self.top, self.bottom)];
+		let mut row: Option<(T, T)> = None;
+		for (from, to) in edges {
+			let (from_value, to_value) = (from.nth(self.axis), to.nth(self.axis));
+			if from_value > self.current || to_value < self.current {
+				continue;
+			}
+			// the edge is evaluated at both limits of the pixels of the current axis value
+			for limit in [-T::one(), T::one()] {
+				let (first, last) = self.touched(from, to, limit);
+				row = Some(match row {
+					Some((low, high)) => (low.min(first), high.max(last)),
+					None => (first, last),
+				});
+			}
+		}
+		row.unwrap_or((self.top.nth(1 - self.axis), self.top.nth(1 - self.axis)))
+	}
+
+	/// Returns the first and last pixels touched by the point of the edge `from -> to` placed at
+	/// the current axis value plus `limit` halves, or by its closest end if it's out of the edge
+	fn touched(&self, from: Point2<T>, to: Point2<T>, limit: T) -> (T, T) {
+		let (axis, other) = (self.axis, 1 - self.axis);
+		let two = T::one() + T::one();
+		let length = to.nth(axis) - from.nth(axis);
+		if length == T::zero() {
+			let (low, high) = (from.nth(other), to.nth(other));
+			let value = if limit < T::zero() { low.min(high) } else { low.max(high) };
+			return (value, value);
+		}
+		// values in halves of unit
+		let value = (two * self.current + limit)
+			.max(two * from.nth(axis))
+			.min(two * to.nth(axis));
+		let numerator = two * from.nth(other) * length
+			+ (to.nth(other) - from.nth(other)) * (value - two * from.nth(axis));
+		let denominator = two * length;
+		// the point is at numerator / denominator, the pixels touching it are the ones at less
+		// than half unit from it
+		let first = -floor_div(denominator - two * numerator, two * denominator);
+		let last = floor_div(two * numerator + denominator, two * denominator);
+		(first, last)
+	}
+
+}
+
+impl<T: SignedNum> Iterator for ConservativeZip<T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let (first, last) = self.row();
+		let (mut a, mut b) = (self.top, self.top);
+		a.set_nth(self.axis, self.current);
+		b.set_nth(self.axis, self.current);
+		a.set_nth(1 - self.axis, first);
+		b.set_nth(1 - self.axis, last);
+
+		if self.current == self.bottom.nth(self.axis) {
+			self.done = true;
+		} else {
+			self.current += T::one();
+		}
+		Some((a, b))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let bottom = self.bottom.nth(self.axis);
+		let remaining = if self.done { 0 } else { count_between(self.current, bottom) };
+		(remaining, Some(remaining))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.len() {
+			self.done = true;
+			return None;
+		}
+		self.current += narrow(n as i128);
+		self.next()
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.nth(self.len().checked_sub(1)?)
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for ConservativeZip<T> {}
+
+impl<T: SignedNum> Debug for ConservativeZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "ConservativeZip [ {:?}, {:?}, {:?} ]. Current: {:?}",
+			self.top, self.middle, self.bottom, self.current)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ConservativeZip;
+
+	#[test]
+	fn touched() {
+		let mut zip = ConservativeZip::new((2, 0), (0, 4), (4, 4), 1);
+		assert_eq!(zip.len(), 5);
+		// edges cross y = 0.5 at 1.75 and 2.25
+		assert_eq!(zip.next(), Some(((2, 0), (2, 0))));
+		assert_eq!(zip.next(), Some(((1, 1), (3, 1))));
+		assert_eq!(zip.next(), Some(((1, 2), (3, 2))));
+		assert_eq!(zip.next(), Some(((0, 3), (4, 3))));
+		assert_eq!(zip.next(), Some(((0, 4), (4, 4))));
+		assert_eq!(zip.next(), None);
+	}
+
+	#[test]
+	fn corners() {
+		// the edge crosses x = 0.5 at y = 1.5, touching the corner of the pixel (0, 2)
+		let mut zip = ConservativeZip::new((0, 0), (6, 1), (1, 3), 0);
+		assert_eq!(zip.next(), Some(((0, 0), (0, 2))));
+		assert_eq!(zip.last(), Some(((6, 1), (6, 1))));
+	}
+
+	#[test]
+	fn contains_top_left() {
+		use crate::util::Point;
+		use crate::zip::TopLeftZip;
+
+		let triangles = [((0, 0), (13, 5), (4, 11)), ((-3, 7), (9, -2), (0, 20))];
+		for (a, b, c) in triangles {
+			for axis in [0, 1] {
+				let mut rows = [None; 32];
+				for (low, high) in ConservativeZip::new(a, b, c, axis) {
+					let row = (low.nth(1 - axis), high.nth(1 - axis));
+					rows[(low.nth(axis) + 5) as usize] = Some(row);
+				}
+				for (low, high) in TopLeftZip::new(a, b, c, axis) {
+					let (first, last) = rows[(low.nth(axis) + 5) as usize].unwrap();
+					assert!(first <= low.nth(1 - axis) && last >= high.nth(1 - axis));
+				}
+			}
+		}
+	}
+
+}
@@ -0,0 +1,154 @@
+//! Contains the floating-point variant of the two-dimensional zip
+
+use core::fmt::{Debug, Formatter};
+use crate::util::{advance, Point};
+use crate::{Axis, Error, FloatNum, Point2};
+
+/// Zip stepping both lines of a triangle with a digital differential analyzer (DDA) instead of
+/// Bresenham. It accepts floating-point vertices and advances one unit in the axis each iteration,
+/// yielding the points of both lines without rounding them, so sub-pixel accurate renderers can
+/// apply their own rounding.
+///
+/// The axis values yielded are the one of the starting point and the following ones, unit by
+/// unit, while they don't go past the value of the ending points.
+///
+/// ```
+/// # use bresenham_zip::{Axis, zip::Dda};
+/// let zip = Dda::new((5.0, 0.5), (0.0, 10.5), (10.0, 10.5), Axis::Y).unwrap();
+/// for (left, right) in zip {
+///   assert_eq!(left.1, right.1);
+///   assert!(left.0 <= right.0);
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Dda<F> {
+	a: Point2<F>,
+	b: Point2<F>,
+	step_a: Point2<F>,
+	step_b: Point2<F>,
+	remaining: usize,
+}
+
+impl<F: FloatNum> Dda<F> {
+
+	/// Creates a new DDA zip of the triangle with a flat edge between the two ending points
+	///
+	/// * `start` - Vertex out of the flat edge
+	/// * `end1`, `end2` - Vertices of the flat edge, sharing the value in the axis
+	/// * `axis` - Axis to use in the iteration
+	///
+	/// # Error
+	/// This call can generate the following errors
+	///
+	/// * [Error::MissingAxis], if the axis is [Axis::Z].
+	/// * [Error::InvalidX], if the axis is X and the two ending points have divergent X values.
+	/// * [Error::InvalidY], if the axis is Y and the two ending points have divergent Y values.
+	///
+	pub fn new(start: Point2<F>, end1: Point2<F>, end2: Point2<F>, axis: Axis)
+		-> Result<Self, Error<F>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => return Err(Error::MissingAxis),
+		};
+		if end1.nth(axis) != end2.nth(axis) {
+			return Err(match axis {
+				0 => Error::InvalidX(end1.0, end2.0),
+				_ => Error::InvalidY(end1.1, end2.1),
+			});
+		}
+
+		let length = end1.nth(axis) - start.nth(axis);
+		let distance = if length < F::ZERO { -length } else { length };
+		if distance == F::ZERO {
+			let still = (F::ZERO, F::ZERO);
+			return Ok(Self { a: end1, b: end2, step_a: still, step_b: still, remaining: 1 });
+		}
+		Ok(Self {
+			a: start,
+			b: start,
+			step_a: ((end1.0 - start.0) / distance, (end1.1 - start.1) / distance),
+			step_b: ((end2.0 - start.0) / distance, (end2.1 - start.1) / distance),
+			remaining: distance.to_usize() + 1,
+		})
+	}
+
+}
+
+impl<F: FloatNum> Iterator for Dda<F> {
+	type Item = (Point2<F>, Point2<F>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		let pair = (self.a, self.b);
+		advance(&mut self.a, &self.step_a);
+		advance(&mut self.b, &self.step_b);
+		Some(pair)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<F: FloatNum> ExactSizeIterator for Dda<F> {}
+
+impl<F: FloatNum> Debug for Dda<F> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Dda [ {:?}, {:?} ]. Remaining: {}", self.a, self.b, self.remaining)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Axis, Error};
+	use super::Dda;
+
+	#[test]
+	fn steps() {
+		let mut zip = Dda::new((5.0, 0.0), (0.0, 10.0), (10.0, 10.0), Axis::Y).unwrap();
+		assert_eq!(zip.len(), 11);
+		for y in 0..=10 {
+			let y = y as f64;
+			assert_eq!(zip.next(), Some(((5.0 - y / 2.0, y), (5.0 + y / 2.0, y))));
+		}
+		assert_eq!(zip.next(), None);
+	}
+
+	#[test]
+	fn sub_pixel() {
+		let zip = Dda::new((0.5f32, 0.25), (3.0, 0.0), (3.0, 1.0), Axis::X).unwrap();
+		let pairs = [
+			((0.5, 0.25), (0.5, 0.25)),
+			((1.5, 0.15), (1.5, 0.55)),
+			((2.5, 0.05), (2.5, 0.85)),
+		];
+		assert_eq!(zip.len(), pairs.len());
+		for ((a, b), (expected_a, expected_b)) in zip.zip(pairs) {
+			assert_eq!(a.0, expected_a.0);
+			assert!((a.1 - expected_a.1).abs() < 1e-6);
+			assert!((b.1 - expected_b.1).abs() < 1e-6);
+			assert_eq!(b.0, expected_b.0);
+		}
+	}
+
+	#[test]
+	fn flat() {
+		let mut zip = Dda::new((0.0, 0.0), (-1.0, 0.0), (1.0, 0.0), Axis::Y).unwrap();
+		assert_eq!(zip.next(), Some(((-1.0, 0.0), (1.0, 0.0))));
+		assert_eq!(zip.next(), None);
+	}
+
+	#[test]
+	fn invalid() {
+		let zip = Dda::new((0.0, 0.0), (-1.0, 2.0), (1.0, 2.5), Axis::Y);
+		assert_eq!(zip.unwrap_err(), Error::InvalidY(2.0, 2.5));
+		let zip = Dda::new((0.0, 0.0), (-1.0, 2.0), (1.0, 2.0), Axis::Z);
+		assert_eq!(zip.unwrap_err(), Error::MissingAxis);
+	}
+
+}
@@ -0,0 +1,145 @@
+//! Contains the lines of the two-dimensional zip, stepped one axis value at a time
+
+use crate::util::{wide, Increment, Point, Ratio};
+use crate::zip_core::ZipEdge;
+use crate::{AxisSelect, Point2, SignedNum};
+
+/// Line of the triangle yielding, for each value of the axis, the same point that walking it
+/// with Bresenham would give. Instead of walking every point of the line, the other coordinate
+/// is kept as a [Ratio] of the axis values travelled, so each step takes constant time no matter
+/// how flat the line is.
+///
+/// Bresenham truncates the offset of the minor coordinate of the line. When the line has several
+/// points with the same axis value, the edge yields the last one walked from `from` if `last`,
+/// like the front of the zip, or the first one otherwise, like the back of the zip, which walks
+/// the line starting at the ending point.
+///
+/// Edges parallel to the axis, like the sides of the axis-aligned quads split into triangles,
+/// keep the other coordinate of `from` on every step, skipping the ratio.
+#[derive(Clone, PartialEq)]
+pub(crate) struct Edge<T, A = u8> {
+	from: Point2<T>,
+	to: Point2<T>,
+	offset: Ratio<T>,
+	increment: Increment<T>,
+	travelled: T,
+	length: T,
+	step: T,
+	sign: T,
+	parallel: bool,
+	last: bool,
+	axis: A,
+}
+
+impl<T: SignedNum, A: AxisSelect> Edge<T, A> {
+
+	pub(crate) fn new(from: Point2<T>, to: Point2<T>, axis: A, last: bool) -> Self {
+		let (index, other) = (axis.index(), 1 - axis.index());
+		let (length, delta) = (to.nth(index) - from.nth(index), to.nth(other) - from.nth(other));
+		let (distance, divisor) = (wide(delta.abs()), wide(length.abs()).max(1));
+		// the offset after travelling k axis values is (k * distance + dividend) / length
+		let dividend = if divisor >= distance {
+			0
+		} else if last {
+			distance - 1
+		} else {
+			divisor - 1
+		};
+		let offset = Ratio::new(dividend, divisor);
+		Self {
+			from,
+			to,
+			increment: offset.increment(distance),
+			offset,
+			travelled: T::zero(),
+			length: length.abs(),
+			step: if length < T::zero() { -T::one() } else { T::one() },
+			sign: delta.signum(),
+			parallel: delta == T::zero(),
+			last,
+			axis,
+		}
+	}
+
+}
+
+impl<T: SignedNum, A: AxisSelect> ZipEdge<T> for Edge<T, A> {
+	type Point = Point2<T>;
+
+	fn point(&self) -> Self::Point {
+		// the last point walked is the ending one, even if the line has more with its axis value
+		if self.last && self.travelled == self.length {
+			return self.to;
+		}
+		let (index, other) = (self.axis.index(), 1 - self.axis.index());
+		let mut point = self.from;
+		point.set_nth(index, self.from.nth(index) + self.step * self.travelled);
+		if !self.parallel {
+			point.set_nth(other, self.from.nth(other) + self.sign * self.offset.value());
+		}
+		point
+	}
+
+	#[inline]
+	fn advance(&mut self) {
+		self.travelled += T::one();
+		if !self.parallel {
+			self.offset.add(self.increment);
+		}
+	}
+
+	fn skip(&mut self, steps: T) {
+		self.travelled += steps;
+		if !self.parallel {
+			self.offset.add_times(self.increment, wide(steps));
+		}
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::line::Bresenham;
+	use crate::util::Point;
+	use crate::zip_core::ZipEdge;
+	use crate::Point2;
+	use super::Edge;
+
+	#[test]
+	fn bresenham() {
+		let lines: [(Point2<i32>, Point2<i32>); 4] =
+			[((0, 0), (-37, 11)), ((3, -2), (20, 40)), ((5, 5), (-9, -9)), ((0, 0), (7, 0))];
+		for (from, to) in lines {
+			for axis in [0u8, 1] {
+				for last in [true, false] {
+					let mut points = [None; 128];
+					for point in Bresenham::new(from, to) {
+						let slot = &mut points[(point.nth(axis) + 64) as usize];
+						if last || slot.is_none() {
+							*slot = Some(point);
+						}
+					}
+					let mut edge = Edge::new(from, to, axis, last);
+					let length = (to.nth(axis) - from.nth(axis)).abs();
+					for _ in 0..=length {
+						let point = edge.point();
+						assert_eq!(points[(point.nth(axis) + 64) as usize], Some(point));
+						edge.advance();
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn parallel() {
+		let mut edge = Edge::new((3, 0), (3, -9), 1, true);
+		for y in 0..=9 {
+			assert_eq!(edge.point(), (3, -y));
+			edge.advance();
+		}
+		assert!(Edge::new((0, 5), (7, 5), 0, false).parallel);
+		assert!(!Edge::new((0, 5), (7, 6), 0, false).parallel);
+	}
+
+}
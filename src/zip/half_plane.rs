@@ -0,0 +1,147 @@
+//! Contains the two-dimensional zip restricted to a half of the plane
+
+use core::fmt::{Debug, Formatter};
+use crate::clip::HalfPlane;
+use crate::fill::FillIterator;
+use crate::util::{floor_div, narrow, wide, Point};
+use crate::zip::BresenhamZip;
+use crate::{AxisSelect, Point2, SignedNum};
+
+/// Zip restricted to the part of the triangle inside a half of the plane, like the one at one
+/// side of a split screen. It's obtained with
+/// [Builder::build_half_plane](crate::zip::Builder::build_half_plane).
+///
+/// The ends of each pair are trimmed where the line crosses it and the pairs fully out of it are
+/// skipped. The axis values out of it at both ends are skipped when the zip is built, solving the
+/// line equation, but the pairs between them can be out only by rounding, so unlike the
+/// [BresenhamZip] its length isn't known beforehand. Each pair is computed from its axis value
+/// like with [span_at](BresenhamZip::span_at), so the same pairs are yielded from both ends.
+///
+/// ```
+/// # use bresenham_zip::{Axis, clip::HalfPlane, zip::Builder};
+/// let zip = Builder::new().axis(Axis::Y).start_point((0, 0))
+///   .first_ending_point((-10, 10)).second_ending_point((10, 10))
+///   .build_half_plane(HalfPlane::new((0, 0), (0, 1)))
+///   .unwrap();
+/// assert_eq!(zip.last(), Some(((-10, 10), (0, 10))));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct HalfPlaneZip<T, A = u8> {
+	zip: BresenhamZip<T, A>,
+	plane: HalfPlane<T>,
+}
+
+impl<T: SignedNum, A: AxisSelect> HalfPlaneZip<T, A> {
+
+	/// Restricts the zip to the half of the plane, skipping the axis values where its pairs
+	/// can't reach it
+	pub(crate) fn new(mut zip: BresenhamZip<T, A>, plane: HalfPlane<T>) -> Self {
+		let axis = zip.axis.index();
+		let (from, to) = (wide(zip.start.nth(axis)), wide(zip.end_a.nth(axis)));
+		let (length, (dx, dy)) = ((to - from).abs(), plane.direction());
+		// the points of the lines are up to one unit away from them in each coordinate, so the
+		// side of the starting point is widened by the most that moving it one unit changes it
+		let side = plane.side(zip.start);
+		let start = side + dx.abs() + dy.abs();
+		// the side along a line grows linearly, so the steps from the starting point where it
+		// reaches the half of the plane solve `start * length + slope * steps >= 0`, widened by
+		// one step as the points can be one unit away in the axis too
+		let steps = |end: Point2<T>| {
+			let (base, slope) = (start * length, plane.side(end) - side);
+			let (low, high) = match slope.signum() {
+				0 if base < 0 => return None,
+				0 => (0, length),
+				1 => (-floor_div(base, slope) - 1, length),
+				_ => (0, floor_div(base, -slope) + 1),
+			};
+			let (low, high) = (low.max(0), high.min(length));
+			(low <= high).then_some((low, high))
+		};
+		// both lines share the starting point, so the steps reaching it are joined
+		let (low, high) = match (steps(zip.end_a), steps(zip.end_b)) {
+			(Some(a), Some(b)) => (a.0.min(b.0), a.1.max(b.1)),
+			(Some(steps), None) | (None, Some(steps)) => steps,
+			(None, None) => (1, 0),
+		};
+		if low > high {
+			zip.core.nth(zip.core.len());
+		} else {
+			let step = (to - from).signum();
+			let (first, last): (T, T) = (narrow(from + step * low), narrow(from + step * high));
+			zip.clamp(first.min(last), first.max(last));
+		}
+		Self { zip, plane }
+	}
+
+	/// Turns the zip into an iterator over every point between each pair of points,
+	/// both included.
+	pub fn pixels(self) -> FillIterator<Self, Point2<T>> {
+		FillIterator::new(self)
+	}
+
+	/// Returns the pair of the axis value of `point` trimmed to the half of the plane, or `None`
+	/// if it's fully out of it
+	fn trimmed(&self, point: Point2<T>) -> Option<(Point2<T>, Point2<T>)> {
+		let axis = self.zip.axis.index();
+		let (a, b) = self.zip.span_at(point.nth(axis))?;
+		self.plane.trim(a, b, axis)
+	}
+
+}
+
+impl<T: SignedNum, A: AxisSelect> Iterator for HalfPlaneZip<T, A> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some((a, _)) = self.zip.core.next() {
+			if let Some(pair) = self.trimmed(a) {
+				return Some(pair);
+			}
+		}
+		None
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.zip.len()))
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<T: SignedNum, A: AxisSelect> DoubleEndedIterator for HalfPlaneZip<T, A> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		while let Some((a, _)) = self.zip.core.next_back() {
+			if let Some(pair) = self.trimmed(a) {
+				return Some(pair);
+			}
+		}
+		None
+	}
+}
+
+impl<T: SignedNum, A: AxisSelect> Debug for HalfPlaneZip<T, A> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "HalfPlaneZip [ {:?} ]. Plane: {:?}", self.zip, self.plane)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::clip::HalfPlane;
+	use crate::zip::Builder;
+	use crate::Axis;
+
+	#[test]
+	fn large() {
+		let zip = Builder::new().with_axis(Axis::Y).with_start((0, 0))
+			.with_first_end((-100000, 100000)).with_second_end((100000, 100000))
+			.build_half_plane(HalfPlane::new((0, 0), (0, 1))).unwrap();
+		assert_eq!(zip.clone().count(), 100001);
+		assert_eq!(zip.clone().next_back(), Some(((-100000, 100000), (0, 100000))));
+	}
+
+}
@@ -0,0 +1,196 @@
+//! Contains the supercover variant of the two-dimensional zip
+
+use core::fmt::{Debug, Formatter};
+use crate::util::{count_between, floor_div, narrow, Point};
+use crate::{Point2, SignedNum};
+
+/// First and last pixels crossed by a line in an axis value, sorted from lowest to highest
+pub type Run<T> = (Point2<T>, Point2<T>);
+
+/// Zip driving both lines of the triangle with a supercover traversal instead of Bresenham, so
+/// every pixel crossed by the lines is included and consecutive pixels of a line never touch
+/// only diagonally, unless the line passes exactly through their shared corner. It is meant to
+/// stamp the boundary of a triangle on a grid without leaving gaps.
+///
+/// Pixels are considered squares of size one centered in their coordinates. As a line can cross
+/// several pixels in the same axis value, each iteration yields the [Run] of every line, with
+/// the first and last pixels it crosses in that axis value.
+///
+/// ```
+/// # use std::error::Error;
+/// # pub fn main() -> Result<(), Box<dyn Error>> {
+/// let zip = bresenham_zip::zip::Builder::new()
+///   .axis(bresenham_zip::Axis::Y)
+///   .start_point((50, 50))
+///   .first_ending_point((0, 60))
+///   .second_ending_point((100, 60))
+///   .build_supercover()?;
+/// for ((first_a, last_a), (first_b, last_b)) in zip {
+///   assert_eq!(first_a.1, last_b.1);
+///   assert!(first_a.0 <= last_a.0 && first_b.0 <= last_b.0);
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct SupercoverZip<T> {
+	start: Point2<T>,
+	end_a: Point2<T>,
+	end_b: Point2<T>,
+	length: T,
+	travelled: T,
+	step: T,
+	done: bool,
+	axis: u8,
+}
+
+impl<T: SignedNum> SupercoverZip<T> {
+
+	pub(crate) fn new(start: Point2<T>, end_a: Point2<T>, end_b: Point2<T>, axis: u8) -> Self {
+		let length = end_a.nth(axis) - start.nth(axis);
+		Self {
+			start,
+			end_a,
+			end_b,
+			length: length.abs(),
+			travelled: T::zero(),
+			step: if length < T::zero() { -T::one() } else { T::one() },
+			done: false,
+			axis,
+		}
+	}
+
+	/// Returns the first and last pixels crossed by the line towards `end` in the current axis
+	/// value
+	fn run(&self, end: Point2<T>) -> (T, T) {
+		let origin = self.start.nth(1 - self.axis);
+		let delta = end.nth(1 - self.axis) - origin;
+		if self.length == T::zero() {
+			return (origin.min(origin + delta), origin.max(origin + delta));
+		}
+		let two = T::one() + T::one();
+		let denominator = two * self.length;
+		// the part of the line in the current axis value lies between both limits of its pixels,
+		// measured in halves of unit from the starting point
+		let [(low_a, high_a), (low_b, high_b)] = [-T::one(), T::one()].map(|limit| {
+			let travelled = (two * self.travelled + limit).max(T::zero()).min(denominator);
+			// the crossing is at origin + numerator / denominator, and the pixels it enters are
+			// the ones at strictly less than half unit from it
+			let numerator = delta * travelled;
+			let low = floor_div(two * numerator - denominator, two * denominator) + T::one();
+			let high = -floor_div(-two * numerator - denominator, two * denominator) - T::one();
+			(low, high)
+		});
+		(origin + low_a.min(low_b), origin + high_a.max(high_b))
+	}
+
+	/// Returns the run of the line towards `end` placed at the axis value `value`
+	fn run_at(&self, end: Point2<T>, value: T) -> Run<T> {
+		let (first, last) = self.run(end);
+		let (mut low, mut high) = (self.start, self.start);
+		low.set_nth(self.axis, value);
+		high.set_nth(self.axis, value);
+		low.set_nth(1 - self.axis, first);
+		high.set_nth(1 - self.axis, last);
+		(low, high)
+	}
+
+}
+
+impl<T: SignedNum> Iterator for SupercoverZip<T> {
+	type Item = (Run<T>, Run<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let value = self.start.nth(self.axis) + self.step * self.travelled;
+		let pair = (self.run_at(self.end_a, value), self.run_at(self.end_b, value));
+
+		if self.travelled == self.length {
+			self.done = true;
+		} else {
+			self.travelled += T::one();
+		}
+		Some(pair)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = if self.done { 0 } else { count_between(self.travelled, self.length) };
+		(remaining, Some(remaining))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.len() {
+			self.done = true;
+			return None;
+		}
+		self.travelled += narrow(n as i128);
+		self.next()
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.nth(self.len().checked_sub(1)?)
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for SupercoverZip<T> {}
+
+impl<T: SignedNum> Debug for SupercoverZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "SupercoverZip [ {:?} -> {:?}, {:?} ]. Travelled: {:?}",
+			self.start, self.end_a, self.end_b, self.travelled)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SupercoverZip;
+
+	#[test]
+	fn runs() {
+		let mut zip = SupercoverZip::new((0, 0), (-4, 2), (1, 2), 1);
+		assert_eq!(zip.len(), 3);
+		// the first line crosses y = 0.5 and y = 1.5 at x = -1 and x = -3
+		assert_eq!(zip.next(), Some((((-1, 0), (0, 0)), ((0, 0), (0, 0)))));
+		assert_eq!(zip.next(), Some((((-3, 1), (-1, 1)), ((0, 1), (1, 1)))));
+		assert_eq!(zip.next(), Some((((-4, 2), (-3, 2)), ((1, 2), (1, 2)))));
+		assert_eq!(zip.next(), None);
+	}
+
+	#[test]
+	fn corners() {
+		// the lines cross the corners of the pixels, where the diagonal steps are allowed
+		let zip = SupercoverZip::new((0, 0), (3, 3), (-3, 3), 1);
+		for (y, (a, b)) in zip.enumerate() {
+			let y = y as i32;
+			assert_eq!((a, b), (((y, y), (y, y)), ((-y, y), (-y, y))));
+		}
+	}
+
+	#[test]
+	fn no_diagonal_gaps() {
+		let zip = SupercoverZip::new((2, -1), (12, 6), (-6, 6), 1);
+		let mut previous: Option<(i32, i32)> = None;
+		for ((first, last), _) in zip {
+			if let Some((low, high)) = previous {
+				// each run shares a column with the previous one
+				assert!(first.0 <= high && last.0 >= low);
+			}
+			previous = Some((first.0, last.0));
+		}
+	}
+
+	#[test]
+	fn flat() {
+		let mut zip = SupercoverZip::new((0, 0), (5, 0), (-5, 0), 1);
+		assert_eq!(zip.next(), Some((((0, 0), (5, 0)), ((-5, 0), (0, 0)))));
+		assert_eq!(zip.next(), None);
+	}
+
+}
@@ -0,0 +1,204 @@
+//! Contains the variant of the two-dimensional zip following the top-left fill rule
+
+use core::fmt::{Debug, Formatter};
+use crate::span::SpanIterator;
+use crate::util::{count_between, floor_div, narrow, sort_by_axis, wide, Point};
+use crate::{Point2, SignedNum};
+
+/// Zip following the top-left fill rule of Direct3D and OpenGL, so triangles sharing an edge
+/// never rasterize the same pixel twice nor leave a gap between them. This makes the rasterization
+/// of meshes and shapes composed of multiple triangles watertight.
+///
+/// Instead of walking the lines with Bresenham, each pair contains the first and the last pixel
+/// whose center is inside of the triangle, sorted from lowest to highest. The centers lying just
+/// on an edge are only included if it's a top or a left edge. Taking the travelled axis as the
+/// vertical one, top is the side of the lowest values of the axis and left is the side of the
+/// lowest values of the other axis. The axis values are yielded from lowest to highest, skipping
+/// the ones without any pixel inside.
+///
+/// Unlike the [BresenhamZip](crate::zip::BresenhamZip), it can rasterize any triangle, so it can
+/// also be obtained with
+/// [Triangle2::rasterize_top_left](crate::triangle::Triangle2::rasterize_top_left).
+///
+/// ```
+/// # use std::error::Error;
+/// # pub fn main() -> Result<(), Box<dyn Error>> {
+/// let mut builder = bresenham_zip::zip::Builder::new();
+/// builder.axis(bresenham_zip::Axis::Y).start_point((0, 0)).first_ending_point((0, 4));
+/// let top = builder.second_ending_point((4, 4)).build_top_left()?;
+/// builder.start_point((4, 8));
+/// let bottom = builder.build_top_left()?;
+/// // the shared edge from (0, 4) to (4, 4) belongs to the bottom triangle
+/// assert_eq!(top.last(), Some(((0, 3), (2, 3))));
+/// assert_eq!(bottom.clone().next(), Some(((0, 4), (3, 4))));
+/// # Ok(()) }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct TopLeftZip<T> {
+	top: Point2<T>,
+	middle: Point2<T>,
+	bottom: Point2<T>,
+	current: T,
+	long_is_low: bool,
+	axis: u8,
+}
+
+impl<T: SignedNum> TopLeftZip<T> {
+
+	pub(crate) fn new(a: Point2<T>, b: Point2<T>, c: Point2<T>, axis: u8) -> Self {
+		let (top, middle, bottom) = sort_by_axis(a, b, c, axis);
+		let other = 1 - axis;
+		let delta = |point: Point2<T>, i: u8| wide(point.nth(i)) - wide(top.nth(i));
+		// the long edge goes from top to bottom, it's on the low side if middle is on the high one
+		let side = delta(middle, other) * delta(bottom, axis)
+			- delta(bottom, other) * delta(middle, axis);
+		Self {
+			top,
+			middle,
+			bottom,
+			current: top.nth(axis),
+			long_is_low: side > 0,
+			axis,
+		}
+	}
+
+	/// Turns the zip into an iterator over the [Span](crate::span::Span)s defined by each pair
+	/// of points
+	pub fn spans(self) -> SpanIterator<Self> {
+		let axis = self.axis;
+		SpanIterator::new(self, axis)
+	}
+
+	/// Returns the first and last pixels inside of the triangle in the current axis value,
+	/// if there is any
+	fn row(&self) -> Option<(T, T)> {
+		let long = self.crossing(self.top, self.bottom);
+		let short = if self.current < self.middle.nth(self.axis) {
+			self.crossing(self.top, self.middle)
+		} else {
+			self.crossing(self.middle, self.bottom)
+		};
+		let (low, high) = if self.long_is_low { (long, short) } else { (short, long) };
+
+		// pixels from the low crossing, included, to the high one, excluded
+		let first = ceil_div(low.0, low.1);
+		let last = ceil_div(high.0, high.1) - 1;
+		if first > last { None } else { Some((narrow(first), narrow(last))) }
+	}
+
+	/// Returns the crossing of the edge `from -> to` with the current axis value as a fraction,
+	/// widened so the products can't overflow
+	fn crossing(&self, from: Point2<T>, to: Point2<T>) -> (i128, i128) {
+		let (axis, other) = (self.axis, 1 - self.axis);
+		let length = wide(to.nth(axis)) - wide(from.nth(axis));
+		let travelled = wide(self.current) - wide(from.nth(axis));
+		let delta = wide(to.nth(other)) - wide(from.nth(other));
+		(wide(from.nth(other)) * length + delta * travelled, length)
+	}
+
+}
+
+/// Ceil of the division by a positive divisor
+fn ceil_div<T: SignedNum>(dividend: T, divisor: T) -> T {
+	-floor_div(-dividend, divisor)
+}
+
+impl<T: SignedNum> Iterator for TopLeftZip<T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		// the bottom value is either a vertex or a bottom edge, never filled
+		while self.current < self.bottom.nth(self.axis) {
+			let row = self.row();
+			let value = self.current;
+			self.current += T::one();
+
+			if let Some((first, last)) = row {
+				let (mut a, mut b) = (self.top, self.top);
+				a.set_nth(self.axis, value);
+				b.set_nth(self.axis, value);
+				a.set_nth(1 - self.axis, first);
+				b.set_nth(1 - self.axis, last);
+				return Some((a, b));
+			}
+		}
+		None
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let bottom = self.bottom.nth(self.axis);
+		let remaining = if self.current < bottom { count_between(self.current, bottom) - 1 } else { 0 };
+		(0, Some(remaining))
+	}
+}
+
+impl<T: SignedNum> Debug for TopLeftZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "TopLeftZip [ {:?}, {:?}, {:?} ]. Current: {:?}",
+			self.top, self.middle, self.bottom, self.current)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::TopLeftZip;
+
+	#[test]
+	fn shared_edges() {
+		// square split by its diagonal in two triangles with a flat edge each
+		let mut covered = [[0; 5]; 5];
+		let halves = [
+			TopLeftZip::new((4, 4), (0, 0), (4, 0), 1),
+			TopLeftZip::new((0, 0), (0, 4), (4, 4), 1),
+		];
+		for (a, b) in halves.into_iter().flatten() {
+			assert_eq!(a.1, b.1);
+			for x in a.0..=b.0 {
+				covered[a.1 as usize][x as usize] += 1;
+			}
+		}
+		// only the pixels of the bottom and right edges of the square are out
+		for (y, row) in covered.iter().enumerate() {
+			for (x, count) in row.iter().enumerate() {
+				assert_eq!(*count, if x < 4 && y < 4 { 1 } else { 0 }, "({}, {})", x, y);
+			}
+		}
+	}
+
+	#[test]
+	fn top_edge() {
+		let mut zip = TopLeftZip::new((2, 4), (0, 0), (4, 0), 1);
+		assert_eq!(zip.next(), Some(((0, 0), (3, 0))));
+		assert_eq!(zip.next(), Some(((1, 1), (3, 1))));
+		assert_eq!(zip.next(), Some(((1, 2), (2, 2))));
+		assert_eq!(zip.next(), Some(((2, 3), (2, 3))));
+		assert_eq!(zip.next(), None);
+	}
+
+	#[test]
+	fn any_triangle() {
+		let mut zip = TopLeftZip::new((0, 0), (6, 2), (2, 4), 1);
+		assert_eq!(zip.next(), Some(((1, 1), (2, 1))));
+		assert_eq!(zip.next(), Some(((1, 2), (5, 2))));
+		assert_eq!(zip.next(), Some(((2, 3), (3, 3))));
+		assert_eq!(zip.next(), None);
+	}
+
+	#[test]
+	fn degenerate() {
+		assert_eq!(TopLeftZip::new((0, 0), (-5, 0), (5, 0), 1).count(), 0);
+		assert_eq!(TopLeftZip::new((0, 0), (5, 5), (5, 5), 1).count(), 0);
+	}
+
+	#[test]
+	fn large() {
+		let mut zip = TopLeftZip::<i16>::new((0, 0), (-200, 200), (200, 200), 1);
+		assert_eq!(zip.nth(99), Some(((-100, 100), (99, 100))));
+		assert_eq!(zip.count(), 99);
+		let mut zip = TopLeftZip::new((0, 0), (-100000, 100000), (100000, 100000), 1);
+		assert_eq!(zip.nth(49999), Some(((-50000, 50000), (49999, 50000))));
+		assert_eq!(zip.last(), Some(((-99999, 99999), (99998, 99999))));
+	}
+
+}
@@ -0,0 +1,167 @@
+//! Contains the zip of the trapezoids with two edges sharing the value of the travelled axis
+
+use core::fmt::{Debug, Formatter};
+use crate::fill::FillIterator;
+use crate::span::SpanIterator;
+use crate::util::{count_between, Point};
+use crate::zip_core::ZipEdge;
+use crate::{Axis, Error, Point2, SignedNum};
+use super::edge::Edge;
+
+/// Base of a trapezoid, one of its two edges sharing the value of the travelled axis, given by
+/// its two points
+pub type Base<T> = (Point2<T>, Point2<T>);
+
+/// Zip of a quad where two opposite edges share the value of the travelled axis, like the
+/// trapezoids left clipping a triangle or most of the shapes of a UI. It walks with Bresenham the
+/// two sides joining those edges, the same way the [BresenhamZip](super::BresenhamZip) walks the
+/// sides of a triangle, which is just the trapezoid with one of its edges collapsed into a point.
+///
+/// ```
+/// # use bresenham_zip::{Axis, zip::TrapezoidZip};
+/// let zip = TrapezoidZip::new(((20, 0), (60, 0)), ((0, 40), (100, 40)), Axis::Y).unwrap();
+/// assert_eq!(zip.clone().next(), Some(((20, 0), (60, 0))));
+/// assert_eq!(zip.last(), Some(((0, 40), (100, 40))));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct TrapezoidZip<T> {
+	low: Edge<T>,
+	high: Edge<T>,
+	remaining: usize,
+	axis: u8,
+}
+
+impl<T: SignedNum> TrapezoidZip<T> {
+
+	/// Creates the zip of the trapezoid going from one of its bases to the other one. The
+	/// points of each base can be in any order, the sides join the points with the lowest
+	/// value in the other axis and the ones with the highest.
+	///
+	/// * `first` - Base where the zip starts
+	/// * `second` - Base where the zip ends
+	/// * `axis` - Axis to use in the iteration
+	///
+	/// # Error
+	/// * [Error::MissingAxis], if the axis is [Axis::Z].
+	/// * [Error::InvalidX], if the axis is X and the points of a base have different X values.
+	/// * [Error::InvalidY], if the axis is Y and the points of a base have different Y values.
+	///
+	pub fn new(first: Base<T>, second: Base<T>, axis: Axis) -> Result<Self, Error<T>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => return Err(Error::MissingAxis),
+		};
+		for (a, b) in [first, second] {
+			if a.nth(axis) != b.nth(axis) {
+				return Err(match axis {
+					0 => Error::InvalidX(a.0, b.0),
+					_ => Error::InvalidY(a.1, b.1),
+				});
+			}
+		}
+		let other = 1 - axis;
+		let sorted = |(a, b): Base<T>| if a.nth(other) <= b.nth(other) { (a, b) } else { (b, a) };
+		let (first, second) = (sorted(first), sorted(second));
+		Ok(Self {
+			low: Edge::new(first.0, second.0, axis, true),
+			high: Edge::new(first.1, second.1, axis, true),
+			remaining: count_between(first.0.nth(axis), second.0.nth(axis)),
+			axis,
+		})
+	}
+
+	/// Turns the zip into an iterator over every point between each pair of points,
+	/// both included.
+	pub fn pixels(self) -> FillIterator<Self, Point2<T>> {
+		FillIterator::new(self)
+	}
+
+	/// Turns the zip into an iterator over the [Span](crate::span::Span)s defined by each pair
+	/// of points
+	pub fn spans(self) -> SpanIterator<Self> {
+		let axis = self.axis;
+		SpanIterator::new(self, axis)
+	}
+
+}
+
+impl<T: SignedNum> Iterator for TrapezoidZip<T> {
+	type Item = (Point2<T>, Point2<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		let pair = (self.low.point(), self.high.point());
+		self.low.advance();
+		self.high.advance();
+		self.remaining -= 1;
+		Some(pair)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.remaining {
+			self.remaining = 0;
+			return None;
+		}
+		let steps = T::from(n).unwrap_or_else(T::zero);
+		self.low.skip(steps);
+		self.high.skip(steps);
+		self.remaining -= n;
+		self.next()
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for TrapezoidZip<T> {}
+
+impl<T: SignedNum> Debug for TrapezoidZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "TrapezoidZip [ {:?}, {:?} ]. Remaining: {}",
+			self.low.point(), self.high.point(), self.remaining)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::zip::BresenhamZip;
+	use crate::{Axis, Error};
+	use super::TrapezoidZip;
+
+	#[test]
+	fn triangle() {
+		let zip = TrapezoidZip::new(((50, 50), (50, 50)), ((100, 0), (0, 0)), Axis::Y).unwrap();
+		assert!(zip.eq(BresenhamZip::new((50, 50), (0, 0), (100, 0), 1)));
+		let zip = TrapezoidZip::new(((3, -2), (3, -2)), ((20, 40), (20, -30)), Axis::X).unwrap();
+		assert!(zip.eq(BresenhamZip::new((3, -2), (20, -30), (20, 40), 0)));
+	}
+
+	#[test]
+	fn quad() {
+		let rectangle = TrapezoidZip::new(((0, 0), (9, 0)), ((9, 4), (0, 4)), Axis::Y).unwrap();
+		assert_eq!(rectangle.len(), 5);
+		assert_eq!(rectangle.clone().pixels().count(), 50);
+		let mut zip = TrapezoidZip::new(((0, 0), (0, 30)), ((-17, -5), (-17, 12)), Axis::X)
+			.unwrap();
+		let mut stepped = zip.clone();
+		stepped.nth(6);
+		assert_eq!(zip.nth(7), stepped.next());
+		assert!(zip.eq(stepped));
+	}
+
+	#[test]
+	fn invalid() {
+		let zip = TrapezoidZip::new(((0, 0), (5, 1)), ((0, 9), (9, 9)), Axis::Y);
+		assert_eq!(zip.unwrap_err(), Error::InvalidY(0, 1));
+		let zip = TrapezoidZip::new(((0, 0), (0, 5)), ((9, 0), (8, 9)), Axis::X);
+		assert_eq!(zip.unwrap_err(), Error::InvalidX(9, 8));
+		let zip = TrapezoidZip::new(((0, 0), (5, 0)), ((0, 9), (9, 9)), Axis::Z);
+		assert_eq!(zip.unwrap_err(), Error::MissingAxis);
+	}
+
+}
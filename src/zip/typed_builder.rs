@@ -0,0 +1,209 @@
+//! Contains the typestate variant of the two-dimensional builder
+
+use core::marker::PhantomData;
+use crate::{Axis, Direction, Error, Point2Like, SignedNum, Snap};
+use crate::zip::{BresenhamZip, Builder};
+
+/// Marker of a [TypedBuilder] field that has not been specified yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unset;
+
+/// Marker of a [TypedBuilder] field that has already been specified
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Set;
+
+/// Variant of the [Builder] tracking the specified fields in its type. This way, `build` can only
+/// be called once the axis, the starting point and both ending points are specified, and missing
+/// any of them is a compilation error instead of an [Error::MissingAxis] or [Error::MissingPoint].
+///
+/// ```
+/// # use std::error::Error;
+/// # pub fn main() -> Result<(), Box<dyn Error>> {
+/// let zip = bresenham_zip::zip::TypedBuilder::new()
+///   .axis(bresenham_zip::Axis::Y)
+///   .start_point((50, 50))
+///   .first_ending_point((0, 100))
+///   .second_ending_point((100, 100))
+///   .build()?;
+/// # Ok(()) }
+/// ```
+///
+/// ```compile_fail
+/// let zip = bresenham_zip::zip::TypedBuilder::new()
+///   .axis(bresenham_zip::Axis::Y)
+///   .first_ending_point((0, 100))
+///   .second_ending_point((100, 100))
+///   .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TypedBuilder<T, A = Unset, S = Unset, E1 = Unset, E2 = Unset> {
+	inner: Builder<T>,
+	state: PhantomData<(A, S, E1, E2)>,
+}
+
+impl<T: SignedNum> TypedBuilder<T> {
+
+	/// Creates a new TypedBuilder without any field specified
+	pub fn new() -> Self {
+		Self {
+			inner: Builder::new(),
+			state: PhantomData,
+		}
+	}
+
+}
+
+impl<T: SignedNum> Default for TypedBuilder<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: SignedNum, A, S, E1, E2> TypedBuilder<T, A, S, E1, E2> {
+
+	/// Specifies the axis that will be used to generate the points of the lines during the iteration.
+	/// The returned tuples will share the same value in the specified axis.
+	///
+	/// * `axis` - Axis to use in the Zip iteration
+	///
+	pub fn axis(mut self, axis: Axis) -> TypedBuilder<T, Set, S, E1, E2> {
+		self.inner.axis(axis);
+		TypedBuilder { inner: self.inner, state: PhantomData }
+	}
+
+	/// Allows the ending points to differ in the axis of the zip up to the given tolerance.
+	/// See [Builder::axis_tolerance].
+	///
+	/// * `tolerance` - Maximum difference accepted between the ending points in the axis
+	/// * `snap` - Common value to move the ending points to
+	///
+	pub fn axis_tolerance(mut self, tolerance: T, snap: Snap) -> Self {
+		self.inner.axis_tolerance(tolerance, snap);
+		self
+	}
+
+	/// Specifies if the first pair is yielded. See [Builder::include_apex].
+	///
+	/// * `include` - Whether to yield the starting point
+	///
+	pub fn include_apex(mut self, include: bool) -> Self {
+		self.inner.include_apex(include);
+		self
+	}
+
+	/// Specifies if the last pair is yielded. See [Builder::include_flat_edge].
+	///
+	/// * `include` - Whether to yield the flat edge
+	///
+	pub fn include_flat_edge(mut self, include: bool) -> Self {
+		self.inner.include_flat_edge(include);
+		self
+	}
+
+	/// Specifies if the points of each pair are sorted. See [Builder::sorted].
+	///
+	/// * `sorted` - Whether to sort the points of each pair
+	///
+	pub fn sorted(mut self, sorted: bool) -> Self {
+		self.inner.sorted(sorted);
+		self
+	}
+
+	/// Specifies the end of the triangle where the iteration starts. See [Builder::direction].
+	///
+	/// * `direction` - End of the triangle yielded first
+	///
+	pub fn direction(mut self, direction: Direction) -> Self {
+		self.inner.direction(direction);
+		self
+	}
+
+	/// Restricts the zip to the axis values between `min` and `max`. See [Builder::clamp_axis].
+	///
+	/// * `min` - Lowest axis value to yield
+	/// * `max` - Highest axis value to yield
+	///
+	pub fn clamp_axis(mut self, min: T, max: T) -> Self {
+		self.inner.clamp_axis(min, max);
+		self
+	}
+
+	/// Specifies the starting point for both the lines to be drawn in the BresenhamZip
+	///
+	/// * `start` - Starting point, any [Point2Like] like (T, T)
+	///
+	pub fn start_point<P: Point2Like<T>>(mut self, start: P)
+		-> TypedBuilder<T, A, Set, E1, E2> {
+		self.inner.start_point(start);
+		TypedBuilder { inner: self.inner, state: PhantomData }
+	}
+
+	/// Specifies the ending point for one of the lines to be drawn in the BresenhamZip
+	///
+	/// * `end` - Ending point of one line, any [Point2Like] like (T, T)
+	///
+	pub fn first_ending_point<P: Point2Like<T>>(mut self, end: P)
+		-> TypedBuilder<T, A, S, Set, E2> {
+		self.inner.first_ending_point(end);
+		TypedBuilder { inner: self.inner, state: PhantomData }
+	}
+
+	/// Specifies the ending point for one of the lines to be drawn in the BresenhamZip
+	///
+	/// * `end` - Ending point of one line, any [Point2Like] like (T, T)
+	///
+	pub fn second_ending_point<P: Point2Like<T>>(mut self, end: P)
+		-> TypedBuilder<T, A, S, E1, Set> {
+		self.inner.second_ending_point(end);
+		TypedBuilder { inner: self.inner, state: PhantomData }
+	}
+
+}
+
+impl<T: SignedNum> TypedBuilder<T, Set, Set, Set, Set> {
+
+	/// Builds the BresenhamZip corresponding with the defined arguments
+	///
+	/// # Error
+	/// This call can generate the following errors
+	///
+	/// * [Error::MissingAxis], if the specified axis was [Axis::Z].
+	/// * [Error::InvalidX], if the axis is X and the two ending points have divergent X values.
+	/// * [Error::InvalidY], if the axis is Y and the two ending points have divergent Y values.
+	///
+	pub fn build(&self) -> Result<BresenhamZip<T>, Error<T>> {
+		self.inner.build()
+	}
+
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{Axis, Error};
+	use crate::zip::TypedBuilder;
+
+	#[test]
+	fn invalid_points() {
+		let built = TypedBuilder::new()
+			.axis(Axis::Y)
+			.start_point((0, 0))
+			.first_ending_point((10, 10))
+			.second_ending_point((20, 20))
+			.build();
+		assert_eq!(built.unwrap_err(), Error::InvalidY(10, 20));
+	}
+
+	#[test]
+	fn valid() {
+		let mut built = TypedBuilder::default()
+			.second_ending_point((100, 100))
+			.start_point((10, 10))
+			.axis(Axis::Y)
+			.first_ending_point((0, 100))
+			.build()
+			.unwrap();
+		assert_eq!(built.next(), Some(((10, 10), (10, 10))));
+		assert_eq!(built.next_back(), Some(((0, 100), (100, 100))));
+	}
+
+}
@@ -1,85 +1,399 @@
 //! Package with the logic of the three-dimensional BresenhamZip
 
 mod builder_3d;
+mod dda_3d;
+mod edge_3d;
+mod supercover_3d;
 
 use core::fmt::{Debug, Formatter};
-use line_drawing::Bresenham3d;
-use crate::{Point3, SignedNum};
-use crate::util::Point;
+use crate::{Axis, AxisSelect, Error, Point3, SignedNum};
+use crate::util::{bounds, fits_lines, flat_edge_last, floor_div, line_length, narrow, wide, Point};
+use crate::barycentric::BarycentricIterator;
+use crate::clip::{Aabb, ClipRect};
+use crate::convert::{FromTuple, PairsAs};
+use crate::fill::FillIterator;
+use crate::fuel::Fueled;
+use crate::state::ZipState;
+use crate::zip_core::ZipCore;
 
 pub use builder_3d::Builder3d;
-
-pub struct Bresenham3dZip<T> {
-	a: Bresenham3d<T>,
-	b: Bresenham3d<T>,
-	prev_a: Point3<T>,
-	prev_b: Point3<T>,
-	goal: T,
-	axis: u8
+pub use dda_3d::Dda3d;
+pub use supercover_3d::Supercover3dZip;
+use edge_3d::Edge3d;
+
+#[derive(Clone, PartialEq)]
+pub struct Bresenham3dZip<T, A = u8> {
+	start: Point3<T>,
+	end_a: Point3<T>,
+	end_b: Point3<T>,
+	core: ZipCore<T, Edge3d<T, A>>,
+	axis: A
 }
 
 impl<T: SignedNum> Bresenham3dZip<T> {
 
 	#[inline]
-	pub(crate) fn new<'a>(start: Point3<T>, end1: Point3<T>, end2: Point3<T>, axis: u8) -> Self {
+	pub(crate) fn new(start: Point3<T>, end1: Point3<T>, end2: Point3<T>, axis: u8) -> Self {
+		Self::with_axis(start, end1, end2, axis)
+	}
+
+	/// Creates the zip of a triangle given its three vertices in any order. The two vertices
+	/// sharing the value of the axis are used as the ending points.
+	///
+	/// ```
+	/// # use bresenham_zip::{Axis, zip_3d::Bresenham3dZip};
+	/// let zip = Bresenham3dZip::from_vertices((0, 0, 100), (50, 50, 50), (100, 0, 100), Axis::Z);
+	/// assert_eq!(zip.unwrap().last(), Some(((0, 0, 100), (100, 0, 100))));
+	/// ```
+	///
+	/// * `a`, `b`, `c` - Vertices of the triangle
+	/// * `axis` - Axis to use in the iteration
+	///
+	/// # Error
+	/// The same errors of [Builder3d::build] can be generated. When no pair of vertices shares the
+	/// value of the axis, the invalid values reported are the ones of `b` and `c`.
+	///
+	pub fn from_vertices(a: Point3<T>, b: Point3<T>, c: Point3<T>, axis: Axis)
+		-> Result<Self, Error<T>> {
+		let index = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => 2,
+		};
+		let (start, end1, end2) = flat_edge_last(a, b, c, index);
+		Builder3d::new()
+			.axis(axis)
+			.start_point(start)
+			.first_ending_point(end1)
+			.second_ending_point(end2)
+			.build()
+	}
+
+	/// Creates the zip like [Builder3d::build], checking first that the lines can be computed
+	/// with the type of the coordinates, like
+	/// [BresenhamZip::try_new_checked](crate::zip::BresenhamZip::try_new_checked). The error terms
+	/// of the three-dimensional lines are doubled, so they need twice the room.
+	///
+	/// * `start` - Starting point of both lines
+	/// * `end1`, `end2` - Ending points of the lines, which must share the value of the axis
+	/// * `axis` - Axis to use in the iteration
+	///
+	/// # Error
+	/// [Error::Overflow] if four times the difference of any coordinate between the starting point
+	/// and an ending point doesn't fit in `T`, or the same errors of [Builder3d::build].
+	///
+	pub fn try_new_checked(start: Point3<T>, end1: Point3<T>, end2: Point3<T>, axis: Axis)
+		-> Result<Self, Error<T>> {
+		if !fits_lines(&start, &[end1, end2], 4) {
+			return Err(Error::Overflow);
+		}
+		Builder3d::new()
+			.axis(axis)
+			.start_point(start)
+			.first_ending_point(end1)
+			.second_ending_point(end2)
+			.build()
+	}
+
+}
+
+impl<T: SignedNum, A: AxisSelect> Bresenham3dZip<T, A> {
+
+	#[inline]
+	pub(crate) fn with_axis(start: Point3<T>, end1: Point3<T>, end2: Point3<T>, axis: A) -> Self {
+		let edges = [
+			Edge3d::new(start, end1, axis, true),
+			Edge3d::new(start, end2, axis, true),
+			Edge3d::new(end1, start, axis, false),
+			Edge3d::new(end2, start, axis, false),
+		];
 		Self {
-			a: Bresenham3d::new(start, end1),
-			b: Bresenham3d::new(start, end2),
-			prev_a: start,
-			prev_b: start,
-			goal: end1.nth(axis),
+			start,
+			end_a: end1,
+			end_b: end2,
+			core: ZipCore::new(edges, start.nth(axis.index()), end1.nth(axis.index())),
 			axis
 		}
 	}
 
+	/// Turns the zip into an iterator over every point between each pair of points,
+	/// both included.
+	pub fn pixels(self) -> FillIterator<Self, Point3<T>> {
+		FillIterator::new(self)
+	}
+
+	/// Turns the zip into an iterator yielding the pairs as the point type `P`, like a vertex
+	/// type implementing [Point3Like](crate::Point3Like), see [PairsAs]
+	pub fn pairs_as<P: FromTuple<Point3<T>>>(self) -> PairsAs<Self, P> {
+		PairsAs::new(self)
+	}
+
+	/// Turns the zip into an iterator yielding the [Barycentric](crate::barycentric::Barycentric)
+	/// coordinates of each point along with it, see [BarycentricIterator]
+	pub fn with_barycentric(self) -> BarycentricIterator<Self, Point3<T>> {
+		let vertices = self.vertices();
+		BarycentricIterator::new_3d(self, vertices)
+	}
+
+	/// Turns the zip into one yielding at most `fuel` pairs, a hard bound on the iterations of
+	/// the loops driving it, see [Fueled]
+	pub fn with_fuel(self, fuel: usize) -> Fueled<Self> {
+		Fueled::new(self, fuel)
+	}
+
+	/// Clips each pair of points to the rectangle or box defined by its lowest and highest
+	/// corners, see [ClipRect]
+	pub fn clip_rect(self, aabb: Aabb<Point3<T>>) -> ClipRect<Self, Point3<T>> {
+		ClipRect::new(self, aabb)
+	}
+
+	/// Returns the pair of points of the triangle in the axis value `value`, or `None` if the
+	/// value is out of the triangle. The pair is computed from the slopes of the lines without
+	/// walking them, and regardless of the pairs already yielded. The pairs of the zips starting
+	/// at the flat edge are the ones yielded walking the lines from the ending points, like when
+	/// iterating from the back.
+	///
+	/// ```
+	/// # use bresenham_zip::{Axis, zip_3d::Bresenham3dZip};
+	/// let zip = Bresenham3dZip::from_vertices((0, 0, 100), (50, 50, 0), (100, 0, 100), Axis::Z);
+	/// assert_eq!(zip.unwrap().span_at(50), Some(((25, 25, 50), (75, 25, 50))));
+	/// ```
+	///
+	/// * `value` - Axis value of the pair
+	///
+	pub fn span_at(&self, value: T) -> Option<(Point3<T>, Point3<T>)> {
+		let axis = self.axis.index();
+		let (from, to) = (self.start.nth(axis), self.end_a.nth(axis));
+		if value < from.min(to) || value > from.max(to) {
+			return None;
+		}
+		// the zips starting at the flat edge walk the lines from the ending points
+		let point_at = |end| if self.core.reversed {
+			line_point_at(end, self.start, axis, value, false)
+		} else {
+			line_point_at(self.start, end, axis, value, true)
+		};
+		Some((point_at(self.end_a), point_at(self.end_b)))
+	}
+
+	/// Returns the lowest and highest corners of the smallest box containing the pairs left to
+	/// yield, or `None` if the zip is exhausted. The lines are monotonic, so only the pairs at
+	/// both ends are computed and the zip isn't consumed.
+	pub fn bounding_box(&self) -> Option<Aabb<Point3<T>>> {
+		Some(bounds(&self.core.end_points()?))
+	}
+
+	/// Returns the number of points of the lines between each pair left to yield, both included,
+	/// so the buffers to fill can be allocated beforehand. The zip isn't consumed.
+	pub fn pixel_count(&self) -> usize {
+		self.clone().map(|(a, b)| line_length(&a, &b)).sum()
+	}
+
+	/// Returns the position of the zip, so the iteration can be resumed later with
+	/// [restore_state](Self::restore_state)
+	pub fn save_state(&self) -> ZipState<T> {
+		self.core.save_state()
+	}
+
+	/// Advances the zip to the position saved in `state`. The zip must be built with the same
+	/// points than the one that saved it and it can't be ahead of the saved position, which is
+	/// checked in the debug builds.
+	///
+	/// * `state` - Position returned by [save_state](Self::save_state)
+	///
+	pub fn restore_state(&mut self, state: ZipState<T>) {
+		self.core.restore_state(state);
+	}
+
+	/// Skips the pairs before the axis value `value`, so the next pair yielded is the one in that
+	/// value. If the value was already yielded, nothing is skipped, and if it's out of the
+	/// triangle past its end, the zip is exhausted.
+	///
+	/// * `value` - Axis value of the next pair to yield
+	///
+	pub fn advance_to(&mut self, value: T) {
+		self.core.advance_to(value);
+	}
+
+	/// Splits the zip into two independent zips, the first one yielding the pairs before the
+	/// axis value `value` and the second one yielding the rest, starting at that value. This way
+	/// the work can be divided between several threads.
+	///
+	/// * `value` - Axis value of the first pair of the second zip
+	///
+	pub fn split_at(self, value: T) -> (Self, Self) {
+		let mut second = self.clone();
+		let (first, rest) = self.core.split_at(value);
+		second.core = rest;
+		(Self { core: first, ..self }, second)
+	}
+
+	/// Returns the starting point and both ending points of the zip
+	pub(crate) fn vertices(&self) -> [Point3<T>; 3] {
+		[self.start, self.end_a, self.end_b]
+	}
+
+	/// Turns the zip around, so it's yielded from the ending points to the starting point
+	pub(crate) fn reversed(mut self) -> Self {
+		self.core.reversed = true;
+		self
+	}
+
+	/// Skips the pairs at both ends of the zip out of the axis values between `min` and `max`
+	pub(crate) fn clamp(&mut self, min: T, max: T) {
+		self.core.clamp(min, max);
+	}
+
+	/// Calls `f` with every pair left, walking the edges directly instead of yielding the pairs
+	fn run<F: FnMut(Point3<T>, Point3<T>)>(self, f: F) {
+		self.core.run(f);
+	}
+
 }
 
-impl<T: SignedNum> Iterator for Bresenham3dZip<T> {
+impl<T: SignedNum, A: AxisSelect> Iterator for Bresenham3dZip<T, A> {
 	type Item = (Point3<T>, Point3<T>);
 
-	#[allow(clippy::while_let_on_iterator)]  // needs to be like that to keep using the iterator
 	fn next(&mut self) -> Option<Self::Item> {
-		let axis = self.axis;
-
-		let mut a = None;
-		while let Some(point) = self.a.next() {
-			if (point.nth(axis) - self.prev_a.nth(axis)).abs() > T::zero() {
-				a = Some(self.prev_a);
-				self.prev_a = point;
-				break;
-			}
-			self.prev_a = point;
-		}
+		self.core.next()
+	}
 
-		let mut b = None;
-		while let Some(point) = self.b.next() {
-			if (point.nth(axis) - self.prev_b.nth(axis)).abs() > T::zero() {
-				b = Some(self.prev_b);
-				self.prev_b = point;
-				break;
-			}
-			self.prev_b = point;
-		}
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.core.len(), Some(self.core.len()))
+	}
 
-		if let Some(point) = a {
-			Some((point, b.unwrap()))
-		} else if self.prev_a.nth(axis) == self.goal {
-			self.goal -= T::one();
-			Some((self.prev_a, self.prev_b))
-		} else { None }
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.core.nth(n)
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
 	}
 }
 
-impl<T: SignedNum> Debug for Bresenham3dZip<T> {
+impl<T: SignedNum, A: AxisSelect> ExactSizeIterator for Bresenham3dZip<T, A> {}
+
+/// Iterating from the back walks both lines from the ending points, so when the slope of a line
+/// leaves a tie, the point yielded for an axis value can differ by one unit from the one yielded
+/// iterating from the front.
+impl<T: SignedNum, A: AxisSelect> DoubleEndedIterator for Bresenham3dZip<T, A> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.core.next_back()
+	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.core.nth_back(n)
+	}
+}
+
+impl<T: SignedNum, A: AxisSelect> Debug for Bresenham3dZip<T, A> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		let (a, b) = self.core.heads();
 		write!(f, "Bresenham3dZip [ ({:?}, {:?}, {:?}), ({:?}, {:?}, {:?}) ]. Goal: {:?}",
-		  self.prev_a.0, self.prev_a.1, self.prev_a.2,
-		  self.prev_b.0, self.prev_b.1, self.prev_b.2,
-			self.goal
+			a.0, a.1, a.2,
+			b.0, b.1, b.2,
+			self.core.goal()
+		)
+	}
+}
+
+#[cfg(feature = "defmt")]
+impl<T: SignedNum + defmt::Format, A: AxisSelect> defmt::Format for Bresenham3dZip<T, A> {
+	fn format(&self, f: defmt::Formatter) {
+		let (a, b) = self.core.heads();
+		defmt::write!(f, "Bresenham3dZip [ ({}, {}, {}), ({}, {}, {}) ]. Goal: {}",
+			a.0, a.1, a.2,
+			b.0, b.1, b.2,
+			self.core.goal()
 		)
 	}
 }
 
+/// Returns the point of the line `start -> end` with the value `value` in the axis. When the line
+/// has several points with that value, the last one walked from `start` is returned if `last`, or
+/// the first one otherwise, like the edges of the zip.
+pub(crate) fn line_point_at<T: SignedNum>(
+	start: Point3<T>,
+	end: Point3<T>,
+	axis: u8,
+	value: T,
+	last: bool,
+) -> Point3<T> {
+	let delta = [0, 1, 2].map(|i| wide(end.nth(i)) - wide(start.nth(i)));
+	let distance = delta.map(|delta| delta.abs());
+	let major = if distance[0] >= distance[1] && distance[0] >= distance[2] {
+		0
+	} else if distance[1] >= distance[2] { 1 } else { 2 };
+	let length = distance[major];
+	let travelled = (wide(value) - wide(start.nth(axis))).abs();
+	if distance[axis as usize] == 0 || (!last && travelled == 0) {
+		return if last { end } else { start };
+	}
+	// Bresenham rounds the minor offsets to the closest value, with the ties rounded down, so
+	// when the axis is a minor one, the last step is the one before its offset rounds up again,
+	// and the first one is the one after it rounds up to the current value. The products are
+	// widened so they can't overflow.
+	let travel = distance[axis as usize];
+	let steps = if axis as usize == major {
+		travelled
+	} else if last {
+		floor_div(2 * length * travelled + length, 2 * travel).min(length)
+	} else {
+		floor_div(2 * length * travelled + 2 * travel - length, 2 * travel)
+	};
+	let mut point = start;
+	for (i, (delta, distance)) in delta.into_iter().zip(distance).enumerate() {
+		let offset = if i == major {
+			steps
+		} else {
+			floor_div(2 * distance * steps + length - 1, 2 * length)
+		};
+		point.set_nth(i as u8, narrow(wide(start.nth(i as u8)) + delta.signum() * offset));
+	}
+	point
+}
+
+/// Runs the whole zip of the triangle calling `f` with each pair of points, in the same order
+/// as iterating it. The axis is the one shared by the ending points, like with
+/// [Builder3d::infer_axis]. See the two-dimensional [rasterize](crate::zip::rasterize).
+///
+/// ```
+/// # use bresenham_zip::zip_3d::rasterize;
+/// let mut spans = 0;
+/// rasterize((50, 50, 50), (0, 10, 200), (100, 250, 200), |a, b| {
+///   assert_eq!(a.2, b.2);
+///   spans += 1;
+/// })?;
+/// assert_eq!(spans, 151);
+/// # Ok::<(), bresenham_zip::Error<i32>>(())
+/// ```
+///
+/// * `start` - Starting point of the zip
+/// * `end1`, `end2` - Ending points of the zip, which must share the value of exactly one axis
+/// * `f` - Function called with the point of each line for every value of the axis
+///
+/// # Error
+/// The same errors of [Builder3d::build] can be generated.
+///
+pub fn rasterize<T, F>(start: Point3<T>, end1: Point3<T>, end2: Point3<T>, f: F)
+	-> Result<(), Error<T>> where T: SignedNum, F: FnMut(Point3<T>, Point3<T>) {
+	Builder3d::new()
+		.infer_axis()
+		.start_point(start)
+		.first_ending_point(end1)
+		.second_ending_point(end2)
+		.build()?
+		.run(f);
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::Bresenham3dZip;
@@ -151,6 +465,128 @@ mod tests {
 	    };
 	}
 
+	#[test]
+	fn static_axis() {
+		use crate::AxisZ;
+
+		let dynamic = Bresenham3dZip::new((50, 50, 50), (0, 10, 400), (800, 200, 400), 2);
+		let fixed = Bresenham3dZip::with_axis((50, 50, 50), (0, 10, 400), (800, 200, 400), AxisZ);
+		assert!(dynamic.eq(fixed));
+	}
+
+	#[test]
+	fn reversed() {
+		let mut zip = Bresenham3dZip::new((50, 50, 50), (0, 0, 100), (100, 100, 100), 2).rev();
+		assert_eq!(zip.next(), Some(((0, 0, 100), (100, 100, 100))));
+		let mut z = 99;
+		for (a, b) in zip {
+			assert_eq!((a, b), ((100 - z, 100 - z, z), (z, z, z)));
+			z -= 1;
+		}
+		assert_eq!(z, 49);
+	}
+
+	#[test]
+	fn both_ends() {
+		let mut zip = Bresenham3dZip::new((50, 50, 50), (0, 0, 0), (0, 0, 100), 0);
+		assert_eq!(zip.next_back(), Some(((0, 0, 0), (0, 0, 100))));
+		assert_eq!(zip.next(), Some(((50, 50, 50), (50, 50, 50))));
+		assert_eq!(zip.by_ref().count(), 49);
+		assert_eq!(zip.next_back(), None);
+	}
+
+	#[test]
+	fn exact_size() {
+		let mut zip = Bresenham3dZip::new((50, 50, 50), (0, 0, 0), (0, 0, 100), 0);
+		assert_eq!(zip.len(), 51);
+		zip.next();
+		assert_eq!(zip.len(), 50);
+		assert_eq!(zip.count(), 50);
+	}
+
+	#[test]
+	fn cloned() {
+		let zip = Bresenham3dZip::new((50, 50, 50), (0, 0, 0), (0, 0, 100), 0);
+		assert!(zip.clone().eq(zip));
+	}
+
+	#[test]
+	fn from_vertices() {
+		use crate::{Axis, Error};
+
+		let zip = Bresenham3dZip::from_vertices((0, 0, 0), (50, 50, 50), (0, 100, 0), Axis::Z);
+		assert_eq!(zip.unwrap(), Bresenham3dZip::new((50, 50, 50), (0, 0, 0), (0, 100, 0), 2));
+		let zip = Bresenham3dZip::from_vertices((0, 0, 0), (50, 50, 50), (0, 100, 10), Axis::Z);
+		assert_eq!(zip.unwrap_err(), Error::InvalidZ(50, 10));
+	}
+
+	#[test]
+	fn checked() {
+		use crate::{Axis, Error};
+
+		let (start, end) = ((0, 0, 0), (-31, 31, 20));
+		let zip = Bresenham3dZip::<i8>::try_new_checked(start, end, (31, 31, -31), Axis::Y);
+		assert_eq!(zip.unwrap().last(), Some(((-31, 31, 20), (31, 31, -31))));
+		let zip = Bresenham3dZip::<i8>::try_new_checked(start, end, (31, 31, -32), Axis::Y);
+		assert_eq!(zip.unwrap_err(), Error::Overflow);
+	}
+
+	#[test]
+	fn nth() {
+		let zip = Bresenham3dZip::new((0, 0, 0), (-37, 11, 5), (23, 11, -40), 1);
+		for n in 0..13 {
+			let (mut stepped, mut skipped) = (zip.clone(), zip.clone());
+			for _ in 0..n {
+				stepped.next();
+			}
+			assert_eq!(skipped.nth(n), stepped.next());
+			assert!(skipped.eq(stepped));
+
+			let (mut stepped, mut skipped) = (zip.clone(), zip.clone());
+			for _ in 0..n {
+				stepped.next_back();
+			}
+			assert_eq!(skipped.nth_back(n), stepped.next_back());
+			assert!(skipped.rev().eq(stepped.rev()));
+		}
+		assert_eq!(zip.clone().count(), 12);
+		assert_eq!(zip.last(), Some(((-37, 11, 5), (23, 11, -40))));
+	}
+
+	#[test]
+	fn checkpoint() {
+		let mut zip = Bresenham3dZip::new((50, 50, 50), (0, 0, 0), (0, 0, 100), 0);
+		zip.by_ref().take(10).for_each(drop);
+		let mut resumed = Bresenham3dZip::new((50, 50, 50), (0, 0, 0), (0, 0, 100), 0);
+		resumed.restore_state(zip.save_state());
+		assert!(resumed.eq(zip));
+	}
+
+	#[test]
+	fn span_at() {
+		let triangles = [
+			((50, 50, 50), (0, 0, 0), (0, 100, 100), 0),
+			((0, 0, 0), (-37, 11, 5), (23, 11, -40), 1),
+			((3, -2, 7), (20, 40, -13), (9, -30, -13), 2),
+			((0, 0, 0), (-5, 0, 0), (5, 0, 3), 1),
+		];
+		for (start, end_a, end_b, axis) in triangles {
+			let zip = Bresenham3dZip::new(start, end_a, end_b, axis);
+			for zip in [zip.clone(), zip.reversed()] {
+				for (a, b) in zip.clone() {
+					let value = [a.0, a.1, a.2][axis as usize];
+					assert_eq!(zip.span_at(value), Some((a, b)));
+				}
+			}
+		}
+		let zip = Bresenham3dZip::new((0, 0, 0), (-37, 11, 5), (23, 11, -40), 1);
+		assert_eq!(zip.span_at(12), None);
+		let zip = Bresenham3dZip::<i16>::new((0, 0, 0), (-300, 300, 100), (300, 300, -100), 1);
+		assert!(zip.clone().eq((0..=300).filter_map(|value| zip.span_at(value))));
+		let zip = Bresenham3dZip::new((0, 0, 0), (-99999, 100000, 7), (100000, 100000, -3), 1);
+		assert!(zip.clone().eq((0..=100000).filter_map(|value| zip.span_at(value))));
+	}
+
 	mod x_axis {
 		use super::Bresenham3dZip;
 
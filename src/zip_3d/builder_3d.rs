@@ -1,15 +1,19 @@
-//! Contains the logic to build new two-dimensional BresenhamZips
+//! Contains the logic to build new three-dimensional BresenhamZips
 
-use crate::{Axis, Point3, SignedNum};
-use crate::util::Point;
-use crate::zip_3d::Bresenham3dZip;
+use crate::{Axis, AxisSelect, BuilderOption, Direction, Error, Point3, Point3Like, PointRole};
+use crate::{SignedNum, Snap};
+use crate::util::{shared_axis, snap_axis, Point};
+use crate::zip_3d::{Bresenham3dZip, Supercover3dZip};
 
 const MAX_ACCEPTED_AXIS: u8 = 2;
+const INFERRED_AXIS: u8 = u8::MAX;
 
 
 /// Builder to construct a new [Bresenham3dZip]. It is required to specify the starting point and two
 /// ending points, both of them **must share the same value in the axis** of the zip to build.
 /// This axis is also required in the building pipeline.
+/// The setters with the `with_` prefix take and return the builder by value, so a configured
+/// builder can be kept without a mutable binding.
 ///
 /// ```
 /// # use std::error::Error;
@@ -22,12 +26,25 @@ const MAX_ACCEPTED_AXIS: u8 = 2;
 ///   .build()?;
 /// # Ok(()) }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Builder3d<T> {
 	start: Option<Point3<T>>,
 	end_a: Option<Point3<T>>,
 	end_b: Option<Point3<T>>,
-	axis: u8
+	axis: u8,
+	tolerance: T,
+	snap: Snap,
+	apex: bool,
+	flat_edge: bool,
+	sorted: bool,
+	direction: Direction,
+	clamp: Option<(T, T)>,
+}
+
+impl<T: SignedNum> Default for Builder3d<T> {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 impl<T: SignedNum> Builder3d<T> {
@@ -38,7 +55,14 @@ impl<T: SignedNum> Builder3d<T> {
 			start: None,
 			end_a: None,
 			end_b: None,
-			axis: MAX_ACCEPTED_AXIS + 1
+			axis: MAX_ACCEPTED_AXIS + 1,
+			tolerance: T::zero(),
+			snap: Snap::Midpoint,
+			apex: true,
+			flat_edge: true,
+			sorted: false,
+			direction: Direction::FromApex,
+			clamp: None,
 		}
 	}
 
@@ -56,30 +80,195 @@ impl<T: SignedNum> Builder3d<T> {
 		self
 	}
 
+	/// Deduces the axis of the zip from the ending points when building it, instead of specifying
+	/// it with [Builder3d::axis]. The ending points must share the value of exactly one axis.
+	///
+	/// ```
+	/// # use bresenham_zip::zip_3d::Builder3d;
+	/// let mut builder = Builder3d::new();
+	/// builder.infer_axis().start_point((50, 50, 50)).first_ending_point((0, 100, 200)).second_ending_point((100, 0, 200));
+	/// assert!(builder.build().is_ok());
+	/// ```
+	pub fn infer_axis(&mut self) -> &mut Builder3d<T> {
+		self.axis = INFERRED_AXIS;
+		self
+	}
+
+	/// Allows the ending points to differ in the axis of the zip up to the given tolerance.
+	/// When they differ, both of them are moved to a common value chosen with `snap`
+	/// instead of generating an error.
+	///
+	/// * `tolerance` - Maximum difference accepted between the ending points in the axis
+	/// * `snap` - Common value to move the ending points to
+	///
+	pub fn axis_tolerance(&mut self, tolerance: T, snap: Snap) -> &mut Builder3d<T> {
+		self.tolerance = tolerance;
+		self.snap = snap;
+		self
+	}
+
+	/// Specifies if the first pair, where both lines are at the starting point, is yielded.
+	/// It is included by default.
+	///
+	/// * `include` - Whether to yield the starting point
+	///
+	pub fn include_apex(&mut self, include: bool) -> &mut Builder3d<T> {
+		self.apex = include;
+		self
+	}
+
+	/// Specifies if the last pair, the flat edge between both ending points, is yielded. It is
+	/// included by default, excluding it avoids yielding twice the axis value shared by two
+	/// halves of a triangle.
+	///
+	/// * `include` - Whether to yield the flat edge
+	///
+	pub fn include_flat_edge(&mut self, include: bool) -> &mut Builder3d<T> {
+		self.flat_edge = include;
+		self
+	}
+
+	/// Specifies if the points of each pair are sorted, so the first one always has the lowest
+	/// value in the first of the other axes, in X, Y, Z order. Otherwise, the first point of each
+	/// pair belongs to the line towards the first ending point.
+	///
+	/// * `sorted` - Whether to sort the points of each pair
+	///
+	pub fn sorted(&mut self, sorted: bool) -> &mut Builder3d<T> {
+		self.sorted = sorted;
+		self
+	}
+
+	/// Specifies the end of the triangle where the iteration starts, regardless of which point
+	/// is the starting one. By default, it starts at the starting point.
+	///
+	/// * `direction` - End of the triangle yielded first
+	///
+	pub fn direction(&mut self, direction: Direction) -> &mut Builder3d<T> {
+		self.direction = direction;
+		self
+	}
+
+	/// Restricts the zip to the axis values between `min` and `max`, both included, so the pairs
+	/// out of them are never yielded
+	///
+	/// * `min` - Lowest axis value to yield
+	/// * `max` - Highest axis value to yield
+	///
+	pub fn clamp_axis(&mut self, min: T, max: T) -> &mut Builder3d<T> {
+		self.clamp = Some((min, max));
+		self
+	}
+
 	/// Specifies the starting point for both the lines to be drawn in the [Bresenham3dZip]
 	///
-	/// * `start` - Starting point of type (T, T, T)
+	/// * `start` - Starting point, any [Point3Like] like (T, T, T)
 	///
-	pub fn start_point(&mut self, start: Point3<T>) -> &mut Builder3d<T> {
-		self.start = Some(start);
+	pub fn start_point<P: Point3Like<T>>(&mut self, start: P) -> &mut Builder3d<T> {
+		self.start = Some(start.to_tuple());
 		self
 	}
 
 	/// Specifies the ending point for one of the lines to be drawn in the [Bresenham3dZip]
 	///
-	/// * `end` - Ending point of one line, must be of type (T, T, T)
+	/// * `end` - Ending point of one line, any [Point3Like] like (T, T, T)
 	///
-	pub fn first_ending_point(&mut self, end: Point3<T>) -> &mut Builder3d<T> {
-		self.end_a = Some(end);
+	pub fn first_ending_point<P: Point3Like<T>>(&mut self, end: P) -> &mut Builder3d<T> {
+		self.end_a = Some(end.to_tuple());
 		self
 	}
 
 	/// Specifies the ending point for one of the lines to be drawn in the [Bresenham3dZip]
 	///
-	/// * `end` - Ending point of one line, must be of type (T, T, T)
+	/// * `end` - Ending point of one line, any [Point3Like] like (T, T, T)
+	///
+	pub fn second_ending_point<P: Point3Like<T>>(&mut self, end: P) -> &mut Builder3d<T> {
+		self.end_b = Some(end.to_tuple());
+		self
+	}
+
+	/// Returns the builder with another starting point, keeping the rest of the configuration.
+	/// As the builders are [Copy], a configured builder can create the zips of many triangles.
+	///
+	/// * `start` - Starting point, any [Point3Like] like (T, T, T)
+	///
+	pub fn with_start<P: Point3Like<T>>(mut self, start: P) -> Self {
+		self.start_point(start);
+		self
+	}
+
+	/// Returns the builder with another first ending point, keeping the rest of the configuration
+	///
+	/// * `end` - Ending point of one line, any [Point3Like] like (T, T, T)
+	///
+	pub fn with_first_end<P: Point3Like<T>>(mut self, end: P) -> Self {
+		self.first_ending_point(end);
+		self
+	}
+
+	/// Returns the builder with another second ending point, keeping the rest of the configuration
+	///
+	/// * `end` - Ending point of one line, any [Point3Like] like (T, T, T)
 	///
-	pub fn second_ending_point(&mut self, end: Point3<T>) -> &mut Builder3d<T> {
-		self.end_b = Some(end);
+	pub fn with_second_end<P: Point3Like<T>>(mut self, end: P) -> Self {
+		self.second_ending_point(end);
+		self
+	}
+
+	/// Same as [Builder3d::axis], taking and returning the builder by value
+	pub fn with_axis(mut self, axis: Axis) -> Self {
+		self.axis(axis);
+		self
+	}
+
+	/// Same as [Builder3d::infer_axis], taking and returning the builder by value
+	pub fn with_inferred_axis(mut self) -> Self {
+		self.infer_axis();
+		self
+	}
+
+	/// Same as [Builder3d::axis_tolerance], taking and returning the builder by value
+	pub fn with_axis_tolerance(mut self, tolerance: T, snap: Snap) -> Self {
+		self.axis_tolerance(tolerance, snap);
+		self
+	}
+
+	/// Same as [Builder3d::include_apex], taking and returning the builder by value
+	pub fn with_apex(mut self, include: bool) -> Self {
+		self.include_apex(include);
+		self
+	}
+
+	/// Same as [Builder3d::include_flat_edge], taking and returning the builder by value
+	pub fn with_flat_edge(mut self, include: bool) -> Self {
+		self.include_flat_edge(include);
+		self
+	}
+
+	/// Same as [Builder3d::sorted], taking and returning the builder by value
+	pub fn with_sorted(mut self, sorted: bool) -> Self {
+		self.sorted(sorted);
+		self
+	}
+
+	/// Same as [Builder3d::direction], taking and returning the builder by value
+	pub fn with_direction(mut self, direction: Direction) -> Self {
+		self.direction(direction);
+		self
+	}
+
+	/// Same as [Builder3d::clamp_axis], taking and returning the builder by value
+	pub fn with_clamp_axis(mut self, min: T, max: T) -> Self {
+		self.clamp_axis(min, max);
+		self
+	}
+
+	/// Removes the three points, keeping the axis and the rest of the configuration to build
+	/// the zip of another triangle
+	pub fn reset(&mut self) -> &mut Builder3d<T> {
+		self.start = None;
+		self.end_a = None;
+		self.end_b = None;
 		self
 	}
 
@@ -88,71 +277,275 @@ impl<T: SignedNum> Builder3d<T> {
 	/// # Error
 	/// This call can generate the following errors
 	///
-	/// * [Error::MissingAxis], if no axis was specified.
+	/// * [Error::MissingAxis], if no axis was specified, or if it had to be inferred and
+	///   the ending points don't share exactly one axis.
 	/// * [Error::MissingPoint], if any of the three points is missing.
 	/// * [Error::InvalidX], if the axis is X and the two ending points have divergent X values.
 	/// * [Error::InvalidY], if the axis is Y and the two ending points have divergent Y values.
-	/// * [Error::InvalidZ], if the axis is Z and the two ending points have divergent Y values.
+	/// * [Error::InvalidZ], if the axis is Z and the two ending points have divergent Z values.
 	///
-	pub fn build<'a, 'b>(&'b self) {
-		if self.axis > MAX_ACCEPTED_AXIS {
-			return;
+	/// The ending points are divergent when they differ by more than the
+	/// [axis tolerance](Builder3d::axis_tolerance).
+	///
+	pub fn build(&self) -> Result<Bresenham3dZip<T>, Error<T>> {
+		if self.axis != INFERRED_AXIS {
+			return self.build_along(self.axis);
+		}
+		match (self.end_a, self.end_b) {
+			(Some(end_a), Some(end_b)) => {
+				self.build_along(shared_axis(end_a, end_b).ok_or(Error::MissingAxis)?)
+			}
+			// any valid axis reports the missing point
+			_ => self.build_along(0),
+		}
+	}
+
+	/// Builds the [Bresenham3dZip] corresponding with the defined arguments, travelling the axis
+	/// selected at compile time with one of the [AxisSelect] markers. The axis specified with
+	/// [Builder3d::axis], if any, is ignored.
+	///
+	/// ```
+	/// # use std::error::Error;
+	/// # pub fn main() -> Result<(), Box<dyn Error>> {
+	/// let zip = bresenham_zip::zip_3d::Builder3d::new()
+	///   .start_point((50, 50, 50))
+	///   .first_ending_point((0, 100, 200))
+	///   .second_ending_point((100, 100, 200))
+	///   .build_along(bresenham_zip::AxisZ)?;
+	/// # Ok(()) }
+	/// ```
+	///
+	/// # Error
+	/// The same errors of [Builder3d::build] can be generated.
+	///
+	pub fn build_along<A>(&self, axis: A) -> Result<Bresenham3dZip<T, A>, Error<T>>
+		where A: AxisSelect {
+		let selected = axis;
+		let axis = selected.index();
+		if axis > MAX_ACCEPTED_AXIS {
+			return Err(Error::MissingAxis);
 		}
-		let axis = self.axis;
 
 		match (&self.start, &self.end_a, &self.end_b) {
-			(None, _, _) => (),
-			(_, None, _) => (),
-			(_, _, None) => (),
+			(None, _, _) => Err(Error::MissingPoint(PointRole::Start)),
+			(_, None, _) => Err(Error::MissingPoint(PointRole::FirstEnding)),
+			(_, _, None) => Err(Error::MissingPoint(PointRole::SecondEnding)),
 			(Some(start), Some(end_a), Some(end_b)) => {
-				if end_a.nth(axis) != end_b.nth(axis) {
-					();
+				let snapped = snap_axis(*end_a, *end_b, axis, self.tolerance, self.snap);
+				if let Some((mut end_a, mut end_b)) = snapped {
+					let other = if axis == 0 { 1 } else { 0 };
+					if self.sorted && end_a.nth(other) > end_b.nth(other) {
+						(end_a, end_b) = (end_b, end_a);
+					}
+					let mut zip = Bresenham3dZip::with_axis(*start, end_a, end_b, selected);
+					if !self.apex {
+						zip.next();
+					}
+					if !self.flat_edge {
+						zip.next_back();
+					}
+					if let Some((min, max)) = self.clamp {
+						zip.clamp(min, max);
+					}
+					zip.core.reversed = self.direction == Direction::FromBase;
+					Ok(zip)
 				} else {
-					Bresenham3dZip::new(*start, *end_a, *end_b, self.axis);
+					Err(match axis {
+						0 => Error::InvalidX(end_a.0, end_b.0),
+						1 => Error::InvalidY(end_a.1, end_b.1),
+						_ => Error::InvalidZ(end_a.2, end_b.2),
+					})
 				}
 			}
 		}
 	}
 
+	/// Builds the [Supercover3dZip] corresponding with the defined arguments, which drives both
+	/// lines with 6-connected supercover lines so they don't have diagonal leaks
+	///
+	/// # Error
+	/// The same errors of [Builder3d::build] can be generated, and [Error::UnsupportedOption] if
+	/// an option only applied to the [Bresenham3dZip] is set.
+	///
+	pub fn build_supercover(&self) -> Result<Supercover3dZip<T>, Error<T>> {
+		self.check_plain_options()?;
+		let zip = self.build()?;
+		let [start, end_a, end_b] = zip.vertices();
+		Ok(Supercover3dZip::new(start, end_a, end_b, zip.axis))
+	}
+
+	/// Checks that none of the options only applied to the [Bresenham3dZip] is set, for the zips
+	/// taking just the points and the axis
+	fn check_plain_options(&self) -> Result<(), Error<T>> {
+		let options = [
+			(self.apex, BuilderOption::Apex),
+			(self.flat_edge, BuilderOption::FlatEdge),
+			(self.direction == Direction::FromApex, BuilderOption::Direction),
+			(self.clamp.is_none(), BuilderOption::ClampAxis),
+		];
+		match options.into_iter().find(|(default, _)| !default) {
+			Some((_, option)) => Err(Error::UnsupportedOption(option)),
+			None => Ok(()),
+		}
+	}
+
 }
 
 #[cfg(test)]
 mod test {
-	use crate::{Axis, build_zip};
-	use core::error::Error;
+	use crate::{Axis, AxisX, build_zip, BuilderOption, Direction, Error, PointRole, Snap};
 	use crate::zip_3d::Builder3d;
 
 	#[test]
 	fn missing_axis() {
-
+		let mut builder = Builder3d::new();
+		builder.start_point((0, 0, 0))
+			.first_ending_point((10, 10, 10))
+			.second_ending_point((20, 10, 10));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingAxis);
+		assert!(builder.axis(Axis::Z).build().is_ok());
 	}
 
 	#[test]
 	fn missing_point() {
-
+		let mut builder = Builder3d::new();
+		builder.axis(Axis::Z);
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::Start));
+		builder.start_point((0, 0, 0));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::FirstEnding));
+		builder.first_ending_point((10, 10, 10));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::SecondEnding));
+		builder.second_ending_point((10, 20, 10));
+		assert!(builder.build().is_ok());
 	}
 
 	#[test]
 	fn invalid_points() {
+		let zip = build_zip!(3D:X - (0, 0, 0) -> (10, 10, 10), (20, 10, 10));
+		assert_eq!(zip.unwrap_err(), Error::InvalidX(10, 20));
+		let zip = build_zip!(3D:Y - (0, 0, 0) -> (10, 10, 10), (10, 20, 10));
+		assert_eq!(zip.unwrap_err(), Error::InvalidY(10, 20));
+		let zip = build_zip!(3D:Z - (0, 0, 0) -> (10, 10, 10), (10, 10, 20));
+		assert_eq!(zip.unwrap_err(), Error::InvalidZ(10, 20));
+	}
+
+	#[test]
+	fn inferred_axis() {
+		let mut builder = Builder3d::new();
+		builder.infer_axis().start_point((0, 0, 0)).first_ending_point((0, 10, 20));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingPoint(PointRole::SecondEnding));
+		builder.second_ending_point((10, 20, 20));
+		assert!(builder.build().unwrap().all(|(a, b)| a.2 == b.2));
+		builder.second_ending_point((0, 10, 30));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingAxis);
+		builder.second_ending_point((10, 0, 30));
+		assert_eq!(builder.build().unwrap_err(), Error::MissingAxis);
+	}
+
+	#[test]
+	fn reusable() {
+		let mut builder = Builder3d::new();
+		builder.axis(Axis::Z).first_ending_point((0, 0, 4)).second_ending_point((4, 4, 4));
+		let zips = [(0, 0, 0), (4, 0, 0)].map(|start| builder.with_start(start).build().unwrap());
+		assert!(zips.iter().all(|zip| zip.len() == 5));
+		let zip = Builder3d::default().with_inferred_axis().with_clamp_axis(0, 2)
+			.with_start((0, 0, 0)).with_first_end((0, 4, 4)).with_second_end((4, 0, 4));
+		assert_eq!(zip.build().unwrap().len(), 3);
+		let zip = builder.reset().with_start((0, 0, 0)).build();
+		assert_eq!(zip.unwrap_err(), Error::MissingPoint(PointRole::FirstEnding));
+	}
+
+	#[test]
+	fn tolerance() {
+		let mut builder = Builder3d::new();
+		builder.axis(Axis::Z).start_point((0, 0, 0))
+			.first_ending_point((-10, 0, 20)).second_ending_point((10, 10, 21));
+		assert_eq!(builder.build().unwrap_err(), Error::InvalidZ(20, 21));
+		builder.axis_tolerance(1, Snap::Max);
+		assert_eq!(builder.build().unwrap().next_back(), Some(((-10, 0, 21), (10, 10, 21))));
+	}
+
+	#[test]
+	fn endpoints() {
+		let mut builder = Builder3d::new();
+		builder.axis(Axis::Z).start_point((0, 0, 0))
+			.first_ending_point((-4, 0, 4)).second_ending_point((4, 4, 4));
+		let mut zip = builder.include_apex(false).include_flat_edge(false).build().unwrap();
+		assert_eq!(zip.len(), 3);
+		assert_eq!(zip.next(), Some(((-1, 0, 1), (1, 1, 1))));
+		assert_eq!(zip.next_back(), Some(((-3, 0, 3), (3, 3, 3))));
+	}
+
+	#[test]
+	fn sorted() {
+		let mut builder = Builder3d::new();
+		builder.axis(Axis::Y).sorted(true).start_point((0, 0, 0))
+			.first_ending_point((5, 8, 0)).second_ending_point((-5, 8, 3));
+		assert!(builder.build().unwrap().all(|(a, b)| a.0 <= b.0));
+		builder.axis(Axis::X).first_ending_point((5, 8, 0)).second_ending_point((5, -8, 3));
+		assert!(builder.build().unwrap().all(|(a, b)| a.1 <= b.1));
+	}
 
+	#[test]
+	fn direction() {
+		let mut builder = Builder3d::new();
+		builder.axis(Axis::X).direction(Direction::FromBase).start_point((0, 0, 0))
+			.first_ending_point((6, 2, 0)).second_ending_point((6, -2, 3));
+		let zip = builder.build().unwrap();
+		assert_eq!(zip.len(), 7);
+		assert!(zip.rev().map(|(a, b)| (a.0, b.0)).eq((0..=6).map(|x| (x, x))));
+	}
+
+	#[test]
+	fn static_axis() {
+		let mut builder = Builder3d::new();
+		builder.axis(Axis::Z)
+			.start_point((0, 0, 0))
+			.first_ending_point((10, 10, 10))
+			.second_ending_point((10, -10, -10));
+		assert!(builder.build().is_err());
+		let mut zip = builder.build_along(AxisX).unwrap();
+		assert_eq!(zip.next(), Some(((0, 0, 0), (0, 0, 0))));
+		assert_eq!(zip.next_back(), Some(((10, 10, 10), (10, -10, -10))));
+	}
+
+	#[test]
+	fn plain_options() {
+		let mut builder = Builder3d::new();
+		builder.axis(Axis::Z).start_point((0, 0, 0))
+			.first_ending_point((-4, 2, 4)).second_ending_point((4, -2, 4));
+		assert!(builder.build_supercover().is_ok());
+		let unsupported = |option| Err(Error::UnsupportedOption(option));
+		let error = builder.include_apex(false).build_supercover().map(|_| ());
+		assert_eq!(error, unsupported(BuilderOption::Apex));
+		let error = builder.include_apex(true).include_flat_edge(false).build_supercover();
+		assert_eq!(error.map(|_| ()), unsupported(BuilderOption::FlatEdge));
+		builder.include_flat_edge(true).direction(Direction::FromBase);
+		assert_eq!(builder.build_supercover().map(|_| ()), unsupported(BuilderOption::Direction));
+		builder.direction(Direction::FromApex).clamp_axis(1, 3);
+		assert_eq!(builder.build_supercover().map(|_| ()), unsupported(BuilderOption::ClampAxis));
 	}
 
 	#[test]
 	fn valid() {
-		// // Direct building
-		// assert_eq!(format!("{:?}", build_zip!(3D:X - (50, 50, 50) -> (0, 0, 0), (0, 100, 200)).unwrap()),
-		//            "Bresenham3dZip [ (50, 50, 50), (50, 50, 50) ]. Goal: 0");
-		// // Modified building
-		// let built = Builder3d::new()
-		// 	.axis(Axis::X)
-		// 	.axis(Axis::Y)
-		// 	.start_point((25, 25, 25))
-		// 	.second_ending_point((50, 50, 50))
-		// 	.start_point((10, 10, 10))
-		// 	.first_ending_point((0, 100, 0))
-		// 	.second_ending_point((100, 100, 100))
-		// 	.build();
-		// assert_eq!(format!("{:?}", built.unwrap()), "Bresenham3dZip [ (10, 10, 10), (10, 10, 10) ]. Goal: 100");
+		// Direct building
+		let mut zip = build_zip!(3D:X - (50, 50, 50) -> (0, 0, 0), (0, 100, 200)).unwrap();
+		let (a, b) = zip.next().unwrap();
+		assert_eq!((a, b.0), ((50, 50, 50), 50));
+		assert_eq!(zip.next_back(), Some(((0, 0, 0), (0, 100, 200))));
+		// Modified building
+		let mut built = Builder3d::new()
+			.axis(Axis::X)
+			.axis(Axis::Y)
+			.start_point((25, 25, 25))
+			.second_ending_point((50, 50, 50))
+			.start_point((10, 10, 10))
+			.first_ending_point((0, 100, 0))
+			.second_ending_point((100, 100, 100))
+			.build()
+			.unwrap();
+		assert_eq!(built.next(), Some(((10, 10, 10), (10, 10, 10))));
+		assert_eq!(built.next_back(), Some(((0, 100, 0), (100, 100, 100))));
 	}
 
 }
\ No newline at end of file
@@ -0,0 +1,141 @@
+//! Contains the floating-point variant of the three-dimensional zip
+
+use core::fmt::{Debug, Formatter};
+use crate::util::{advance, Point};
+use crate::{Axis, Error, FloatNum, Point3};
+
+/// Zip stepping both lines of a triangle with a digital differential analyzer (DDA) instead of
+/// Bresenham. It accepts floating-point vertices and advances one unit in the axis each iteration,
+/// yielding the points of both lines without rounding them, so sub-pixel accurate renderers can
+/// apply their own rounding.
+///
+/// The axis values yielded are the one of the starting point and the following ones, unit by
+/// unit, while they don't go past the value of the ending points.
+///
+/// ```
+/// # use bresenham_zip::{Axis, zip_3d::Dda3d};
+/// let zip = Dda3d::new((5.0, 5.0, 0.5), (0.0, 0.0, 10.5), (10.0, 5.0, 10.5), Axis::Z).unwrap();
+/// for (a, b) in zip {
+///   assert_eq!(a.2, b.2);
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Dda3d<F> {
+	a: Point3<F>,
+	b: Point3<F>,
+	step_a: Point3<F>,
+	step_b: Point3<F>,
+	remaining: usize,
+}
+
+impl<F: FloatNum> Dda3d<F> {
+
+	/// Creates a new DDA zip of the triangle with a flat edge between the two ending points
+	///
+	/// * `start` - Vertex out of the flat edge
+	/// * `end1`, `end2` - Vertices of the flat edge, sharing the value in the axis
+	/// * `axis` - Axis to use in the iteration
+	///
+	/// # Error
+	/// This call can generate the following errors
+	///
+	/// * [Error::InvalidX], if the axis is X and the two ending points have divergent X values.
+	/// * [Error::InvalidY], if the axis is Y and the two ending points have divergent Y values.
+	/// * [Error::InvalidZ], if the axis is Z and the two ending points have divergent Z values.
+	///
+	pub fn new(start: Point3<F>, end1: Point3<F>, end2: Point3<F>, axis: Axis)
+		-> Result<Self, Error<F>> {
+		let axis = match axis {
+			Axis::X => 0,
+			Axis::Y => 1,
+			Axis::Z => 2,
+		};
+		if end1.nth(axis) != end2.nth(axis) {
+			return Err(match axis {
+				0 => Error::InvalidX(end1.0, end2.0),
+				1 => Error::InvalidY(end1.1, end2.1),
+				_ => Error::InvalidZ(end1.2, end2.2),
+			});
+		}
+
+		let length = end1.nth(axis) - start.nth(axis);
+		let distance = if length < F::ZERO { -length } else { length };
+		if distance == F::ZERO {
+			let still = (F::ZERO, F::ZERO, F::ZERO);
+			return Ok(Self { a: end1, b: end2, step_a: still, step_b: still, remaining: 1 });
+		}
+		Ok(Self {
+			a: start,
+			b: start,
+			step_a: (
+				(end1.0 - start.0) / distance,
+				(end1.1 - start.1) / distance,
+				(end1.2 - start.2) / distance,
+			),
+			step_b: (
+				(end2.0 - start.0) / distance,
+				(end2.1 - start.1) / distance,
+				(end2.2 - start.2) / distance,
+			),
+			remaining: distance.to_usize() + 1,
+		})
+	}
+
+}
+
+impl<F: FloatNum> Iterator for Dda3d<F> {
+	type Item = (Point3<F>, Point3<F>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		let pair = (self.a, self.b);
+		advance(&mut self.a, &self.step_a);
+		advance(&mut self.b, &self.step_b);
+		Some(pair)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<F: FloatNum> ExactSizeIterator for Dda3d<F> {}
+
+impl<F: FloatNum> Debug for Dda3d<F> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Dda3d [ {:?}, {:?} ]. Remaining: {}", self.a, self.b, self.remaining)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Axis, Error};
+	use super::Dda3d;
+
+	#[test]
+	fn steps() {
+		let zip = Dda3d::new((5.0, 5.0, 0.0), (0.0, 0.0, 10.0), (10.0, 5.0, 10.0), Axis::Z);
+		let mut zip = zip.unwrap();
+		assert_eq!(zip.len(), 11);
+		for z in 0..=10 {
+			let z = z as f64;
+			let (a, b) = zip.next().unwrap();
+			assert_eq!(a, (5.0 - z / 2.0, 5.0 - z / 2.0, z));
+			assert_eq!(b, (5.0 + z / 2.0, 5.0, z));
+		}
+		assert_eq!(zip.next(), None);
+	}
+
+	#[test]
+	fn invalid() {
+		let zip = Dda3d::new((0.0, 0.0, 0.0), (-1.0, 2.0, 1.0), (1.0, 2.0, 1.5), Axis::Z);
+		assert_eq!(zip.unwrap_err(), Error::InvalidZ(1.0, 1.5));
+		let zip = Dda3d::new((0.0, 0.0, 0.0), (-1.0, 2.0, 1.0), (1.0, 2.0, 1.5), Axis::Y);
+		assert_eq!(zip.unwrap().count(), 3);
+	}
+
+}
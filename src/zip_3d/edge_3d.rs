@@ -0,0 +1,206 @@
+//! Contains the lines of the three-dimensional zip, stepped one axis value at a time
+
+use crate::util::{wide, Increment, Point, Ratio};
+use crate::zip_core::ZipEdge;
+use crate::{AxisSelect, Point3, SignedNum};
+
+/// Coordinate of an [Edge3d] out of the axis, kept as a [Ratio]
+#[derive(Clone, PartialEq)]
+struct Coordinate<T> {
+	offset: Ratio<T>,
+	increment: Increment<T>,
+	carry: Increment<T>,
+	sign: T,
+	index: u8,
+}
+
+/// Line of the triangle yielding, for each value of the axis, the same point that walking it
+/// with Bresenham would give. Instead of walking every point of the line, the other coordinates
+/// are kept as [Ratio]s of the axis values travelled, so each step takes constant time.
+///
+/// Bresenham rounds the offsets of the minor coordinates to the closest value, rounding down the
+/// ties. When the axis is a minor coordinate, the major coordinate is a ratio of the axis values
+/// travelled and the other minor coordinate is a ratio of the major one, so it receives an extra
+/// `carry` whenever the major coordinate does.
+///
+/// When the line has several points with the same axis value, the edge yields the last one walked
+/// from `from` if `last`, like the front of the zip, or the first one otherwise, like the back of
+/// the zip, which walks the line starting at the ending point.
+///
+/// Edges parallel to the axis keep the other coordinates of `from` on every step, skipping the
+/// ratios.
+#[derive(Clone, PartialEq)]
+pub(crate) struct Edge3d<T, A = u8> {
+	from: Point3<T>,
+	to: Point3<T>,
+	coordinates: [Coordinate<T>; 2],
+	travelled: T,
+	length: T,
+	step: T,
+	parallel: bool,
+	last: bool,
+	axis: A,
+}
+
+impl<T: SignedNum, A: AxisSelect> Edge3d<T, A> {
+
+	pub(crate) fn new(from: Point3<T>, to: Point3<T>, axis: A, last: bool) -> Self {
+		let index = axis.index();
+		let delta = [to.0 - from.0, to.1 - from.1, to.2 - from.2];
+		let distance = delta.map(|delta| wide(delta.abs()));
+		let major = if distance[0] >= distance[1] && distance[0] >= distance[2] {
+			0
+		} else if distance[1] >= distance[2] { 1 } else { 2 };
+		let (length, travel) = (distance[major as usize].max(1), distance[index as usize].max(1));
+		let others = match index {
+			0 => [1, 2],
+			1 => [0, 2],
+			_ => [0, 1],
+		};
+		// offset of a minor coordinate after `steps` steps of the major one
+		let minor = |i: u8, steps: i128| {
+			let offset = Ratio::new(2 * distance[i as usize] * steps + length - 1, 2 * length);
+			Coordinate {
+				increment: offset.increment(2 * distance[i as usize]),
+				carry: (T::zero(), T::zero()),
+				offset,
+				sign: delta[i as usize].signum(),
+				index: i,
+			}
+		};
+		let coordinates = if index == major {
+			others.map(|i| minor(i, 0))
+		} else {
+			// steps of the major coordinate after travelling k axis values
+			let dividend = if last { length } else { 2 * travel - length };
+			let steps = Ratio::new(dividend, 2 * travel);
+			let increment = steps.increment(2 * length);
+			let driver = Coordinate {
+				offset: steps,
+				increment,
+				carry: (T::zero(), T::zero()),
+				sign: delta[major as usize].signum(),
+				index: major,
+			};
+			let i = if others[0] == major { others[1] } else { others[0] };
+			let mut other = minor(i, wide(steps.value()));
+			other.carry = other.increment;
+			other.increment = other.offset.increment(2 * distance[i as usize] * wide(increment.0));
+			[driver, other]
+		};
+		let parallel = others.iter().all(|i| distance[*i as usize] == 0);
+		let length = delta[index as usize];
+		Self {
+			from,
+			to,
+			coordinates,
+			travelled: T::zero(),
+			length: length.abs(),
+			step: if length < T::zero() { -T::one() } else { T::one() },
+			parallel,
+			last,
+			axis,
+		}
+	}
+
+}
+
+impl<T: SignedNum, A: AxisSelect> ZipEdge<T> for Edge3d<T, A> {
+	type Point = Point3<T>;
+
+	fn point(&self) -> Self::Point {
+		// the last point walked is the ending one, and the first one is the starting one
+		if self.last && self.travelled == self.length {
+			return self.to;
+		} else if !self.last && self.travelled == T::zero() {
+			return self.from;
+		}
+		let index = self.axis.index();
+		let mut point = self.from;
+		point.set_nth(index, self.from.nth(index) + self.step * self.travelled);
+		if self.parallel {
+			return point;
+		}
+		for coordinate in &self.coordinates {
+			let i = coordinate.index;
+			point.set_nth(i, self.from.nth(i) + coordinate.sign * coordinate.offset.value());
+		}
+		point
+	}
+
+	fn advance(&mut self) {
+		self.travelled += T::one();
+		if self.parallel {
+			return;
+		}
+		let [driver, other] = &mut self.coordinates;
+		let carried = driver.offset.add(driver.increment);
+		other.offset.add(other.increment);
+		if carried {
+			other.offset.add(other.carry);
+		}
+	}
+
+	fn skip(&mut self, steps: T) {
+		self.travelled += steps;
+		if self.parallel {
+			return;
+		}
+		let [driver, other] = &mut self.coordinates;
+		let carried = driver.offset.add_times(driver.increment, wide(steps));
+		other.offset.add_times(other.increment, wide(steps));
+		other.offset.add_times(other.carry, carried);
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::line::Bresenham3d;
+	use crate::util::Point;
+	use crate::zip_core::ZipEdge;
+	use crate::Point3;
+	use super::Edge3d;
+
+	#[test]
+	fn bresenham() {
+		let lines: [(Point3<i32>, Point3<i32>); 5] = [
+			((0, 0, 0), (-37, 11, 5)),
+			((3, -2, 7), (20, 40, -13)),
+			((5, 5, 5), (-9, -9, -9)),
+			((0, 0, 0), (7, 0, 3)),
+			((1, 2, 3), (-4, 25, 12)),
+		];
+		for (from, to) in lines {
+			for axis in [0u8, 1, 2] {
+				for last in [true, false] {
+					let mut points = [None; 128];
+					for point in Bresenham3d::new(from, to) {
+						let slot = &mut points[(point.nth(axis) + 64) as usize];
+						if last || slot.is_none() {
+							*slot = Some(point);
+						}
+					}
+					let mut edge = Edge3d::new(from, to, axis, last);
+					let length = (to.nth(axis) - from.nth(axis)).abs();
+					for _ in 0..=length {
+						let point = edge.point();
+						assert_eq!(points[(point.nth(axis) + 64) as usize], Some(point));
+						edge.advance();
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn parallel() {
+		let mut edge = Edge3d::new((3, 0, 1), (3, 0, 9), 2, false);
+		for z in 1..=9 {
+			assert_eq!(edge.point(), (3, 0, z));
+			edge.advance();
+		}
+		assert!(!Edge3d::new((3, 0, 1), (3, 1, 9), 2, false).parallel);
+	}
+
+}
@@ -0,0 +1,127 @@
+//! Contains the supercover variant of the three-dimensional zip
+
+use core::fmt::{Debug, Formatter};
+use crate::line::Supercover3d;
+use crate::util::{count_between, Point};
+use crate::{Point3, SignedNum};
+
+/// Zip driving both lines of the triangle with the 6-connected [Supercover3d] lines instead of
+/// Bresenham, so the voxels of consecutive axis values of a line always share a face and the
+/// walls built from the zipped triangles have no diagonal leaks.
+///
+/// As a line can cross several voxels in the same axis value, each iteration yields the part of
+/// both lines in that axis value, as the [Supercover3d] lines walking only its voxels.
+///
+/// ```
+/// # use std::error::Error;
+/// # pub fn main() -> Result<(), Box<dyn Error>> {
+/// let zip = bresenham_zip::zip_3d::Builder3d::new()
+///   .axis(bresenham_zip::Axis::Z)
+///   .start_point((0, 0, 0))
+///   .first_ending_point((-4, 2, 2))
+///   .second_ending_point((4, 4, 2))
+///   .build_supercover()?;
+/// for (z, (a, b)) in zip.enumerate() {
+///   assert!(a.chain(b).all(|voxel| voxel.2 == z as i32));
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Supercover3dZip<T> {
+	a: Supercover3d<T>,
+	b: Supercover3d<T>,
+	value: T,
+	last: T,
+	step: T,
+	done: bool,
+	axis: u8,
+}
+
+impl<T: SignedNum> Supercover3dZip<T> {
+
+	pub(crate) fn new(start: Point3<T>, end_a: Point3<T>, end_b: Point3<T>, axis: u8) -> Self {
+		let (value, last) = (start.nth(axis), end_a.nth(axis));
+		Self {
+			a: Supercover3d::new(start, end_a),
+			b: Supercover3d::new(start, end_b),
+			value,
+			last,
+			step: if last < value { -T::one() } else { T::one() },
+			done: false,
+			axis,
+		}
+	}
+
+	/// Returns the part of the line in the current axis value, advancing it past that part
+	fn part(&self, line: &mut Supercover3d<T>) -> Supercover3d<T> {
+		let mut part = *line;
+		let mut voxels = 0;
+		while line.peek().is_some_and(|voxel| voxel.nth(self.axis) == self.value) {
+			line.next();
+			voxels += 1;
+		}
+		part.truncate(voxels);
+		part
+	}
+
+}
+
+impl<T: SignedNum> Iterator for Supercover3dZip<T> {
+	type Item = (Supercover3d<T>, Supercover3d<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let (mut a, mut b) = (self.a, self.b);
+		let pair = (self.part(&mut a), self.part(&mut b));
+		(self.a, self.b) = (a, b);
+		if self.value == self.last {
+			self.done = true;
+		} else {
+			self.value += self.step;
+		}
+		Some(pair)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = if self.done { 0 } else { count_between(self.value, self.last) };
+		(remaining, Some(remaining))
+	}
+}
+
+impl<T: SignedNum> ExactSizeIterator for Supercover3dZip<T> {}
+
+impl<T: SignedNum> Debug for Supercover3dZip<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Supercover3dZip [ {:?}, {:?} ]. Goal: {:?}",
+			self.a.peek(), self.b.peek(), self.last)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::line::Supercover3d;
+	use super::Supercover3dZip;
+
+	#[test]
+	fn slabs() {
+		let (start, end_a, end_b) = ((3, -2, 7), (-5, 6, 1), (9, 0, 1));
+		let zip = Supercover3dZip::new(start, end_a, end_b, 2);
+		assert_eq!(zip.len(), 7);
+		let (mut a, mut b) = (Supercover3d::new(start, end_a), Supercover3d::new(start, end_b));
+		for (z, (part_a, part_b)) in (1..=7).rev().zip(zip) {
+			// the parts are the whole lines split at each axis value
+			assert!(part_a.len() > 0 && part_b.len() > 0);
+			for voxel in part_a {
+				assert_eq!((voxel, voxel.2), (a.next().unwrap(), z));
+			}
+			for voxel in part_b {
+				assert_eq!((voxel, voxel.2), (b.next().unwrap(), z));
+			}
+		}
+		assert_eq!((a.next(), b.next()), (None, None));
+	}
+
+}
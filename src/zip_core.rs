@@ -0,0 +1,323 @@
+//! Contains the walk of the two lines shared by the zips of every dimension
+
+use crate::state::ZipState;
+use crate::util::{count_between, narrow};
+use crate::SignedNum;
+
+/// Line of a zip yielding one point for each value of the axis, walked from one of its ends
+pub(crate) trait ZipEdge<T>: Clone {
+	type Point: Copy;
+
+	/// Returns the point of the line in the current axis value
+	fn point(&self) -> Self::Point;
+
+	/// Moves the edge to the next axis value
+	fn advance(&mut self);
+
+	/// Moves the edge `steps` axis values at once
+	fn skip(&mut self, steps: T);
+}
+
+/// Both lines of a zip, walked from the starting point by the front and from the ending points by
+/// the back, along with the axis values left to yield at each end. The zips of every dimension
+/// only differ in the points of their lines, so the termination, the skips and the direction of
+/// the iteration are handled here once. Once both ends meet, the zip is marked as done instead of
+/// moving them past each other, so the axis values never leave the range of the type.
+#[derive(Clone, PartialEq)]
+pub(crate) struct ZipCore<T, E> {
+	a: E,
+	b: E,
+	back_a: E,
+	back_b: E,
+	front: T,
+	back: T,
+	step: T,
+	done: bool,
+	pub(crate) reversed: bool,
+}
+
+impl<T: SignedNum, E: ZipEdge<T>> ZipCore<T, E> {
+
+	/// Creates the walk of both lines
+	///
+	/// * `a`, `b` - Lines from the starting point to each ending point
+	/// * `back_a`, `back_b` - The same lines walked from their ending points
+	/// * `front`, `back` - Axis values of the starting point and the ending points
+	///
+	pub(crate) fn new([a, b, back_a, back_b]: [E; 4], front: T, back: T) -> Self {
+		Self {
+			a,
+			b,
+			back_a,
+			back_b,
+			front,
+			back,
+			step: if back > front { T::one() } else { -T::one() },
+			done: false,
+			reversed: false,
+		}
+	}
+
+	/// Returns the number of pairs left to yield
+	#[inline]
+	pub(crate) fn len(&self) -> usize {
+		if self.done { 0 } else { count_between(self.front, self.back) }
+	}
+
+	/// Returns the points of the lines at the front, shown by the zips when debugged
+	pub(crate) fn heads(&self) -> (E::Point, E::Point) {
+		(self.a.point(), self.b.point())
+	}
+
+	/// Returns the next axis value to yield from the back, the goal of the zip
+	#[inline]
+	pub(crate) fn goal(&self) -> T {
+		self.back
+	}
+
+	pub(crate) fn save_state(&self) -> ZipState<T> {
+		ZipState { front: self.front, back: self.back, done: self.done }
+	}
+
+	pub(crate) fn restore_state(&mut self, state: ZipState<T>) {
+		// the front moves along the step and the back against it
+		let forward = self.step > T::zero();
+		let ahead = |value: T, saved: T| if forward { value > saved } else { value < saved };
+		debug_assert!(!ahead(self.front, state.front) && !ahead(state.back, self.back),
+			"the zip is ahead of the saved state");
+		self.skip_front(count_between(self.front, state.front) - 1);
+		self.skip_back(count_between(self.back, state.back) - 1);
+		self.done |= state.done;
+	}
+
+	pub(crate) fn advance_to(&mut self, value: T) {
+		if self.reversed {
+			if self.precedes(self.back, value) {
+				self.skip_back(count_between(self.back, value) - 1);
+			}
+		} else if self.precedes(self.front, value) {
+			self.skip_front(count_between(self.front, value) - 1);
+		}
+	}
+
+	pub(crate) fn split_at(self, value: T) -> (Self, Self) {
+		let mut second = self.clone();
+		second.advance_to(value);
+		let mut first = self;
+		if first.reversed {
+			if !first.precedes(first.front, value) {
+				first.skip_front(count_between(first.front, value));
+			}
+		} else if !first.precedes(first.back, value) {
+			first.skip_back(count_between(first.back, value));
+		}
+		(first, second)
+	}
+
+	/// Returns the axis value of the pair yielded `n` positions ahead
+	#[cfg(feature = "rayon")]
+	pub(crate) fn value_at(&self, n: usize) -> T {
+		let steps = self.step * narrow(n as i128);
+		if self.reversed { self.back - steps } else { self.front + steps }
+	}
+
+	/// Skips the pairs at both ends of the zip out of the axis values between `min` and `max`
+	pub(crate) fn clamp(&mut self, min: T, max: T) {
+		let forward = self.step > T::zero();
+		let (first, last) = if forward { (min, max) } else { (max, min) };
+		if (forward && self.front < first) || (!forward && self.front > first) {
+			self.skip_front(count_between(self.front, first) - 1);
+		}
+		if (forward && self.back > last) || (!forward && self.back < last) {
+			self.skip_back(count_between(self.back, last) - 1);
+		}
+	}
+
+	/// Returns the points of the first and last pairs left to yield, in the direction of the
+	/// iteration
+	pub(crate) fn end_points(&self) -> Option<[E::Point; 4]> {
+		if self.done {
+			return None;
+		}
+		let (mut a, mut b) = if self.reversed {
+			(self.back_a.clone(), self.back_b.clone())
+		} else {
+			(self.a.clone(), self.b.clone())
+		};
+		let (first_a, first_b) = (a.point(), b.point());
+		let steps = narrow(self.len() as i128 - 1);
+		a.skip(steps);
+		b.skip(steps);
+		Some([first_a, first_b, a.point(), b.point()])
+	}
+
+	/// Calls `f` with every pair left, walking the edges directly instead of yielding the pairs
+	pub(crate) fn run<F: FnMut(E::Point, E::Point)>(self, mut f: F) {
+		if self.done {
+			return;
+		}
+		let (mut a, mut b) = if self.reversed {
+			(self.back_a, self.back_b)
+		} else {
+			(self.a, self.b)
+		};
+		for _ in 0..count_between(self.front, self.back) {
+			f(a.point(), b.point());
+			a.advance();
+			b.advance();
+		}
+	}
+
+	pub(crate) fn next(&mut self) -> Option<(E::Point, E::Point)> {
+		if self.reversed {
+			self.back_pair()
+		} else {
+			self.front_pair()
+		}
+	}
+
+	pub(crate) fn next_back(&mut self) -> Option<(E::Point, E::Point)> {
+		if self.reversed {
+			self.front_pair()
+		} else {
+			self.back_pair()
+		}
+	}
+
+	pub(crate) fn nth(&mut self, n: usize) -> Option<(E::Point, E::Point)> {
+		if self.reversed {
+			self.skip_back(n);
+		} else {
+			self.skip_front(n);
+		}
+		self.next()
+	}
+
+	pub(crate) fn nth_back(&mut self, n: usize) -> Option<(E::Point, E::Point)> {
+		if self.reversed {
+			self.skip_front(n);
+		} else {
+			self.skip_back(n);
+		}
+		self.next_back()
+	}
+
+	/// Skips `n` pairs at the front of the zip without yielding them, exhausting it if there
+	/// aren't enough
+	fn skip_front(&mut self, n: usize) {
+		if n >= self.len() {
+			self.done = true;
+		} else if n > 0 {
+			let steps = narrow(n as i128);
+			self.front += self.step * steps;
+			self.a.skip(steps);
+			self.b.skip(steps);
+		}
+	}
+
+	/// Skips `n` pairs at the back of the zip without yielding them, exhausting it if there
+	/// aren't enough
+	fn skip_back(&mut self, n: usize) {
+		if n >= self.len() {
+			self.done = true;
+		} else if n > 0 {
+			let steps = narrow(n as i128);
+			self.back -= self.step * steps;
+			self.back_a.skip(steps);
+			self.back_b.skip(steps);
+		}
+	}
+
+	/// Checks if the axis value `a` is yielded before the axis value `b`
+	fn precedes(&self, a: T, b: T) -> bool {
+		if (self.step > T::zero()) != self.reversed { a < b } else { a > b }
+	}
+
+	/// Yields the pair at the front of the zip
+	fn front_pair(&mut self) -> Option<(E::Point, E::Point)> {
+		if self.done {
+			return None;
+		}
+		let (a, b) = (self.a.point(), self.b.point());
+		self.a.advance();
+		self.b.advance();
+
+		if self.front == self.back {
+			self.done = true;
+		} else {
+			self.front += self.step;
+		}
+		Some((a, b))
+	}
+
+	/// Yields the pair at the back of the zip
+	fn back_pair(&mut self) -> Option<(E::Point, E::Point)> {
+		if self.done {
+			return None;
+		}
+		let (a, b) = (self.back_a.point(), self.back_b.point());
+		self.back_a.advance();
+		self.back_b.advance();
+
+		if self.front == self.back {
+			self.done = true;
+		} else {
+			self.back -= self.step;
+		}
+		Some((a, b))
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::zip::BresenhamZip;
+	use crate::zip_3d::Bresenham3dZip;
+
+	#[test]
+	fn every_dimension() {
+		// the lines of each dimension round differently, but they are walked the same way
+		let flat = BresenhamZip::new((3, -2), (-30, 25), (17, 25), 1);
+		let spatial = Bresenham3dZip::new((3, -2, 0), (-30, 25, 0), (17, 25, 0), 1);
+		let rows = flat.clone().rev().map(|(a, b)| (a.1, b.1));
+		assert!(rows.eq(spatial.clone().rev().map(|(a, b)| (a.1, b.1))));
+		let (first, second) = (flat.clone().split_at(10), spatial.clone().split_at(10));
+		assert_eq!((first.0.len(), first.1.len()), (second.0.len(), second.1.len()));
+		assert!(first.0.chain(first.1).eq(flat));
+		assert!(second.0.chain(second.1).eq(spatial));
+	}
+
+	#[test]
+	fn limits() {
+		// the flat edge is yielded once at the limits of the type, from either end
+		let top = BresenhamZip::<i8>::new((0, 124), (-3, i8::MAX), (3, i8::MAX), 1);
+		assert_eq!(top.clone().filter(|(a, _)| a.1 == i8::MAX).count(), 1);
+		assert_eq!(top.clone().rev().filter(|(a, _)| a.1 == i8::MAX).count(), 1);
+		let bottom = Bresenham3dZip::<i8>::new((0, -124, 0), (-3, i8::MIN, 1), (3, i8::MIN, 1), 1);
+		assert_eq!(bottom.clone().last(), Some(((-3, i8::MIN, 1), (3, i8::MIN, 1))));
+		let mut zip = top.clone();
+		assert_eq!(zip.nth_back(0), Some(((-3, i8::MAX), (3, i8::MAX))));
+		assert_eq!(zip.by_ref().count(), 3);
+		assert_eq!((zip.next(), zip.next_back(), zip.len()), (None, None, 0));
+		// the goal shown when debugging the zip stays at the last value yielded
+		let state = zip.save_state();
+		assert_eq!((state.front, state.back, state.done), (126, 126, true));
+		let mut flat = BresenhamZip::<i8>::new((0, i8::MIN), (-1, i8::MIN), (1, i8::MIN), 1);
+		assert_eq!(flat.next(), Some(((-1, i8::MIN), (1, i8::MIN))));
+		assert_eq!((flat.next(), flat.len()), (None, 0));
+		let (first, second) = top.clone().split_at(i8::MAX);
+		assert_eq!((first.len(), second.len()), (3, 1));
+		assert!(first.chain(second).eq(top));
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic(expected = "the zip is ahead of the saved state")]
+	fn restore_ahead() {
+		let mut zip = BresenhamZip::new((0, 0), (-5, 5), (5, 5), 1);
+		let state = zip.save_state();
+		zip.nth(2);
+		zip.restore_state(state);
+	}
+
+}